@@ -0,0 +1,42 @@
+use criterion::{black_box, Criterion};
+use neure::prelude::*;
+
+fn bench_take_till(c: &mut Criterion) {
+    let line = "a fairly long line of the text, it will be scanned until the newline. ".repeat(64);
+    let data = format!("{line}\n{line}\n{line}\n").into_bytes();
+
+    c.bench_function("lines of neure take_till", {
+        let data = data.clone();
+        let line = re::take_till(b'\n');
+        let lines = line.sep(b"\n");
+
+        move |b| {
+            b.iter(|| {
+                let mut ctx = BytesCtx::new(black_box(&data));
+
+                black_box(ctx.ctor(&lines).unwrap())
+            })
+        }
+    });
+
+    c.bench_function("lines of neure predicate", {
+        let line = (|b: &u8| *b != b'\n').repeat_zero_more();
+        let lines = line.sep(b"\n");
+
+        move |b| {
+            b.iter(|| {
+                let mut ctx = BytesCtx::new(black_box(&data));
+
+                black_box(ctx.ctor(&lines).unwrap())
+            })
+        }
+    });
+}
+
+criterion::criterion_group!(
+    name = benches;
+    config = Criterion::default().configure_from_args();
+    targets = bench_take_till
+);
+
+criterion::criterion_main!(benches);
@@ -1,23 +1,37 @@
 mod guard;
 mod policy;
+mod read;
 #[allow(clippy::module_inception)]
 mod regex;
+mod sink;
 mod span;
+mod token;
 
 use std::marker::PhantomData;
 
 use crate::err::Error;
+use crate::iter::ScanIter;
 use crate::re::Regex;
 use crate::MayDebug;
 
 pub use self::guard::CtxGuard;
 pub use self::policy::PolicyCtx;
+pub use self::read::ReadCtx;
 pub use self::regex::RegexCtx;
+pub use self::sink::CaptureSink;
+pub use self::sink::DepthSink;
+pub use self::sink::ErrorSink;
+pub use self::sink::SinkCtx;
+pub use self::sink::TriviaSink;
 pub use self::span::Span;
+pub use self::token::TokenCtx;
 
 pub type BytesCtx<'a> = RegexCtx<'a, [u8]>;
 pub type CharsCtx<'a> = RegexCtx<'a, str>;
 
+/// A [`Context`] over a slice of any type, same as [`TokenCtx`].
+pub type SliceCtx<'a, T> = TokenCtx<'a, T>;
+
 pub trait Context<'a> {
     type Orig: ?Sized;
 
@@ -41,12 +55,64 @@ pub trait Context<'a> {
 
     fn dec(&mut self, offset: usize) -> &mut Self;
 
+    /// Return an iterator of the items start from current offset,
+    /// the index of item is relative to current offset.
     fn peek(&self) -> Result<Self::Iter<'a>, Error> {
         self.peek_at(self.offset())
     }
 
+    /// Return an iterator of the items start from `offset`,
+    /// the index of item is relative to `offset`.
     fn peek_at(&self, offset: usize) -> Result<Self::Iter<'a>, Error>;
 
+    ///
+    /// Return an iterator of the items start from `offset`,
+    /// the index of item is the absolute offset in the [`Orig`](Context::Orig).
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use neure::prelude::*;
+    /// #
+    /// # fn main() -> color_eyre::Result<()> {
+    /// #     color_eyre::install()?;
+    ///     let ctx = CharsCtx::new("let 变量 = 1;");
+    ///     let items: Vec<_> = ctx.scan_from(4)?.take(3).collect();
+    ///
+    ///     assert_eq!(items, [(4, '变'), (7, '量'), (10, ' ')]);
+    ///     assert_eq!(ctx.peek_at(4)?.nth(1), Some((3, '量')));
+    ///     Ok(())
+    /// # }
+    /// ```
+    fn scan_from(&self, offset: usize) -> Result<ScanIter<Self::Iter<'a>>, Error> {
+        Ok(ScanIter::new(offset, self.peek_at(offset)?))
+    }
+
+    ///
+    /// Return the item at current offset, or `None` if no more data.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use neure::prelude::*;
+    /// #
+    /// # fn main() -> color_eyre::Result<()> {
+    /// #     color_eyre::install()?;
+    ///     let mut ctx = CharsCtx::new("a变");
+    ///
+    ///     assert_eq!(ctx.current(), Some('a'));
+    ///     assert_eq!(ctx.inc(1).current(), Some('变'));
+    ///     assert_eq!(ctx.inc(3).current(), None);
+    ///     Ok(())
+    /// # }
+    /// ```
+    fn current(&self) -> Option<Self::Item>
+    where
+        Self: 'a,
+    {
+        self.peek().ok()?.next().map(|(_, item)| item)
+    }
+
     fn orig(&self) -> Result<&'a Self::Orig, Error> {
         self.orig_at(self.offset())
     }
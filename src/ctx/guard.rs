@@ -46,6 +46,14 @@ where
         self.ctx
     }
 
+    /// Return true if the offset not moved since `offset`.
+    ///
+    /// The loops use it to detect an iteration that matched nothing, such as the progress guard
+    /// of [`Repeat`](crate::re::ctor::Repeat) and [`Separate`](crate::re::ctor::Separate).
+    pub fn is_stuck(&self, offset: usize) -> bool {
+        self.ctx.offset() == offset
+    }
+
     pub fn reset(&mut self) -> &mut Self {
         self.ctx.set_offset(self.offset);
         self
@@ -57,6 +65,21 @@ where
         }
         ret
     }
+
+    /// Call `func` with the context, return `Ok(None)` if it failed with an error not [`fatal`](Error::is_fatal).
+    /// The loops use it to stop the iterations.
+    ///
+    /// The fatal error is returned, and the offset will be reset like [`process_ret`](CtxGuard::process_ret).
+    pub fn non_fatal<R>(
+        &mut self,
+        func: impl FnOnce(&mut C) -> Result<R, Error>,
+    ) -> Result<Option<R>, Error> {
+        match func(self.ctx) {
+            Ok(ret) => Ok(Some(ret)),
+            Err(e) if e.is_fatal() => self.process_ret(Err(e)),
+            Err(_) => Ok(None),
+        }
+    }
 }
 
 impl<'b, C> CtxGuard<'_, 'b, C>
@@ -91,7 +91,10 @@ where
 
     type Item = <I as Context<'a>>::Item;
 
-    type Iter<'b> = <I as Context<'a>>::Iter<'b> where Self: 'b;
+    type Iter<'b>
+        = <I as Context<'a>>::Iter<'b>
+    where
+        Self: 'b;
 
     fn len(&self) -> usize {
         Context::len(&self.inner)
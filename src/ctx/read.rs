@@ -0,0 +1,182 @@
+use std::io::Read;
+
+use super::BytesCtx;
+use super::Regex;
+use super::Span;
+
+use crate::ctx::Match;
+use crate::err::Error;
+use crate::trace_log;
+
+///
+/// A streaming driver reading the data from [`Read`] on demand.
+///
+/// The patterns are matched by a [`BytesCtx`] over the buffered window, the window always
+/// contains at least [`lookahead`](ReadCtx::lookahead) bytes unless the reader reached EOF.
+/// If a match reaches the end of the window, the window is grown and the pattern is matched again.
+/// The matched data is consumed after a successful match, it can not be backtracked any more.
+///
+/// # Notice
+///
+/// `ReadCtx` does not implement [`Context`](crate::ctx::Context), the buffer may be moved when reading more data.
+/// Construct the pattern in [`with_ctx`](ReadCtx::with_ctx), the returned value can not borrow the buffered data.
+/// The pattern should only look ahead a bounded number of bytes,
+/// which means a failed match should not be caused by the data out of the window.
+///
+/// # Example
+///
+/// ```
+/// # use neure::prelude::*;
+/// # use neure::ctx::ReadCtx;
+/// #
+/// # fn main() -> color_eyre::Result<()> {
+/// #     color_eyre::install()?;
+///     let data = "1,22,333;".as_bytes();
+///     let mut ctx = ReadCtx::new(data).with_lookahead(2);
+///     let lens = ctx.with_ctx(|ctx| {
+///         let num = neu::ascii_digit().repeat_one_more();
+///         let num = num.map(|v: &[u8]| Ok(v.len()));
+///
+///         ctx.ctor(&num.sep(b","))
+///     })?;
+///
+///     assert_eq!(lens, [1, 2, 3]);
+///     assert_eq!(ctx.offset(), 8);
+///     assert_eq!(ctx.try_mat(&b";")?, Span::new(8, 1));
+///     Ok(())
+/// # }
+/// ```
+#[derive(Debug)]
+pub struct ReadCtx<R> {
+    reader: R,
+    buf: Vec<u8>,
+    beg: usize,
+    offset: usize,
+    lookahead: usize,
+    eof: bool,
+}
+
+impl<R> ReadCtx<R>
+where
+    R: Read,
+{
+    pub fn new(reader: R) -> Self {
+        Self {
+            reader,
+            buf: vec![],
+            beg: 0,
+            offset: 0,
+            lookahead: 4096,
+            eof: false,
+        }
+    }
+
+    /// Return the number of bytes consumed from the reader.
+    pub fn offset(&self) -> usize {
+        self.offset
+    }
+
+    pub fn lookahead(&self) -> usize {
+        self.lookahead
+    }
+
+    /// Return true if the reader reached EOF.
+    pub fn is_eof(&self) -> bool {
+        self.eof
+    }
+
+    /// Return the buffered data that not consumed.
+    pub fn buffered(&self) -> &[u8] {
+        &self.buf[self.beg..]
+    }
+
+    pub fn with_lookahead(mut self, lookahead: usize) -> Self {
+        self.lookahead = lookahead.max(1);
+        self
+    }
+
+    pub fn set_lookahead(&mut self, lookahead: usize) -> &mut Self {
+        self.lookahead = lookahead.max(1);
+        self
+    }
+
+    pub fn into_inner(self) -> R {
+        self.reader
+    }
+
+    /// Read from the reader until `need` bytes buffered or EOF reached.
+    pub fn fill(&mut self, need: usize) -> Result<&mut Self, Error> {
+        let mut chunk = vec![0; self.lookahead];
+
+        while !self.eof && self.buf.len() - self.beg < need {
+            match self.reader.read(&mut chunk) {
+                Ok(0) => self.eof = true,
+                Ok(len) => self.buf.extend_from_slice(&chunk[..len]),
+                Err(e) if e.kind() == std::io::ErrorKind::Interrupted => {}
+                Err(e) => return Err(Error::Io(e.kind())),
+            }
+        }
+        trace_log!("fill {need} -> read ctx -> {}", self.buf.len() - self.beg);
+        Ok(self)
+    }
+
+    fn consume(&mut self, len: usize) {
+        self.beg += len;
+        self.offset += len;
+        // drop the consumed data, it will never be accessed again
+        if self.beg >= self.lookahead {
+            self.buf.drain(..self.beg);
+            self.beg = 0;
+        }
+    }
+
+    ///
+    /// Invoke `func` with a [`BytesCtx`] over the buffered window,
+    /// consume the data matched by `func` if it succeeded.
+    ///
+    /// If the match reaches the end of the window, the window is grown geometrically
+    /// and `func` is invoked again, so `func` may be invoked more than once.
+    ///
+    /// Return [`Error::NeedMore`] if `func` failed and all the data was consumed.
+    pub fn with_ctx<F, O>(&mut self, mut func: F) -> Result<O, Error>
+    where
+        F: FnMut(&mut BytesCtx<'_>) -> Result<O, Error>,
+    {
+        let mut need = self.lookahead;
+
+        loop {
+            self.fill(need)?;
+
+            let dat = self.buffered();
+            let mut ctx = BytesCtx::new(dat);
+            let ret = func(&mut ctx);
+            let (len, end) = (dat.len(), ctx.offset());
+
+            match ret {
+                // the match may be truncated by the window
+                Ok(_) if end == len && !self.eof => {
+                    need = (len * 2).max(len + self.lookahead);
+                }
+                Ok(val) => {
+                    self.consume(end);
+                    return Ok(val);
+                }
+                Err(_) if len == 0 && self.eof => {
+                    return Err(Error::NeedMore);
+                }
+                Err(e) => return Err(e),
+            }
+        }
+    }
+
+    /// Match the pattern and return the [`Span`] relative to the start of reader.
+    pub fn try_mat<P>(&mut self, pat: &P) -> Result<Span, Error>
+    where
+        P: for<'b> Regex<BytesCtx<'b>, Ret = Span>,
+    {
+        let offset = self.offset;
+
+        self.with_ctx(|ctx| ctx.try_mat(pat))
+            .map(|span| Span::new(offset + span.beg, span.len))
+    }
+}
@@ -7,6 +7,7 @@ use super::PolicyCtx;
 use super::PolicyMatch;
 use super::RePolicy;
 use super::Regex;
+use super::SinkCtx;
 use super::Span;
 
 use crate::ctx::Match;
@@ -20,6 +21,14 @@ use crate::re::Pass;
 use crate::span::SimpleStorer;
 use crate::trace_log;
 
+///
+/// A [`Context`] over the borrowed data `T`, such as [`str`] or `[u8]`.
+///
+/// The offset is always checked when changed by [`set_offset`](Context::set_offset),
+/// [`inc`](Context::inc) or [`dec`](Context::dec):
+/// it must not exceed the length of data, and it must on a char boundary for [`str`].
+/// A invalid offset will panic in debug mode.
+/// In release mode the offset saturates to the range `0..=len`, and the char boundary is not checked.
 #[derive(Debug)]
 pub struct RegexCtx<'a, T>
 where
@@ -81,6 +90,13 @@ where
         SimpleStorer::new(capacity)
     }
 
+    ///
+    /// Wrap the context with sinks, see [`SinkCtx`].
+    ///
+    pub fn with_sink(self) -> SinkCtx<Self> {
+        SinkCtx::new(self)
+    }
+
     ///
     /// Setting a policy(which implemented [`BPolicy`]) will invoked before any match occurs.
     ///
@@ -302,6 +318,121 @@ where
     }
 }
 
+impl RegexCtx<'_, [u8]> {
+    fn check_offset(&self, offset: usize) -> usize {
+        debug_assert!(
+            offset <= self.dat.len(),
+            "offset {offset} out of bound, the length is {}",
+            self.dat.len()
+        );
+        offset.min(self.dat.len())
+    }
+}
+
+impl RegexCtx<'_, str> {
+    fn check_offset(&self, offset: usize) -> usize {
+        debug_assert!(
+            offset <= self.dat.len(),
+            "offset {offset} out of bound, the length is {}",
+            self.dat.len()
+        );
+        debug_assert!(
+            self.dat.is_char_boundary(offset.min(self.dat.len())),
+            "offset {offset} is not on a char boundary"
+        );
+        offset.min(self.dat.len())
+    }
+
+    ///
+    /// Move the offset to the start of current line, which is the offset after the previous `\n`.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use neure::prelude::*;
+    /// #
+    /// # fn main() -> color_eyre::Result<()> {
+    /// #     color_eyre::install()?;
+    ///     let mut ctx = CharsCtx::new("let a = 1;\nlet b = 2;");
+    ///
+    ///     ctx.set_offset(15);
+    ///     assert_eq!(ctx.to_line_start().offset(), 11);
+    ///     ctx.set_offset(4);
+    ///     assert_eq!(ctx.to_line_start().offset(), 0);
+    ///     Ok(())
+    /// # }
+    /// ```
+    pub fn to_line_start(&mut self) -> &mut Self {
+        let offset = self.check_offset(self.offset);
+
+        self.offset = self.dat[..offset].rfind('\n').map_or(0, |pos| pos + 1);
+        self
+    }
+
+    ///
+    /// Move the offset to the end of current line, which is the offset of the next `\n`,
+    /// or the length of data if current line is the last line.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use neure::prelude::*;
+    /// #
+    /// # fn main() -> color_eyre::Result<()> {
+    /// #     color_eyre::install()?;
+    ///     let mut ctx = CharsCtx::new("let a = 1;\nlet b = 2;");
+    ///
+    ///     ctx.set_offset(4);
+    ///     assert_eq!(ctx.to_line_end().offset(), 10);
+    ///     ctx.set_offset(15);
+    ///     assert_eq!(ctx.to_line_end().offset(), 21);
+    ///     Ok(())
+    /// # }
+    /// ```
+    pub fn to_line_end(&mut self) -> &mut Self {
+        let offset = self.check_offset(self.offset);
+
+        self.offset = self.dat[offset..]
+            .find('\n')
+            .map_or(self.dat.len(), |pos| offset + pos);
+        self
+    }
+
+    ///
+    /// Return the 1-based line and column of `offset`, the column is counted in chars.
+    /// The `\n` is treated as the last char of its line.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use neure::prelude::*;
+    /// #
+    /// # fn main() -> color_eyre::Result<()> {
+    /// #     color_eyre::install()?;
+    ///     let ctx = CharsCtx::new("let 变量 = 1;\nlet b = 2;");
+    ///
+    ///     assert_eq!(ctx.line_col(0), (1, 1));
+    ///     assert_eq!(ctx.line_col(7), (1, 6));
+    ///     assert_eq!(ctx.line_col(16), (2, 1));
+    ///     Ok(())
+    /// # }
+    /// ```
+    pub fn line_col(&self, offset: usize) -> (usize, usize) {
+        let head = &self.dat[..self.check_offset(offset)];
+        let line_beg = head.rfind('\n').map_or(0, |pos| pos + 1);
+        let line = head.bytes().filter(|v| *v == b'\n').count() + 1;
+
+        (line, head[line_beg..].chars().count() + 1)
+    }
+
+    ///
+    /// Return the 1-based line and column of the begin and end of `span`,
+    /// see [`line_col`](RegexCtx::line_col).
+    pub fn span_line_col(&self, span: &Span) -> ((usize, usize), (usize, usize)) {
+        (self.line_col(span.beg), self.line_col(span.beg + span.len))
+    }
+}
+
 impl<'a> Context<'a> for RegexCtx<'a, [u8]> {
     type Orig = [u8];
 
@@ -321,19 +452,24 @@ impl<'a> Context<'a> for RegexCtx<'a, [u8]> {
     }
 
     fn set_offset(&mut self, offset: usize) -> &mut Self {
-        self.offset = offset;
+        self.offset = self.check_offset(offset);
         trace_log!("set {offset} -> ctx -> {}", self.offset);
         self
     }
 
     fn inc(&mut self, offset: usize) -> &mut Self {
-        self.offset += offset;
+        self.offset = self.check_offset(self.offset.saturating_add(offset));
         trace_log!("inc {offset} -> ctx -> {}", self.offset);
         self
     }
 
     fn dec(&mut self, offset: usize) -> &mut Self {
-        self.offset -= offset;
+        debug_assert!(
+            offset <= self.offset,
+            "can not decrease offset {} by {offset}",
+            self.offset
+        );
+        self.offset = self.check_offset(self.offset.saturating_sub(offset));
         trace_log!("dec {offset} -> ctx -> {}", self.offset);
         self
     }
@@ -376,19 +512,24 @@ impl<'a> Context<'a> for RegexCtx<'a, str> {
     }
 
     fn set_offset(&mut self, offset: usize) -> &mut Self {
-        self.offset = offset;
+        self.offset = self.check_offset(offset);
         trace_log!("set {offset} -> ctx -> {}", self.offset);
         self
     }
 
     fn inc(&mut self, offset: usize) -> &mut Self {
-        self.offset += offset;
+        self.offset = self.check_offset(self.offset.saturating_add(offset));
         trace_log!("inc {offset} -> ctx -> {}", self.offset);
         self
     }
 
     fn dec(&mut self, offset: usize) -> &mut Self {
-        self.offset -= offset;
+        debug_assert!(
+            offset <= self.offset,
+            "can not decrease offset {} by {offset}",
+            self.offset
+        );
+        self.offset = self.check_offset(self.offset.saturating_sub(offset));
         trace_log!("dec {offset} -> ctx -> {}", self.offset);
         self
     }
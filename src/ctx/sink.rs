@@ -0,0 +1,399 @@
+use super::Context;
+use super::Regex;
+use super::Span;
+
+use crate::ctx::Match;
+use crate::err::Error;
+use crate::map::MapSingle;
+use crate::re::Ctor;
+use crate::re::Extract;
+use crate::re::Handler;
+use crate::re::Pass;
+use crate::span::SimpleStorer;
+
+///
+/// A [`Context`] which can collect the recovered errors during parsing.
+///
+pub trait ErrorSink {
+    fn push_error(&mut self, error: Error) -> Result<(), Error>;
+
+    fn errors(&self) -> &[Error];
+}
+
+///
+/// A [`Context`] which can store the captured spans during parsing.
+///
+pub trait CaptureSink {
+    fn capture(&mut self, id: usize, span: Span) -> Result<(), Error>;
+
+    fn captures(&self) -> &SimpleStorer;
+}
+
+///
+/// A [`Context`] which can record the skipped trivia, such as comments.
+///
+pub trait TriviaSink {
+    fn push_trivia(&mut self, span: Span);
+
+    fn trivia(&self) -> &[Span];
+}
+
+///
+/// A [`Context`] which can track the nesting depth of recursive parsing.
+///
+pub trait DepthSink {
+    fn depth(&self) -> usize;
+
+    fn set_depth(&mut self, depth: usize);
+}
+
+///
+/// Wrap the [`Context`] `I` with sinks, such as the error list used by
+/// [`recover_to`](crate::re::ConstructOp::recover_to) and the span storer used by
+/// [`capture`](crate::re::ConstructOp::capture), the trivia list used by
+/// [`skip_trivia`](crate::re::skip_trivia), and the depth counter used by
+/// [`max_depth`](crate::re::ConstructOp::max_depth).
+///
+/// The error list can be limited by [`with_error_limit`](SinkCtx::with_error_limit),
+/// [`push_error`](ErrorSink::push_error) will return [`Error::TooManyErrors`] if the limit reached,
+/// which aborts the recovery.
+///
+/// # Example
+///
+/// ```
+/// # use neure::prelude::*;
+/// #
+/// # fn main() -> color_eyre::Result<()> {
+/// #     color_eyre::install()?;
+///     let byte = neu::ascii_hexdigit().repeat_times::<2>();
+///     let byte = byte.pad(b",").recover_to(b",");
+///     let mut ctx = BytesCtx::new(b"ff,zz,01,").with_sink();
+///
+///     assert_eq!(ctx.ctor(&byte.repeat(1..))?, [Some(&b"ff"[..]), None, Some(b"01")]);
+///     assert_eq!(ctx.take_errors().len(), 1);
+///     assert!(ctx.errors().is_empty());
+///     Ok(())
+/// # }
+/// ```
+#[derive(Debug, Clone, Default)]
+pub struct SinkCtx<I> {
+    pub(crate) inner: I,
+    pub(crate) errors: Vec<Error>,
+    pub(crate) captures: SimpleStorer,
+    pub(crate) trivia: Vec<Span>,
+    pub(crate) error_limit: Option<usize>,
+    pub(crate) depth: usize,
+}
+
+impl<I> SinkCtx<I> {
+    pub fn new(inner: I) -> Self {
+        Self {
+            inner,
+            errors: vec![],
+            captures: SimpleStorer::default(),
+            trivia: vec![],
+            error_limit: None,
+            depth: 0,
+        }
+    }
+
+    pub fn inner(&self) -> &I {
+        &self.inner
+    }
+
+    pub fn inner_mut(&mut self) -> &mut I {
+        &mut self.inner
+    }
+
+    pub fn set_inner(&mut self, dat: I) -> &mut Self {
+        self.inner = dat;
+        self
+    }
+
+    pub fn with_inner(mut self, dat: I) -> Self {
+        self.inner = dat;
+        self
+    }
+
+    pub fn error_limit(&self) -> Option<usize> {
+        self.error_limit
+    }
+
+    pub fn set_error_limit(&mut self, limit: Option<usize>) -> &mut Self {
+        self.error_limit = limit;
+        self
+    }
+
+    ///
+    /// Abort the parsing with [`Error::TooManyErrors`] if more than `limit` errors recovered.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use neure::prelude::*;
+    /// #
+    /// # fn main() -> color_eyre::Result<()> {
+    /// #     color_eyre::install()?;
+    ///     let num = neu::digit(10).repeat_one_more().map(map::from_str::<i32>());
+    ///     let stmts = num.pad(";").recover_to(";").repeat(1..);
+    ///     let mut ctx = CharsCtx::new("1;a;b;c;5;").with_sink().with_error_limit(2);
+    ///
+    ///     assert!(matches!(
+    ///         ctx.ctor(&stmts),
+    ///         Err(neure::err::Error::TooManyErrors)
+    ///     ));
+    ///     assert_eq!(ctx.errors().len(), 2);
+    ///     Ok(())
+    /// # }
+    /// ```
+    pub fn with_error_limit(mut self, limit: usize) -> Self {
+        self.error_limit = Some(limit);
+        self
+    }
+
+    pub fn reset_with(&mut self, dat: I) -> &mut Self {
+        self.inner = dat;
+        self.errors.clear();
+        self.captures.reset();
+        self.trivia.clear();
+        self.depth = 0;
+        self
+    }
+
+    pub fn errors(&self) -> &[Error] {
+        &self.errors
+    }
+
+    pub fn take_errors(&mut self) -> Vec<Error> {
+        std::mem::take(&mut self.errors)
+    }
+
+    pub fn captures(&self) -> &SimpleStorer {
+        &self.captures
+    }
+
+    pub fn take_captures(&mut self) -> SimpleStorer {
+        std::mem::take(&mut self.captures)
+    }
+
+    pub fn trivia(&self) -> &[Span] {
+        &self.trivia
+    }
+
+    pub fn take_trivia(&mut self) -> Vec<Span> {
+        std::mem::take(&mut self.trivia)
+    }
+
+    pub fn depth(&self) -> usize {
+        self.depth
+    }
+
+    pub fn span_storer(&self, capacity: usize) -> SimpleStorer {
+        SimpleStorer::new(capacity)
+    }
+}
+
+impl<I> ErrorSink for SinkCtx<I> {
+    fn push_error(&mut self, error: Error) -> Result<(), Error> {
+        if self
+            .error_limit
+            .is_some_and(|limit| self.errors.len() >= limit)
+        {
+            return Err(Error::TooManyErrors);
+        }
+        self.errors.push(error);
+        Ok(())
+    }
+
+    fn errors(&self) -> &[Error] {
+        &self.errors
+    }
+}
+
+impl<I> CaptureSink for SinkCtx<I> {
+    fn capture(&mut self, id: usize, span: Span) -> Result<(), Error> {
+        if id >= self.captures.len() {
+            self.captures.resize(id + 1);
+        }
+        self.captures.add_span(id, span);
+        Ok(())
+    }
+
+    fn captures(&self) -> &SimpleStorer {
+        &self.captures
+    }
+}
+
+impl<I> TriviaSink for SinkCtx<I> {
+    fn push_trivia(&mut self, span: Span) {
+        self.trivia.push(span);
+    }
+
+    fn trivia(&self) -> &[Span] {
+        &self.trivia
+    }
+}
+
+impl<I> DepthSink for SinkCtx<I> {
+    fn depth(&self) -> usize {
+        self.depth
+    }
+
+    fn set_depth(&mut self, depth: usize) {
+        self.depth = depth;
+    }
+}
+
+impl<'a, I> Context<'a> for SinkCtx<I>
+where
+    I: Context<'a>,
+{
+    type Orig = <I as Context<'a>>::Orig;
+
+    type Item = <I as Context<'a>>::Item;
+
+    type Iter<'b>
+        = <I as Context<'a>>::Iter<'b>
+    where
+        Self: 'b;
+
+    fn len(&self) -> usize {
+        Context::len(&self.inner)
+    }
+
+    fn offset(&self) -> usize {
+        Context::offset(&self.inner)
+    }
+
+    fn set_offset(&mut self, offset: usize) -> &mut Self {
+        Context::set_offset(&mut self.inner, offset);
+        self
+    }
+
+    fn inc(&mut self, offset: usize) -> &mut Self {
+        Context::inc(&mut self.inner, offset);
+        self
+    }
+
+    fn dec(&mut self, offset: usize) -> &mut Self {
+        Context::dec(&mut self.inner, offset);
+        self
+    }
+
+    fn orig_at(&self, offset: usize) -> Result<&'a Self::Orig, Error> {
+        Context::orig_at(&self.inner, offset)
+    }
+
+    fn peek_at(&self, offset: usize) -> Result<Self::Iter<'a>, Error> {
+        Context::peek_at(&self.inner, offset)
+    }
+
+    fn orig_sub(&self, offset: usize, len: usize) -> Result<&'a Self::Orig, Error> {
+        Context::orig_sub(&self.inner, offset, len)
+    }
+
+    fn clone_with(&self, orig: &'a Self::Orig) -> Self {
+        let mut ctx = SinkCtx::new(I::clone_with(&self.inner, orig));
+
+        ctx.error_limit = self.error_limit;
+        ctx
+    }
+}
+
+impl<'a, I> Match<SinkCtx<I>> for SinkCtx<I>
+where
+    I: Context<'a> + Match<I>,
+    Self: Context<'a>,
+{
+    fn try_mat_t<Pat: Regex<SinkCtx<I>> + ?Sized>(&mut self, pat: &Pat) -> Result<Pat::Ret, Error> {
+        // invoke the matching policy of inner context, such as the before policy of PolicyCtx
+        self.inner.try_mat(&crate::re::null())?;
+        pat.try_parse(self)
+    }
+}
+
+impl<'a, I, R> Extract<'a, Self, R> for SinkCtx<I>
+where
+    Self: Context<'a>,
+    I: Context<'a> + Clone,
+{
+    type Out<'b> = SinkCtx<I>;
+
+    type Error = Error;
+
+    fn extract(ctx: &Self, _: &R) -> Result<Self::Out<'a>, Self::Error> {
+        Ok(Clone::clone(ctx))
+    }
+}
+
+impl<'a, I> SinkCtx<I>
+where
+    I: Context<'a> + Match<I>,
+    Self: Context<'a>,
+{
+    pub fn ctor_with<H, A, P, M, O>(&mut self, pat: &P, handler: &mut H) -> Result<O, Error>
+    where
+        P: Ctor<'a, Self, M, O, H, A>,
+        H: Handler<A, Out = M, Error = Error>,
+        A: Extract<'a, Self, Span, Out<'a> = A, Error = Error>,
+    {
+        pat.construct(self, handler)
+    }
+
+    pub fn map_with<H, A, P, O>(&mut self, pat: &P, mut handler: H) -> Result<O, Error>
+    where
+        P: Regex<Self, Ret = Span>,
+        H: Handler<A, Out = O, Error = Error>,
+        A: Extract<'a, Self, P::Ret, Out<'a> = A, Error = Error>,
+    {
+        let ret = self.try_mat(pat)?;
+
+        handler.invoke(A::extract(self, &ret)?)
+    }
+
+    pub fn ctor<P, O>(&mut self, pat: &P) -> Result<O, Error>
+    where
+        P: Ctor<
+            'a,
+            Self,
+            &'a <Self as Context<'a>>::Orig,
+            O,
+            Pass,
+            &'a <Self as Context<'a>>::Orig,
+        >,
+        &'a <Self as Context<'a>>::Orig:
+            Extract<'a, Self, Span, Out<'a> = &'a <Self as Context<'a>>::Orig, Error = Error> + 'a,
+    {
+        self.ctor_with(pat, &mut Pass)
+    }
+
+    pub fn map<P, O>(
+        &mut self,
+        pat: &P,
+        mapper: impl MapSingle<&'a <Self as Context<'a>>::Orig, O>,
+    ) -> Result<O, Error>
+    where
+        P: Regex<Self, Ret = Span>,
+        <Self as Context<'a>>::Orig: 'a,
+        &'a <Self as Context<'a>>::Orig:
+            Extract<'a, Self, P::Ret, Out<'a> = &'a <Self as Context<'a>>::Orig, Error = Error>,
+    {
+        mapper.map_to(self.map_with(pat, Ok)?)
+    }
+
+    pub fn ctor_span<P, O>(&mut self, pat: &P) -> Result<O, Error>
+    where
+        P: Ctor<'a, Self, Span, O, Pass, Span>,
+        Span: Extract<'a, Self, Span, Out<'a> = Span, Error = Error>,
+    {
+        self.ctor_with(pat, &mut Pass)
+    }
+
+    pub fn map_span<P, O>(&mut self, pat: &P, mapper: impl MapSingle<Span, O>) -> Result<O, Error>
+    where
+        P: Regex<Self, Ret = Span>,
+        Span: Extract<'a, Self, P::Ret, Out<'a> = Span, Error = Error>,
+    {
+        mapper.map_to(self.map_with(pat, Ok)?)
+    }
+}
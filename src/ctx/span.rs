@@ -7,6 +7,7 @@ use crate::err::Error;
 use crate::re::Extract;
 
 #[derive(Debug, Clone, Copy, Default, PartialEq, Eq, PartialOrd, Ord, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct Span {
     pub beg: usize,
 
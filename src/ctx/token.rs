@@ -0,0 +1,239 @@
+use std::iter::Enumerate;
+use std::slice::Iter;
+
+use super::Context;
+use super::Regex;
+use super::SinkCtx;
+use super::Span;
+
+use crate::ctx::Match;
+use crate::err::Error;
+use crate::map::MapSingle;
+use crate::re::Ctor;
+use crate::re::Extract;
+use crate::re::Handler;
+use crate::re::Pass;
+use crate::trace_log;
+
+///
+/// A [`Context`] over a pre-lexed token slice `[T]`, the [`Item`](Context::Item) is `&T`.
+///
+/// The offset is the index of token, so the length of every token is 1.
+/// Use [`Neu<&T>`](crate::neu::Neu), such as a closure `|t: &&T| ...`, to match the token kinds.
+///
+/// # Example
+///
+/// ```
+/// # use neure::prelude::*;
+/// #
+/// # fn main() -> color_eyre::Result<()> {
+/// #     color_eyre::install()?;
+///     #[derive(Debug, Clone, Copy, PartialEq, Eq)]
+///     enum Tok {
+///         Num(i64),
+///         Comma,
+///     }
+///
+///     let toks = [Tok::Num(1), Tok::Comma, Tok::Num(2), Tok::Comma, Tok::Num(3)];
+///     let num = (|t: &&Tok| matches!(t, Tok::Num(_))).repeat_one();
+///     let comma = (|t: &&Tok| matches!(t, Tok::Comma)).repeat_one();
+///     let nums = num.sep(comma);
+///     let mut ctx = TokenCtx::new(&toks);
+///
+///     assert_eq!(ctx.ctor(&nums)?, [&toks[0..1], &toks[2..3], &toks[4..5]]);
+///     Ok(())
+/// # }
+/// ```
+#[derive(Debug)]
+pub struct TokenCtx<'a, T> {
+    dat: &'a [T],
+    offset: usize,
+}
+
+impl<T> Clone for TokenCtx<'_, T> {
+    fn clone(&self) -> Self {
+        *self
+    }
+}
+
+impl<T> Copy for TokenCtx<'_, T> {}
+
+impl<'a, T> TokenCtx<'a, T> {
+    pub fn new(dat: &'a [T]) -> Self {
+        Self { dat, offset: 0 }
+    }
+
+    pub fn dat(&self) -> &'a [T] {
+        self.dat
+    }
+
+    pub fn offset(&self) -> usize {
+        self.offset
+    }
+
+    pub fn with_dat(mut self, dat: &'a [T]) -> Self {
+        self.dat = dat;
+        self
+    }
+
+    pub fn with_offset(mut self, offset: usize) -> Self {
+        self.offset = offset;
+        self
+    }
+
+    pub fn reset_with(&mut self, dat: &'a [T]) -> &mut Self {
+        self.dat = dat;
+        self.offset = 0;
+        self
+    }
+
+    pub fn reset(&mut self) -> &mut Self {
+        self.offset = 0;
+        self
+    }
+
+    ///
+    /// Wrap the context with sinks, see [`SinkCtx`].
+    ///
+    pub fn with_sink(self) -> SinkCtx<Self> {
+        SinkCtx::new(self)
+    }
+
+    fn check_offset(&self, offset: usize) -> usize {
+        debug_assert!(
+            offset <= self.dat.len(),
+            "offset {offset} out of bound, the length is {}",
+            self.dat.len()
+        );
+        offset.min(self.dat.len())
+    }
+}
+
+impl<'a, T> Context<'a> for TokenCtx<'a, T> {
+    type Orig = [T];
+
+    type Item = &'a T;
+
+    type Iter<'b>
+        = Enumerate<Iter<'a, T>>
+    where
+        Self: 'b;
+
+    fn len(&self) -> usize {
+        self.dat.len()
+    }
+
+    fn offset(&self) -> usize {
+        self.offset
+    }
+
+    fn set_offset(&mut self, offset: usize) -> &mut Self {
+        self.offset = self.check_offset(offset);
+        trace_log!("set {offset} -> ctx -> {}", self.offset);
+        self
+    }
+
+    fn inc(&mut self, offset: usize) -> &mut Self {
+        self.offset = self.check_offset(self.offset.saturating_add(offset));
+        trace_log!("inc {offset} -> ctx -> {}", self.offset);
+        self
+    }
+
+    fn dec(&mut self, offset: usize) -> &mut Self {
+        debug_assert!(
+            offset <= self.offset,
+            "can not decrease offset {} by {offset}",
+            self.offset
+        );
+        self.offset = self.check_offset(self.offset.saturating_sub(offset));
+        trace_log!("dec {offset} -> ctx -> {}", self.offset);
+        self
+    }
+
+    fn orig_at(&self, offset: usize) -> Result<&'a Self::Orig, Error> {
+        self.dat.get(offset..).ok_or(Error::OriginOutOfBound)
+    }
+
+    fn peek_at(&self, offset: usize) -> Result<Self::Iter<'a>, Error> {
+        Ok(self.orig_at(offset)?.iter().enumerate())
+    }
+
+    fn orig_sub(&self, offset: usize, len: usize) -> Result<&'a Self::Orig, Error> {
+        self.dat
+            .get(offset..(offset + len))
+            .ok_or(Error::OriginOutOfBound)
+    }
+
+    fn clone_with(&self, orig: &'a Self::Orig) -> Self {
+        TokenCtx::new(orig)
+    }
+}
+
+impl<'a, T> Match<TokenCtx<'a, T>> for TokenCtx<'a, T> {
+    fn try_mat_t<Pat: Regex<TokenCtx<'a, T>> + ?Sized>(
+        &mut self,
+        pat: &Pat,
+    ) -> Result<Pat::Ret, Error> {
+        pat.try_parse(self)
+    }
+}
+
+impl<'a, T, R> Extract<'a, Self, R> for TokenCtx<'a, T> {
+    type Out<'b> = TokenCtx<'a, T>;
+
+    type Error = Error;
+
+    fn extract(ctx: &Self, _: &R) -> Result<Self::Out<'a>, Self::Error> {
+        Ok(Clone::clone(ctx))
+    }
+}
+
+impl<'a, T> TokenCtx<'a, T> {
+    pub fn ctor_with<H, A, P, M, O>(&mut self, pat: &P, handler: &mut H) -> Result<O, Error>
+    where
+        P: Ctor<'a, Self, M, O, H, A>,
+        H: Handler<A, Out = M, Error = Error>,
+        A: Extract<'a, Self, Span, Out<'a> = A, Error = Error>,
+    {
+        pat.construct(self, handler)
+    }
+
+    pub fn map_with<H, A, P, O>(&mut self, pat: &P, mut handler: H) -> Result<O, Error>
+    where
+        P: Regex<Self, Ret = Span>,
+        H: Handler<A, Out = O, Error = Error>,
+        A: Extract<'a, Self, P::Ret, Out<'a> = A, Error = Error>,
+    {
+        let ret = self.try_mat(pat)?;
+
+        handler.invoke(A::extract(self, &ret)?)
+    }
+
+    pub fn ctor<P, O>(&mut self, pat: &P) -> Result<O, Error>
+    where
+        P: Ctor<'a, Self, &'a [T], O, Pass, &'a [T]>,
+    {
+        self.ctor_with(pat, &mut Pass)
+    }
+
+    pub fn map<P, O>(&mut self, pat: &P, mapper: impl MapSingle<&'a [T], O>) -> Result<O, Error>
+    where
+        P: Regex<Self, Ret = Span>,
+    {
+        mapper.map_to(self.map_with(pat, Ok)?)
+    }
+
+    pub fn ctor_span<P, O>(&mut self, pat: &P) -> Result<O, Error>
+    where
+        P: Ctor<'a, Self, Span, O, Pass, Span>,
+    {
+        self.ctor_with(pat, &mut Pass)
+    }
+
+    pub fn map_span<P, O>(&mut self, pat: &P, mapper: impl MapSingle<Span, O>) -> Result<O, Error>
+    where
+        P: Regex<Self, Ret = Span>,
+    {
+        mapper.map_to(self.map_with(pat, Ok)?)
+    }
+}
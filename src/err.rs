@@ -1,5 +1,7 @@
 use std::fmt::Display;
 
+use crate::ctx::Context;
+
 #[non_exhaustive]
 #[derive(Debug, Clone, Copy)]
 pub enum Error {
@@ -7,11 +9,19 @@ pub enum Error {
 
     Not,
 
+    NotFollowedBy,
+
+    FollowedBy,
+
     Consume,
 
-    Slice,
+    Field,
+
+    Slice { at: usize },
+
+    String { at: usize },
 
-    String,
+    Keyword { at: usize },
 
     End,
 
@@ -27,23 +37,33 @@ pub enum Error {
 
     SelectEq,
 
+    Lookup,
+
+    SplitOnce,
+
+    CharCode,
+
     SepCollect,
 
+    SepString,
+
     Collect,
 
     Separate,
 
+    Interleave,
+
     RegexRepeat,
 
-    NeuRepeatRange,
+    NeuRepeatRange { at: usize },
 
-    NeuRepeat,
+    NeuRepeat { at: usize },
 
-    NeuOneMore,
+    NeuOneMore { at: usize },
 
-    NeuOne,
+    NeuOne { at: usize },
 
-    NeuThen,
+    NeuThen { at: usize },
 
     OriginOutOfBound,
 
@@ -53,27 +73,221 @@ pub enum Error {
 
     Utf8Error,
 
+    Duration,
+
+    IntWithSeparators,
+
     FromLeBytes,
 
     FromBeBytes,
 
     FromNeBytes,
 
+    BlockEnd { at: usize },
+
+    Expected { what: &'static str, at: usize },
+
+    TrailingInput,
+
+    TooFew,
+
+    ManyTillBounded,
+
+    Overflow,
+
+    GreedyUntil,
+
+    NonAscii { at: usize },
+
+    FromBase64,
+
+    SepEndBy,
+
+    TooManyErrors,
+
+    HexFloat,
+
+    ParseHexFloat,
+
+    SplitParse,
+
+    TakeTill,
+
+    IndexBy,
+
+    Empty,
+
+    ReadArray,
+
+    NoAlternative { tried: usize },
+
+    RepeatWhile,
+
+    DivByZero,
+
+    DepthExceeded,
+
+    FixedPoint,
+
+    LexStep,
+
+    LenConstraint { expect: usize, found: usize },
+
+    CopyInto { need: usize, remain: usize },
+
+    RepeatLazy,
+
+    NotCharBoundary { at: usize },
+
+    AtColumn { expect: usize, found: usize },
+
+    WordBoundary,
+
+    TakeUntil,
+
+    SepExactly,
+
+    Fold,
+
+    Verify,
+
+    NeedMore,
+
+    Io(std::io::ErrorKind),
+
+    Balanced,
+
+    NoProgress { at: usize },
+
     Other,
 
     Uid(usize),
 }
 
+impl Error {
+    /// Return true if the error should abort the parsing,
+    /// such as [`Error::TooManyErrors`], [`Error::DepthExceeded`] and [`Error::NoProgress`].
+    /// The fatal error will not be swallowed by the loops, such as [`repeat`](crate::re::ConstructOp::repeat),
+    /// [`sep`](crate::re::ConstructOp::sep) and [`collect`](crate::re::ConstructOp::collect),
+    /// or the alternatives, such as [`or`](crate::re::ConstructOp::or) and [`opt`](crate::re::ConstructOp::opt).
+    pub fn is_fatal(&self) -> bool {
+        matches!(
+            self,
+            Error::TooManyErrors | Error::DepthExceeded | Error::NoProgress { .. }
+        )
+    }
+
+    ///
+    /// Attach the data at the failure offset of `ctx` to the error, at most 16 items are captured.
+    ///
+    /// The failure offset is the [`offset`](Error::offset) of the error,
+    /// or the current offset of `ctx` if the error does not carry one.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use neure::prelude::*;
+    /// #
+    /// # fn main() -> color_eyre::Result<()> {
+    /// #     color_eyre::install()?;
+    ///     let kv = neu::ascii_alphabetic().repeat_one_more().sep_once("=", "on");
+    ///     let mut ctx = CharsCtx::new("debug=off");
+    ///     let err = ctx.ctor(&kv).unwrap_err().with_snippet(&ctx);
+    ///
+    ///     assert_eq!(err.text, "off");
+    ///     assert_eq!(err.at, 6);
+    ///     Ok(())
+    /// # }
+    /// ```
+    pub fn with_snippet<'a, C>(self, ctx: &C) -> Snippet
+    where
+        C: Context<'a> + 'a,
+        C::Item: Into<char>,
+    {
+        let at = self.offset().unwrap_or(ctx.offset());
+        let text = ctx
+            .peek_at(at)
+            .map(|iter| {
+                iter.take(SNIPPET_LEN)
+                    .map(|(_, item)| item.into())
+                    .collect()
+            })
+            .unwrap_or_default();
+
+        Snippet {
+            text,
+            at,
+            inner: self,
+        }
+    }
+
+    /// Return the offset where the error occurred if the error carries one.
+    pub fn offset(&self) -> Option<usize> {
+        match self {
+            Error::Slice { at }
+            | Error::String { at }
+            | Error::Keyword { at }
+            | Error::NeuRepeatRange { at }
+            | Error::NeuRepeat { at }
+            | Error::NeuOneMore { at }
+            | Error::NeuOne { at }
+            | Error::NeuThen { at }
+            | Error::Expected { at, .. }
+            | Error::NonAscii { at }
+            | Error::NotCharBoundary { at }
+            | Error::NoProgress { at }
+            | Error::BlockEnd { at } => Some(*at),
+            _ => None,
+        }
+    }
+}
+
 impl std::error::Error for Error {}
 
+/// The count of items captured by [`Error::with_snippet`].
+const SNIPPET_LEN: usize = 16;
+
+///
+/// The [`Error`] with the data at the failure offset, see [`Error::with_snippet`].
+///
+#[derive(Debug, Clone)]
+pub struct Snippet {
+    pub text: String,
+    pub at: usize,
+    pub inner: Error,
+}
+
+impl Display for Snippet {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(
+            f,
+            "{} (near `{}` at offset {})",
+            self.inner, self.text, self.at
+        )
+    }
+}
+
+impl std::error::Error for Snippet {
+    fn source(&self) -> Option<&(dyn std::error::Error + 'static)> {
+        Some(&self.inner)
+    }
+}
+
 impl Display for Error {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
         match self {
             Error::Null => write!(f, "Error::Null"),
             Error::Not => write!(f, "In (`not`): got error when invoke regex"),
+            Error::NotFollowedBy => write!(f, "In (`not_followed_by`): lookahead matched"),
+            Error::FollowedBy => write!(f, "In (`followed_by`): lookahead not matched"),
             Error::Consume => write!(f, "In (`consume`): need more data"),
-            Error::Slice => write!(f, "In (`slice`): bytes not equal"),
-            Error::String => write!(f, "In (`string`): string not equal"),
+            Error::Field => write!(f, "In (`field`): need more data or not a valid boundary"),
+            Error::Slice { at } => write!(f, "In (`slice`): bytes not equal at offset {at}"),
+            Error::String { at } => write!(f, "In (`string`): string not equal at offset {at}"),
+            Error::Keyword { at } => write!(
+                f,
+                "In (`keyword`): string not equal or followed by a word character at offset {at}"
+            ),
             Error::End => write!(f, "In (`end`): offset is not at the ending"),
             Error::Start => write!(f, "In (`start`): offset is not at the begining"),
             Error::LockMutex => write!(f, "Can not lock mutex for regex"),
@@ -81,22 +295,114 @@ impl Display for Error {
             Error::FromStr => write!(f, "In (`FromStr`): got error in `from_str_radix`"),
             Error::TryInto => write!(f, "In (`MapTryInto`): got error in `TryInto::try_into`"),
             Error::SelectEq => write!(f, "In (`SelectEq`): tuple.0 and tuple.1 not equal"),
+            Error::Lookup => write!(f, "In (`Lookup`): value not found in the table"),
+            Error::SplitOnce => write!(f, "In (`SplitOnce`): delimiter not found"),
+            Error::CharCode => write!(f, "In (`CharCode`): value is not exactly one char"),
             Error::SepCollect => write!(f, "In (`SepCollect`): need more data"),
+            Error::SepString => write!(f, "In (`SepString`): need more data"),
             Error::Collect => write!(f, "In (`Collect`): need more data"),
             Error::Separate => write!(f, "In (`Separate`): need more data"),
+            Error::Interleave => write!(f, "In (`Interleave`): need more data"),
             Error::RegexRepeat => write!(f, "In (`RegexRepeat`): need more data"),
-            Error::NeuRepeatRange => write!(f, "In (`NeuRepeatRange`): need more data"),
-            Error::NeuRepeat => write!(f, "In (`NeuRepeat`): need more data"),
-            Error::NeuOneMore => write!(f, "In (`NeuOneMore`): need more data"),
-            Error::NeuOne => write!(f, "In (`NeuOne`): need more data"),
-            Error::NeuThen => write!(f, "In (`NeuThen`): need more data"),
+            Error::NeuRepeatRange { at } => {
+                write!(f, "In (`NeuRepeatRange`): need more data at offset {at}")
+            }
+            Error::NeuRepeat { at } => {
+                write!(f, "In (`NeuRepeat`): need more data at offset {at}")
+            }
+            Error::NeuOneMore { at } => {
+                write!(f, "In (`NeuOneMore`): need more data at offset {at}")
+            }
+            Error::NeuOne { at } => write!(f, "In (`NeuOne`): need more data at offset {at}"),
+            Error::NeuThen { at } => write!(f, "In (`NeuThen`): need more data at offset {at}"),
             Error::Vec => write!(f, "In (`Vec`): all match failed"),
             Error::PairVec => write!(f, "In (`Hash`): all match failed"),
             Error::OriginOutOfBound => write!(f, "Offset out of bound"),
             Error::Utf8Error => write!(f, "In (`FromUtf8`): catch `Utf8Error` or `FromUtf8Error`"),
+            Error::Duration => write!(f, "In (`duration`): invalid duration format or overflow"),
+            Error::IntWithSeparators => write!(
+                f,
+                "In (`int_with_separators`): separator must be between two digits"
+            ),
             Error::FromLeBytes => write!(f, "In (`FromLeBytes`): need more bytes for given type"),
             Error::FromBeBytes => write!(f, "In (`FromBeBytes`): need more bytes for given type"),
             Error::FromNeBytes => write!(f, "In (`FromNeBytes`): need more bytes for given type"),
+            Error::BlockEnd { at } => {
+                write!(f, "In (`block`): expect the end of block at offset {at}")
+            }
+            Error::Expected { what, at } => write!(f, "expected {what} at offset {at}"),
+            Error::TrailingInput => write!(f, "In (`eof`): input remains after the offset"),
+            Error::TooFew => write!(f, "In (`sep_two_plus`): need at least two elements"),
+            Error::ManyTillBounded => {
+                write!(
+                    f,
+                    "In (`many_till_bounded`): failed before terminator or maximum"
+                )
+            }
+            Error::Overflow => write!(f, "In (`map`): value out of range of target type"),
+            Error::GreedyUntil => write!(f, "In (`greedy_until`): can not match the terminator"),
+            Error::NonAscii { at } => {
+                write!(
+                    f,
+                    "In (`require_ascii`): found non-ASCII byte at offset {at}"
+                )
+            }
+            Error::FromBase64 => write!(f, "In (`FromBase64`): invalid base64 data"),
+            Error::SepEndBy => write!(f, "In (`sep_end_by`): every element need a delimiter"),
+            Error::TooManyErrors => write!(f, "In (`ErrorSink`): too many errors recovered"),
+            Error::HexFloat => write!(
+                f,
+                "In (`hex_float`): not a hexadecimal floating-point literal"
+            ),
+            Error::ParseHexFloat => {
+                write!(
+                    f,
+                    "In (`ParseHexFloat`): invalid hexadecimal floating-point literal"
+                )
+            }
+            Error::SplitParse => write!(f, "In (`SplitParse`): can not parse the part"),
+            Error::TakeTill => write!(f, "In (`take_till`): can not find the terminator byte"),
+            Error::IndexBy => write!(f, "In (`index_by`): found duplicate key"),
+            Error::Empty => write!(f, "In (`NonEmpty`): the value is empty"),
+            Error::ReadArray => write!(f, "In (`read_array_le`): need more bytes for the array"),
+            Error::NoAlternative { tried } => {
+                write!(f, "In (`or_any`): none of the {tried} alternatives matched")
+            }
+            Error::RepeatWhile => write!(f, "In (`repeat_while`): need more data"),
+            Error::DivByZero => write!(f, "In (`AsRatio`): the denominator is zero"),
+            Error::DepthExceeded => write!(f, "In (`max_depth`): the nesting is too deep"),
+            Error::FixedPoint => write!(f, "In (`FixedPoint`): invalid decimal for the scale"),
+            Error::LexStep => write!(f, "In (`lex_step`): no rule matched"),
+            Error::LenConstraint { expect, found } => {
+                write!(f, "In (`ToArray`): expect {expect} elements, found {found}")
+            }
+            Error::CopyInto { need, remain } => {
+                write!(
+                    f,
+                    "In (`copy_into`): need {need} bytes, but only {remain} remaining"
+                )
+            }
+            Error::RepeatLazy => write!(f, "In (`repeat_lazy`): can not match the terminator"),
+            Error::NotCharBoundary { at } => {
+                write!(f, "In (`Utf8Checked`): offset {at} is not a char boundary")
+            }
+            Error::AtColumn { expect, found } => {
+                write!(f, "In (`at_column`): expect column {expect}, found {found}")
+            }
+            Error::WordBoundary => write!(f, "In (`word_boundary`): word boundary not match"),
+            Error::TakeUntil => write!(f, "In (`take_until`): can not match the pattern"),
+            Error::SepExactly => write!(f, "In (`sep_exactly`): element count not match"),
+            Error::Fold => write!(f, "In (`fold`): need more data"),
+            Error::Verify => write!(f, "In (`verify`): the predicate rejected the value"),
+            Error::NeedMore => write!(f, "In (`ReadCtx`): need more data but reached EOF"),
+            Error::Io(kind) => write!(f, "In (`ReadCtx`): got io error: {kind}"),
+            Error::Balanced => write!(f, "In (`balanced`): the group is not closed"),
+            Error::NoProgress { at } => {
+                write!(
+                    f,
+                    "In (`repeat_with_progress_guard`): no progress at offset {at}"
+                )
+            }
             Error::Other => write!(f, "Error::Other"),
             Error::Uid(id) => write!(f, "Got error(id = {id})"),
         }
@@ -1,9 +1,11 @@
 mod byte;
+mod scan;
 mod span;
 
 use crate::ctx::Span;
 
 pub use self::byte::BytesIndices;
+pub use self::scan::ScanIter;
 pub use self::span::IteratorBySpan;
 pub use self::span::SpanIterator;
 
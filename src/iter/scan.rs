@@ -0,0 +1,39 @@
+///
+/// Iterator adapter which add a base offset to the item index,
+/// the index of [`peek_at`](crate::ctx::Context::peek_at) is relative to the given offset.
+///
+#[derive(Debug, Clone)]
+pub struct ScanIter<I> {
+    base: usize,
+
+    iter: I,
+}
+
+impl<I> ScanIter<I> {
+    pub fn new(base: usize, iter: I) -> Self {
+        Self { base, iter }
+    }
+
+    pub fn base(&self) -> usize {
+        self.base
+    }
+}
+
+impl<I, T> Iterator for ScanIter<I>
+where
+    I: Iterator<Item = (usize, T)>,
+{
+    type Item = (usize, T);
+
+    fn next(&mut self) -> Option<Self::Item> {
+        self.iter
+            .next()
+            .map(|(offset, item)| (self.base + offset, item))
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        self.iter.size_hint()
+    }
+}
+
+impl<I, T> ExactSizeIterator for ScanIter<I> where I: ExactSizeIterator<Item = (usize, T)> {}
@@ -42,6 +42,7 @@ pub mod prelude {
     pub use crate::ctx::RegexCtx;
     pub use crate::ctx::Ret;
     pub use crate::ctx::Span;
+    pub use crate::ctx::TokenCtx;
     pub use crate::map;
     pub use crate::neu;
     pub use crate::neu::Condition;
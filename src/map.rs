@@ -1,4 +1,7 @@
-use std::{borrow::Cow, marker::PhantomData, mem::size_of, num::ParseIntError};
+use std::{
+    borrow::Borrow, borrow::Cow, marker::PhantomData, mem::size_of, num::ParseIntError,
+    time::Duration,
+};
 
 use crate::err::Error;
 
@@ -19,6 +22,20 @@ where
     }
 }
 
+/// Same as [`MapSingle`], but the value is borrowed, so the caller still owns it on failure.
+pub trait MapRef<I, O> {
+    fn map_ref(&self, val: &I) -> Result<O, Error>;
+}
+
+impl<I, O, F> MapRef<I, O> for F
+where
+    F: Fn(&I) -> Result<O, Error>,
+{
+    fn map_ref(&self, val: &I) -> Result<O, Error> {
+        (self)(val)
+    }
+}
+
 #[derive(Debug, Clone, Copy, Default, PartialEq, Eq, PartialOrd, Ord)]
 pub struct Single;
 
@@ -102,6 +119,261 @@ pub fn select_eq() -> SelectEq {
     SelectEq::new()
 }
 
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, PartialOrd, Ord)]
+pub struct MapFst<F>(F);
+
+impl<F> MapFst<F> {
+    pub fn new(f: F) -> Self {
+        Self(f)
+    }
+}
+
+impl<I1, I2, O1, F> MapSingle<(I1, I2), (O1, I2)> for MapFst<F>
+where
+    F: MapSingle<I1, O1>,
+{
+    fn map_to(&self, val: (I1, I2)) -> Result<(O1, I2), Error> {
+        Ok((self.0.map_to(val.0)?, val.1))
+    }
+}
+
+///
+/// Map the first element of a tuple, leaving the second element unchanged.
+///
+/// # Example
+///
+/// ```
+/// # use neure::prelude::*;
+/// #
+/// # fn main() -> color_eyre::Result<()> {
+/// #     color_eyre::install()?;
+///     let num = neu::digit(10).repeat_one_more();
+///     let name = neu::ascii_alphabetic().repeat_one_more();
+///     let pair = num
+///         .sep_once(",", name)
+///         .map(map::map_fst(map::from_str::<i32>()));
+///
+///     assert_eq!(CharsCtx::new("42,x").ctor(&pair)?, (42, "x"));
+///     assert!(CharsCtx::new("4x,x").ctor(&pair).is_err());
+///
+///     Ok(())
+/// # }
+/// ```
+#[inline(always)]
+pub fn map_fst<F>(f: F) -> MapFst<F> {
+    MapFst::new(f)
+}
+
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, PartialOrd, Ord)]
+pub struct MapSnd<F>(F);
+
+impl<F> MapSnd<F> {
+    pub fn new(f: F) -> Self {
+        Self(f)
+    }
+}
+
+impl<I1, I2, O2, F> MapSingle<(I1, I2), (I1, O2)> for MapSnd<F>
+where
+    F: MapSingle<I2, O2>,
+{
+    fn map_to(&self, val: (I1, I2)) -> Result<(I1, O2), Error> {
+        Ok((val.0, self.0.map_to(val.1)?))
+    }
+}
+
+///
+/// Map the second element of a tuple, leaving the first element unchanged.
+///
+/// # Example
+///
+/// ```
+/// # use neure::prelude::*;
+/// #
+/// # fn main() -> color_eyre::Result<()> {
+/// #     color_eyre::install()?;
+///     let name = neu::ascii_alphabetic().repeat_one_more();
+///     let num = neu::digit(10).repeat_one_more();
+///     let pair = name
+///         .sep_once("=", num)
+///         .map(map::map_snd(map::from_str::<u64>()));
+///
+///     assert_eq!(CharsCtx::new("x=42").ctor(&pair)?, ("x", 42));
+///
+///     Ok(())
+/// # }
+/// ```
+#[inline(always)]
+pub fn map_snd<F>(f: F) -> MapSnd<F> {
+    MapSnd::new(f)
+}
+
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, PartialOrd, Ord)]
+pub struct MapBoth<F, G> {
+    fst: F,
+    snd: G,
+}
+
+impl<F, G> MapBoth<F, G> {
+    pub fn new(fst: F, snd: G) -> Self {
+        Self { fst, snd }
+    }
+}
+
+impl<I1, I2, O1, O2, F, G> MapSingle<(I1, I2), (O1, O2)> for MapBoth<F, G>
+where
+    F: MapSingle<I1, O1>,
+    G: MapSingle<I2, O2>,
+{
+    fn map_to(&self, val: (I1, I2)) -> Result<(O1, O2), Error> {
+        Ok((self.fst.map_to(val.0)?, self.snd.map_to(val.1)?))
+    }
+}
+
+///
+/// Map both elements of a tuple with separate mappers.
+///
+/// # Example
+///
+/// ```
+/// # use neure::prelude::*;
+/// #
+/// # fn main() -> color_eyre::Result<()> {
+/// #     color_eyre::install()?;
+///     let num = neu::digit(10).repeat_one_more();
+///     let pair = num
+///         .sep_once(":", num)
+///         .map(map::map_both(map::from_str::<u8>(), map::from_str::<i64>()));
+///
+///     assert_eq!(CharsCtx::new("42:1024").ctor(&pair)?, (42u8, 1024i64));
+///     assert!(CharsCtx::new("420:1").ctor(&pair).is_err());
+///
+///     Ok(())
+/// # }
+/// ```
+#[inline(always)]
+pub fn map_both<F, G>(fst: F, snd: G) -> MapBoth<F, G> {
+    MapBoth::new(fst, snd)
+}
+
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, PartialOrd, Ord)]
+pub struct TryMapRef<F, G> {
+    mapper: F,
+    fallback: G,
+}
+
+impl<F, G> TryMapRef<F, G> {
+    pub fn new(mapper: F, fallback: G) -> Self {
+        Self { mapper, fallback }
+    }
+
+    pub fn mapper(&self) -> &F {
+        &self.mapper
+    }
+
+    pub fn fallback(&self) -> &G {
+        &self.fallback
+    }
+}
+
+impl<I, O, F, G> MapSingle<I, O> for TryMapRef<F, G>
+where
+    F: MapRef<I, O>,
+    G: Fn(I, Error) -> Result<O, Error>,
+{
+    fn map_to(&self, val: I) -> Result<O, Error> {
+        match self.mapper.map_ref(&val) {
+            Ok(ret) => Ok(ret),
+            Err(e) => (self.fallback)(val, e),
+        }
+    }
+}
+
+///
+/// Map the value by reference with `mapper`, if it fails,
+/// pass the original value and the error to `fallback`.
+///
+/// # Example
+///
+/// ```
+/// # use neure::prelude::*;
+/// #
+/// # fn main() -> color_eyre::Result<()> {
+/// #     color_eyre::install()?;
+///     let port = neu::digit(10).repeat_one_more();
+///     let port = port.map(map::try_map_ref(
+///         |v: &&str| str::parse::<u16>(v).map_err(|_| neure::err::Error::FromStr),
+///         |v: &str, _| Ok(if v.len() > 5 { u16::MAX } else { 0 }),
+///     ));
+///
+///     assert_eq!(CharsCtx::new("8080").ctor(&port)?, 8080);
+///     assert_eq!(CharsCtx::new("99999").ctor(&port)?, 0);
+///     assert_eq!(CharsCtx::new("1234567").ctor(&port)?, u16::MAX);
+///     Ok(())
+/// # }
+/// ```
+#[inline(always)]
+pub fn try_map_ref<F, G>(mapper: F, fallback: G) -> TryMapRef<F, G> {
+    TryMapRef::new(mapper, fallback)
+}
+
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, PartialOrd, Ord)]
+pub struct AssertRoundTrip<F>(F);
+
+impl<F> AssertRoundTrip<F> {
+    pub fn new(render: F) -> Self {
+        Self(render)
+    }
+
+    pub fn render(&self) -> &F {
+        &self.0
+    }
+}
+
+impl<'a, O, F, R, T> MapSingle<(O, &'a T), O> for AssertRoundTrip<F>
+where
+    T: ?Sized + PartialEq + std::fmt::Debug,
+    R: AsRef<T>,
+    F: Fn(&O) -> R,
+{
+    fn map_to(&self, val: (O, &'a T)) -> Result<O, Error> {
+        debug_assert_eq!(
+            (self.0)(&val.0).as_ref(),
+            val.1,
+            "the rendered value is not equal to the matched data"
+        );
+        Ok(val.0)
+    }
+}
+
+///
+/// Render the value with `render` and assert it equal to the matched data, return the value.
+///
+/// The input is a tuple of the value and the matched data,
+/// such as the result of [`with_slice`](crate::re::ConstructOp::with_slice).
+/// It panics if not equal in debug mode, and does nothing in release mode.
+///
+/// # Example
+///
+/// ```
+/// # use neure::prelude::*;
+/// #
+/// # fn main() -> color_eyre::Result<()> {
+/// #     color_eyre::install()?;
+///     let num = neu::digit(10).repeat_one_more().map(map::from_str::<u32>());
+///     let num = num
+///         .with_slice()
+///         .map(map::assert_round_trip(|v: &u32| v.to_string()));
+///
+///     assert_eq!(CharsCtx::new("42").ctor(&num)?, 42);
+///     Ok(())
+/// # }
+/// ```
+#[inline(always)]
+pub fn assert_round_trip<F>(render: F) -> AssertRoundTrip<F> {
+    AssertRoundTrip::new(render)
+}
+
 #[derive(Debug, Copy, PartialEq, Eq, PartialOrd, Ord)]
 pub struct FromStr<T>(PhantomData<T>);
 
@@ -207,6 +479,146 @@ pub fn try_into<T>() -> MapTryInto<T> {
     MapTryInto::new()
 }
 
+#[derive(Debug, Copy, PartialEq, Eq, PartialOrd, Ord)]
+pub struct Cast<S, D>(PhantomData<(S, D)>);
+
+impl<S, D> Clone for Cast<S, D> {
+    fn clone(&self) -> Self {
+        Self(self.0)
+    }
+}
+
+impl<S, D> Cast<S, D> {
+    pub fn new() -> Self {
+        Self(PhantomData)
+    }
+}
+
+impl<S, D> Default for Cast<S, D> {
+    fn default() -> Self {
+        Self(Default::default())
+    }
+}
+
+impl<S, D> MapSingle<S, D> for Cast<S, D>
+where
+    D: TryFrom<S>,
+{
+    fn map_to(&self, val: S) -> Result<D, Error> {
+        D::try_from(val).map_err(|_| Error::Overflow)
+    }
+}
+
+///
+/// Cast the integer `S` to `D` using [`TryFrom`], return [`Error::Overflow`] if out of range.
+///
+/// # Example
+///
+/// ```
+/// # use neure::prelude::*;
+/// #
+/// # fn main() -> color_eyre::Result<()> {
+/// #     color_eyre::install()?;
+///     let num = neu::digit(10).repeat_one_more().map(map::from_str::<i64>());
+///     let byte = num.map(map::cast::<i64, u8>());
+///
+///     assert_eq!(CharsCtx::new("42").ctor(&byte)?, 42u8);
+///     assert!(matches!(
+///         CharsCtx::new("300").ctor(&byte),
+///         Err(neure::err::Error::Overflow)
+///     ));
+///     Ok(())
+/// # }
+/// ```
+pub fn cast<S, D>() -> Cast<S, D> {
+    Cast::new()
+}
+
+#[cfg(feature = "base64")]
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, PartialOrd, Ord)]
+pub struct FromBase64 {
+    url_safe: bool,
+}
+
+#[cfg(feature = "base64")]
+impl FromBase64 {
+    pub fn new() -> Self {
+        Self { url_safe: false }
+    }
+
+    pub fn url_safe() -> Self {
+        Self { url_safe: true }
+    }
+
+    pub fn is_url_safe(&self) -> bool {
+        self.url_safe
+    }
+}
+
+#[cfg(feature = "base64")]
+impl<I> MapSingle<I, Vec<u8>> for FromBase64
+where
+    I: AsRef<[u8]>,
+{
+    fn map_to(&self, val: I) -> Result<Vec<u8>, Error> {
+        use base64::engine::general_purpose::STANDARD;
+        use base64::engine::general_purpose::URL_SAFE;
+        use base64::Engine;
+
+        if self.url_safe {
+            URL_SAFE.decode(val)
+        } else {
+            STANDARD.decode(val)
+        }
+        .map_err(|_| Error::FromBase64)
+    }
+}
+
+///
+/// Decode the value as base64 with the standard alphabet.
+///
+/// # Example
+///
+/// ```
+/// # use neure::prelude::*;
+/// #
+/// # fn main() -> color_eyre::Result<()> {
+/// #     color_eyre::install()?;
+///     let b64 = neu::ascii_alphanumeric().or('+').or('/').or('=');
+///     let b64 = b64.repeat_one_more().map(map::from_base64());
+///
+///     assert_eq!(CharsCtx::new("aGVsbG8=").ctor(&b64)?, b"hello");
+///     assert!(CharsCtx::new("aGVsbG8").ctor(&b64).is_err());
+///     Ok(())
+/// # }
+/// ```
+#[cfg(feature = "base64")]
+pub fn from_base64() -> FromBase64 {
+    FromBase64::new()
+}
+
+///
+/// Decode the value as base64 with the URL-safe alphabet.
+///
+/// # Example
+///
+/// ```
+/// # use neure::prelude::*;
+/// #
+/// # fn main() -> color_eyre::Result<()> {
+/// #     color_eyre::install()?;
+///     let b64 = neu::ascii_alphanumeric().or('-').or('_').or('=');
+///     let b64 = b64.repeat_one_more().map(map::from_base64_url_safe());
+///
+///     assert_eq!(CharsCtx::new("-_8=").ctor(&b64)?, [0xfb, 0xff]);
+///     Ok(())
+/// # }
+/// ```
+#[cfg(feature = "base64")]
+pub fn from_base64_url_safe() -> FromBase64 {
+    FromBase64::url_safe()
+}
+
 pub trait TryFromStrRadix
 where
     Self: Sized,
@@ -293,33 +705,280 @@ pub fn from_str_radix<T: TryFromStrRadix>(radix: u32) -> FromStrRadix<T> {
 }
 
 #[derive(Debug, Copy, PartialEq, Eq, PartialOrd, Ord)]
-pub struct FromUtf8<T>(PhantomData<T>);
-
-impl<T> FromUtf8<T> {
-    pub fn new() -> Self {
-        Self(PhantomData)
-    }
+pub struct FromStrRadixSep<T> {
+    radix: u32,
+    marker: PhantomData<T>,
 }
 
-impl<T> Clone for FromUtf8<T> {
+impl<T> Clone for FromStrRadixSep<T> {
     fn clone(&self) -> Self {
-        Self(self.0)
+        Self {
+            radix: self.radix,
+            marker: self.marker,
+        }
     }
 }
 
-impl<T> Default for FromUtf8<T> {
+impl<T> Default for FromStrRadixSep<T> {
     fn default() -> Self {
-        Self(Default::default())
+        Self {
+            radix: Default::default(),
+            marker: Default::default(),
+        }
     }
 }
 
-impl<'a> MapSingle<&'a [u8], &'a str> for FromUtf8<&'a str> {
-    fn map_to(&self, val: &'a [u8]) -> Result<&'a str, Error> {
-        std::str::from_utf8(val).map_err(|_| Error::Utf8Error)
+impl<T> FromStrRadixSep<T>
+where
+    T: TryFromStrRadix,
+{
+    pub fn new(radix: u32) -> Self {
+        Self {
+            radix,
+            marker: PhantomData,
+        }
     }
-}
 
-impl<'a> MapSingle<&'a [u8], String> for FromUtf8<String> {
+    pub fn radix(&self) -> u32 {
+        self.radix
+    }
+}
+
+impl<I, O> MapSingle<I, O> for FromStrRadixSep<O>
+where
+    O: TryFromStrRadix,
+    I: AsRef<str>,
+{
+    #[inline(always)]
+    fn map_to(&self, val: I) -> Result<O, Error> {
+        let val = val.as_ref();
+        let ret = if val.contains('_') {
+            O::from_str_radix(&val.replace('_', ""), self.radix())
+        } else {
+            O::from_str_radix(val, self.radix())
+        };
+
+        ret.map_err(|_| Error::FromStr)
+    }
+}
+
+///
+/// Remove the separators `_` and convert the value using `from_str_radix`.
+///
+/// # Example
+///
+/// ```
+/// # use neure::prelude::*;
+/// #
+/// # fn main() -> color_eyre::Result<()> {
+/// #     color_eyre::install()?;
+///     let bin = neu::digit_or_underscore(2).repeat_one_more();
+///     let bin = bin.map(map::from_str_radix_sep::<u8>(2));
+///
+///     assert_eq!(CharsCtx::new("1111_0000").ctor(&bin)?, 0xf0);
+///     assert_eq!(CharsCtx::new("101").ctor(&bin)?, 5);
+///     Ok(())
+/// # }
+/// ```
+#[inline(always)]
+pub fn from_str_radix_sep<T: TryFromStrRadix>(radix: u32) -> FromStrRadixSep<T> {
+    FromStrRadixSep::new(radix)
+}
+
+pub trait TryFromHexFloat
+where
+    Self: Sized,
+{
+    fn from_hex_float(src: &str) -> Result<Self, Error>;
+}
+
+/// Split the hexadecimal floating-point literal into the sign, mantissa and binary exponent.
+///
+/// The last value is true if some non-zero digits were dropped from the mantissa.
+fn hex_float_parts(src: &str) -> Option<(bool, u64, i32, bool)> {
+    let (neg, src) = match src.as_bytes().first()? {
+        b'-' => (true, &src[1..]),
+        b'+' => (false, &src[1..]),
+        _ => (false, src),
+    };
+    let src = src.strip_prefix("0x").or_else(|| src.strip_prefix("0X"))?;
+    let (digits, exp) = src.split_once(['p', 'P'])?;
+    let (int, frac) = digits.split_once('.').unwrap_or((digits, ""));
+    let mut exp = exp.parse::<i32>().ok()?;
+    let mut mantissa = 0u64;
+    let mut sticky = false;
+
+    if int.is_empty() && frac.is_empty() {
+        return None;
+    }
+    for (idx, ch) in int.chars().chain(frac.chars()).enumerate() {
+        let digit = ch.to_digit(16)? as u64;
+        let is_frac = idx >= int.len();
+
+        if mantissa > (u64::MAX >> 4) {
+            // drop the digits can not be represented
+            sticky |= digit != 0;
+            if !is_frac {
+                exp = exp.checked_add(4)?;
+            }
+        } else {
+            mantissa = (mantissa << 4) | digit;
+            if is_frac {
+                exp = exp.checked_sub(4)?;
+            }
+        }
+    }
+    Some((neg, mantissa, exp, sticky))
+}
+
+/// Round `mantissa * 2^exp` to nearest even with `bits` significant bits,
+/// the exponent of the least significant bit is at least `min_exp`.
+///
+/// Return the rounded mantissa and exponent, the value is exactly `mantissa * 2^exp`.
+fn round_hex_float(mantissa: u64, exp: i32, sticky: bool, bits: u32, min_exp: i32) -> (u64, i64) {
+    if mantissa == 0 {
+        return (0, 0);
+    }
+    // move the highest bit to the top of u128
+    let shift = mantissa.leading_zeros() + 64;
+    let val = (mantissa as u128) << shift;
+    let exp = exp as i64 - shift as i64;
+    let drop = (128 - bits as i64).max(min_exp as i64 - exp);
+
+    if drop > 128 {
+        return (0, 0);
+    }
+    let (kept, rem) = if drop == 128 {
+        (0, val)
+    } else {
+        (val >> drop, val & ((1 << drop) - 1))
+    };
+    let half = 1u128 << (drop - 1);
+    let up = rem > half || (rem == half && (sticky || kept & 1 == 1));
+
+    (kept as u64 + up as u64, exp + drop)
+}
+
+/// Return `mantissa * 2^exp`, the `mantissa` should have 53 significant bits at most.
+fn scale_f64(mantissa: u64, exp: i64) -> f64 {
+    let pow2 = |exp: i64| f64::from_bits(((exp + 1023) as u64) << 52);
+
+    if mantissa == 0 {
+        0.0
+    } else if exp > 971 {
+        f64::INFINITY
+    } else if exp < -1022 {
+        // scale in two steps, 2^exp is not representable
+        mantissa as f64 * pow2(exp + 1022) * pow2(-1022)
+    } else {
+        mantissa as f64 * pow2(exp)
+    }
+}
+
+impl TryFromHexFloat for f64 {
+    fn from_hex_float(src: &str) -> Result<Self, Error> {
+        let (neg, mantissa, exp, sticky) = hex_float_parts(src).ok_or(Error::ParseHexFloat)?;
+        let (mantissa, exp) = round_hex_float(mantissa, exp, sticky, 53, -1074);
+        let val = scale_f64(mantissa, exp);
+
+        Ok(if neg { -val } else { val })
+    }
+}
+
+impl TryFromHexFloat for f32 {
+    fn from_hex_float(src: &str) -> Result<Self, Error> {
+        let (neg, mantissa, exp, sticky) = hex_float_parts(src).ok_or(Error::ParseHexFloat)?;
+        let (mantissa, exp) = round_hex_float(mantissa, exp, sticky, 24, -149);
+        // the rounded value is exact in f64
+        let val = scale_f64(mantissa, exp) as f32;
+
+        Ok(if neg { -val } else { val })
+    }
+}
+
+#[derive(Debug, Copy, PartialEq, Eq, PartialOrd, Ord)]
+pub struct ParseHexFloat<T>(PhantomData<T>);
+
+impl<T> Clone for ParseHexFloat<T> {
+    fn clone(&self) -> Self {
+        Self(self.0)
+    }
+}
+
+impl<T> Default for ParseHexFloat<T> {
+    fn default() -> Self {
+        Self(Default::default())
+    }
+}
+
+impl<T> ParseHexFloat<T> {
+    pub fn new() -> Self {
+        Self(PhantomData)
+    }
+}
+
+impl<I, O> MapSingle<I, O> for ParseHexFloat<O>
+where
+    O: TryFromHexFloat,
+    I: AsRef<str>,
+{
+    fn map_to(&self, val: I) -> Result<O, Error> {
+        O::from_hex_float(val.as_ref())
+    }
+}
+
+///
+/// Parse the C99 hexadecimal floating-point literal, such as the literal matched by
+/// [`hex_float`](crate::re::hex_float).
+///
+/// # Example
+///
+/// ```
+/// # use neure::{prelude::*, map::MapSingle};
+/// #
+/// # fn main() -> color_eyre::Result<()> {
+/// #     color_eyre::install()?;
+///     let parser = map::parse_hex_float::<f64>();
+///
+///     assert_eq!(parser.map_to("0x1.8p3")?, 12.0);
+///     assert_eq!(parser.map_to("-0X.8P-1")?, -0.25);
+///     assert_eq!(map::parse_hex_float::<f32>().map_to("0xAp0")?, 10.0);
+///     assert!(parser.map_to("0x1.8").is_err());
+///     Ok(())
+/// # }
+/// ```
+pub fn parse_hex_float<T: TryFromHexFloat>() -> ParseHexFloat<T> {
+    ParseHexFloat::new()
+}
+
+#[derive(Debug, Copy, PartialEq, Eq, PartialOrd, Ord)]
+pub struct FromUtf8<T>(PhantomData<T>);
+
+impl<T> FromUtf8<T> {
+    pub fn new() -> Self {
+        Self(PhantomData)
+    }
+}
+
+impl<T> Clone for FromUtf8<T> {
+    fn clone(&self) -> Self {
+        Self(self.0)
+    }
+}
+
+impl<T> Default for FromUtf8<T> {
+    fn default() -> Self {
+        Self(Default::default())
+    }
+}
+
+impl<'a> MapSingle<&'a [u8], &'a str> for FromUtf8<&'a str> {
+    fn map_to(&self, val: &'a [u8]) -> Result<&'a str, Error> {
+        std::str::from_utf8(val).map_err(|_| Error::Utf8Error)
+    }
+}
+
+impl<'a> MapSingle<&'a [u8], String> for FromUtf8<String> {
     fn map_to(&self, val: &'a [u8]) -> Result<String, Error> {
         String::from_utf8(val.to_vec()).map_err(|_| Error::Utf8Error)
     }
@@ -362,6 +1021,1218 @@ pub fn from_utf8_lossy<T>() -> FromUtf8Lossy<T> {
     FromUtf8Lossy::default()
 }
 
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord)]
+pub struct Utf8Checked<'b> {
+    full: &'b [u8],
+}
+
+impl<'b> Utf8Checked<'b> {
+    pub fn new(full: &'b [u8]) -> Self {
+        Self { full }
+    }
+
+    pub fn full(&self) -> &'b [u8] {
+        self.full
+    }
+
+    fn is_char_boundary(&self, at: usize) -> bool {
+        // the continuation bytes of UTF-8 are `0b10xx_xxxx`
+        self.full.get(at).is_none_or(|v| (v & 0xc0) != 0x80)
+    }
+}
+
+impl<'a> MapSingle<&'a [u8], &'a str> for Utf8Checked<'_> {
+    fn map_to(&self, val: &'a [u8]) -> Result<&'a str, Error> {
+        let beg = (val.as_ptr() as usize)
+            .checked_sub(self.full.as_ptr() as usize)
+            .filter(|beg| beg + val.len() <= self.full.len())
+            .ok_or(Error::OriginOutOfBound)?;
+        let end = beg + val.len();
+
+        for at in [beg, end] {
+            if !self.is_char_boundary(at) {
+                return Err(Error::NotCharBoundary { at });
+            }
+        }
+        std::str::from_utf8(val).map_err(|_| Error::Utf8Error)
+    }
+}
+
+///
+/// Convert the bytes to [`&str`](str), the bytes must be a slice of the `full` buffer.
+/// Return [`Error::NotCharBoundary`] if the start or end of the bytes splits a multibyte char
+/// of the `full` buffer, or [`Error::OriginOutOfBound`] if the bytes are not inside the `full` buffer.
+///
+/// # Example
+///
+/// ```
+/// # use neure::prelude::*;
+/// #
+/// # fn main() -> color_eyre::Result<()> {
+/// #     color_eyre::install()?;
+///     let bytes = "añb".as_bytes();
+///
+///     let two = re::consume(3).map(map::utf8_checked(bytes));
+///     assert_eq!(BytesCtx::new(bytes).ctor(&two)?, "añ");
+///
+///     let two = re::consume(2).map(map::utf8_checked(bytes));
+///     assert!(matches!(
+///         BytesCtx::new(bytes).ctor(&two),
+///         Err(neure::err::Error::NotCharBoundary { at: 2 })
+///     ));
+///     Ok(())
+/// # }
+/// ```
+#[inline(always)]
+pub fn utf8_checked(full: &[u8]) -> Utf8Checked<'_> {
+    Utf8Checked::new(full)
+}
+
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, PartialOrd, Ord)]
+pub struct ToVec;
+
+impl ToVec {
+    pub fn new() -> Self {
+        Self {}
+    }
+}
+
+impl<T: Clone> MapSingle<&[T], Vec<T>> for ToVec {
+    fn map_to(&self, val: &[T]) -> Result<Vec<T>, Error> {
+        Ok(val.to_vec())
+    }
+}
+
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, PartialOrd, Ord)]
+pub struct CharCode;
+
+impl CharCode {
+    pub fn new() -> Self {
+        Self {}
+    }
+}
+
+impl<I> MapSingle<I, u32> for CharCode
+where
+    I: AsRef<str>,
+{
+    fn map_to(&self, val: I) -> Result<u32, Error> {
+        let mut chars = val.as_ref().chars();
+
+        match (chars.next(), chars.next()) {
+            (Some(ch), None) => Ok(ch as u32),
+            _ => Err(Error::CharCode),
+        }
+    }
+}
+
+///
+/// Convert the single char string to its code point, return error if the value is not exactly one char.
+///
+/// # Example
+///
+/// ```
+/// # use neure::prelude::*;
+/// #
+/// # fn main() -> color_eyre::Result<()> {
+/// #     color_eyre::install()?;
+///     let ch = neu::any().repeat_one().map(map::char_code());
+///     let mut ctx = CharsCtx::new("A中");
+///
+///     assert_eq!(ctx.ctor(&ch)?, 65);
+///     assert_eq!(ctx.ctor(&ch)?, 0x4e2d);
+///     assert!(CharsCtx::new("AB").map(&re::consume(2), map::char_code()).is_err());
+///     Ok(())
+/// # }
+/// ```
+#[inline(always)]
+pub fn char_code() -> CharCode {
+    CharCode::new()
+}
+
+///
+/// Copy the value into an owned [`Vec`].
+///
+/// # Example
+///
+/// ```
+/// # use neure::prelude::*;
+/// #
+/// # fn main() -> color_eyre::Result<()> {
+/// #     color_eyre::install()?;
+///     let head = re::consume(4).map(map::to_vec());
+///     let head = {
+///         let mut ctx = BytesCtx::new(b"\x7fELF\x02\x01");
+///
+///         ctx.ctor(&head)?
+///     };
+///
+///     assert_eq!(head, b"\x7fELF".to_vec());
+///     Ok(())
+/// # }
+/// ```
+#[inline(always)]
+pub fn to_vec() -> ToVec {
+    ToVec::new()
+}
+
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, PartialOrd, Ord)]
+pub struct ToCowBytes;
+
+impl ToCowBytes {
+    pub fn new() -> Self {
+        Self {}
+    }
+}
+
+impl<'a> MapSingle<&'a [u8], Cow<'a, [u8]>> for ToCowBytes {
+    fn map_to(&self, val: &'a [u8]) -> Result<Cow<'a, [u8]>, Error> {
+        Ok(Cow::Borrowed(val))
+    }
+}
+
+///
+/// Convert the bytes into a [`Cow::Borrowed`], call [`into_owned`](Cow::into_owned)
+/// if the value need detached from the input.
+///
+/// # Example
+///
+/// ```
+/// # use neure::prelude::*;
+/// # use std::borrow::Cow;
+/// #
+/// # fn main() -> color_eyre::Result<()> {
+/// #     color_eyre::install()?;
+///     let data = neu::ascii_alphanumeric().repeat_one_more().map(map::to_cow_bytes());
+///     let mut ctx = BytesCtx::new(b"abc123;");
+///     let data = ctx.ctor(&data)?;
+///
+///     assert!(matches!(data, Cow::Borrowed(b"abc123")));
+///     assert_eq!(data.into_owned(), b"abc123".to_vec());
+///     Ok(())
+/// # }
+/// ```
+#[inline(always)]
+pub fn to_cow_bytes() -> ToCowBytes {
+    ToCowBytes::new()
+}
+
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, PartialOrd, Ord)]
+pub struct ToCowStr;
+
+impl ToCowStr {
+    pub fn new() -> Self {
+        Self {}
+    }
+}
+
+impl<'a> MapSingle<&'a str, Cow<'a, str>> for ToCowStr {
+    fn map_to(&self, val: &'a str) -> Result<Cow<'a, str>, Error> {
+        Ok(Cow::Borrowed(val))
+    }
+}
+
+///
+/// Convert the string into a [`Cow::Borrowed`] without allocation, call [`into_owned`](Cow::into_owned)
+/// if the value need detached from the input.
+///
+/// # Example
+///
+/// ```
+/// # use neure::prelude::*;
+/// # use std::borrow::Cow;
+/// #
+/// # fn main() -> color_eyre::Result<()> {
+/// #     color_eyre::install()?;
+///     let str = neu::not('"').repeat_zero_more().quote("\"", "\"");
+///     let str = str.map(map::to_cow_str());
+///     let mut ctx = CharsCtx::new(r#""plain""#);
+///     let val = ctx.ctor(&str)?;
+///
+///     assert!(matches!(val, Cow::Borrowed("plain")));
+///     assert_eq!(val.into_owned(), String::from("plain"));
+///     Ok(())
+/// # }
+/// ```
+#[inline(always)]
+pub fn to_cow_str() -> ToCowStr {
+    ToCowStr::new()
+}
+
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, PartialOrd, Ord)]
+pub struct ToBoxStr;
+
+impl ToBoxStr {
+    pub fn new() -> Self {
+        Self {}
+    }
+}
+
+impl MapSingle<&str, Box<str>> for ToBoxStr {
+    fn map_to(&self, val: &str) -> Result<Box<str>, Error> {
+        Ok(Box::from(val))
+    }
+}
+
+///
+/// Copy the string into a [`Box<str>`], which is detached from the input.
+///
+/// # Example
+///
+/// ```
+/// # use neure::prelude::*;
+/// #
+/// # fn main() -> color_eyre::Result<()> {
+/// #     color_eyre::install()?;
+///     let ident = neu::ascii_alphabetic().repeat_one_more().map(map::to_box_str());
+///     let ident = {
+///         let input = String::from("neure = 1");
+///         let mut ctx = CharsCtx::new(&input);
+///
+///         ctx.ctor(&ident)?
+///     };
+///
+///     assert_eq!(&*ident, "neure");
+///     Ok(())
+/// # }
+/// ```
+#[inline(always)]
+pub fn to_box_str() -> ToBoxStr {
+    ToBoxStr::new()
+}
+
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, PartialOrd, Ord)]
+pub struct ToBoxBytes;
+
+impl ToBoxBytes {
+    pub fn new() -> Self {
+        Self {}
+    }
+}
+
+impl MapSingle<&[u8], Box<[u8]>> for ToBoxBytes {
+    fn map_to(&self, val: &[u8]) -> Result<Box<[u8]>, Error> {
+        Ok(Box::from(val))
+    }
+}
+
+///
+/// Copy the bytes into a [`Box<[u8]>`], which is detached from the input.
+///
+/// # Example
+///
+/// ```
+/// # use neure::prelude::*;
+/// #
+/// # fn main() -> color_eyre::Result<()> {
+/// #     color_eyre::install()?;
+///     let magic = re::consume(4).map(map::to_box_bytes());
+///     let magic = {
+///         let input = b"\x7fELF\x02\x01".to_vec();
+///         let mut ctx = BytesCtx::new(&input);
+///
+///         ctx.ctor(&magic)?
+///     };
+///
+///     assert_eq!(&*magic, b"\x7fELF");
+///     Ok(())
+/// # }
+/// ```
+#[inline(always)]
+pub fn to_box_bytes() -> ToBoxBytes {
+    ToBoxBytes::new()
+}
+
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, PartialOrd, Ord)]
+pub struct FlattenOption;
+
+impl FlattenOption {
+    pub fn new() -> Self {
+        Self {}
+    }
+}
+
+impl<T> MapSingle<Option<Option<T>>, Option<T>> for FlattenOption {
+    fn map_to(&self, val: Option<Option<T>>) -> Result<Option<T>, Error> {
+        Ok(val.flatten())
+    }
+}
+
+///
+/// Flatten the nested [`Option`].
+///
+/// # Example
+///
+/// ```
+/// # use neure::prelude::*;
+/// #
+/// # fn main() -> color_eyre::Result<()> {
+/// #     color_eyre::install()?;
+///     let num = neu::digit(10).repeat_zero_more();
+///     let num = num.map(|v: &str| Ok(v.parse::<i32>().ok()));
+///     let num = num.pad(";").opt().map(map::flatten_option());
+///
+///     assert_eq!(CharsCtx::new("3;").ctor(&num)?, Some(3));
+///     assert_eq!(CharsCtx::new(";").ctor(&num)?, None);
+///     assert_eq!(CharsCtx::new("3").ctor(&num)?, None);
+///     Ok(())
+/// # }
+/// ```
+#[inline(always)]
+pub fn flatten_option() -> FlattenOption {
+    FlattenOption::new()
+}
+
+#[derive(Debug, Clone, Copy)]
+pub struct OkOr {
+    err: Error,
+}
+
+impl OkOr {
+    pub fn new(err: Error) -> Self {
+        Self { err }
+    }
+
+    pub fn err(&self) -> Error {
+        self.err
+    }
+}
+
+impl<T> MapSingle<Option<T>, T> for OkOr {
+    fn map_to(&self, val: Option<T>) -> Result<T, Error> {
+        val.ok_or(self.err)
+    }
+}
+
+///
+/// Convert the [`Option`] to the value, return `err` if the value is [`None`].
+///
+/// # Example
+///
+/// ```
+/// # use neure::{err::Error, prelude::*};
+/// #
+/// # fn main() -> color_eyre::Result<()> {
+/// #     color_eyre::install()?;
+///     let key = neu::ascii_alphabetic().repeat_one_more();
+///     let key = key.map(|v: &str| Ok(["on", "off"].iter().position(|k| *k == v)));
+///     let key = key.map(map::ok_or(Error::Uid(1)));
+///
+///     assert_eq!(CharsCtx::new("off").ctor(&key)?, 1);
+///     assert!(matches!(CharsCtx::new("of").ctor(&key), Err(Error::Uid(1))));
+///     Ok(())
+/// # }
+/// ```
+#[inline(always)]
+pub fn ok_or(err: Error) -> OkOr {
+    OkOr::new(err)
+}
+
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, PartialOrd, Ord)]
+pub struct Trim;
+
+impl Trim {
+    pub fn new() -> Self {
+        Self {}
+    }
+}
+
+impl<'a> MapSingle<&'a str, &'a str> for Trim {
+    fn map_to(&self, val: &'a str) -> Result<&'a str, Error> {
+        Ok(val.trim())
+    }
+}
+
+impl<'a> MapSingle<&'a [u8], &'a [u8]> for Trim {
+    fn map_to(&self, val: &'a [u8]) -> Result<&'a [u8], Error> {
+        Ok(val.trim_ascii())
+    }
+}
+
+///
+/// Remove the leading and trailing whitespace of the value.
+///
+/// # Example
+///
+/// ```
+/// # use neure::prelude::*;
+/// #
+/// # fn main() -> color_eyre::Result<()> {
+/// #     color_eyre::install()?;
+///     let name = re::field(8).map(map::trim());
+///     let data = re::field(8).map(map::trim());
+///
+///     assert_eq!(CharsCtx::new("  neure ").ctor(&name)?, "neure");
+///     assert_eq!(BytesCtx::new(b"rust    ").ctor(&data)?, b"rust");
+///
+///     Ok(())
+/// # }
+/// ```
+#[inline(always)]
+pub fn trim() -> Trim {
+    Trim::new()
+}
+
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, PartialOrd, Ord)]
+pub struct SplitOnce {
+    delim: char,
+}
+
+impl SplitOnce {
+    pub fn new(delim: char) -> Self {
+        Self { delim }
+    }
+
+    pub fn delim(&self) -> char {
+        self.delim
+    }
+}
+
+impl<'a> MapSingle<&'a str, (&'a str, &'a str)> for SplitOnce {
+    fn map_to(&self, val: &'a str) -> Result<(&'a str, &'a str), Error> {
+        val.split_once(self.delim).ok_or(Error::SplitOnce)
+    }
+}
+
+///
+/// Split the value on the first occurrence of `delim`, return an error if `delim` not found.
+///
+/// # Example
+///
+/// ```
+/// # use neure::prelude::*;
+/// #
+/// # fn main() -> color_eyre::Result<()> {
+/// #     color_eyre::install()?;
+///     let kv = neu::ascii_alphanumeric()
+///         .or('=')
+///         .repeat_one_more()
+///         .map(map::split_once('='));
+///
+///     assert_eq!(CharsCtx::new("k=v").ctor(&kv)?, ("k", "v"));
+///     assert_eq!(CharsCtx::new("k=v=1").ctor(&kv)?, ("k", "v=1"));
+///     assert!(CharsCtx::new("kv").ctor(&kv).is_err());
+///
+///     Ok(())
+/// # }
+/// ```
+#[inline(always)]
+pub fn split_once(delim: char) -> SplitOnce {
+    SplitOnce::new(delim)
+}
+
+#[derive(Debug, Copy, PartialEq, Eq, PartialOrd, Ord)]
+pub struct SplitParse<T> {
+    delim: char,
+    marker: PhantomData<T>,
+}
+
+impl<T> Clone for SplitParse<T> {
+    fn clone(&self) -> Self {
+        Self {
+            delim: self.delim,
+            marker: self.marker,
+        }
+    }
+}
+
+impl<T> SplitParse<T> {
+    pub fn new(delim: char) -> Self {
+        Self {
+            delim,
+            marker: PhantomData,
+        }
+    }
+
+    pub fn delim(&self) -> char {
+        self.delim
+    }
+}
+
+impl<I, T> MapSingle<I, Vec<T>> for SplitParse<T>
+where
+    T: std::str::FromStr,
+    I: AsRef<str>,
+{
+    fn map_to(&self, val: I) -> Result<Vec<T>, Error> {
+        val.as_ref()
+            .split(self.delim)
+            .map(|v| v.parse::<T>().map_err(|_| Error::SplitParse))
+            .collect()
+    }
+}
+
+///
+/// Split the value on every `delim` and parse each part into `T`,
+/// return an error if any part failed.
+///
+/// # Example
+///
+/// ```
+/// # use neure::prelude::*;
+/// #
+/// # fn main() -> color_eyre::Result<()> {
+/// #     color_eyre::install()?;
+///     let list = neu::digit(10).or(',').or('x').repeat_one_more();
+///     let list = list.map(map::split_parse::<i32>(','));
+///
+///     assert_eq!(CharsCtx::new("1,2,3").ctor(&list)?, [1, 2, 3]);
+///     assert!(CharsCtx::new("1,x,3").ctor(&list).is_err());
+///     assert!(CharsCtx::new("1,,3").ctor(&list).is_err());
+///
+///     Ok(())
+/// # }
+/// ```
+#[inline(always)]
+pub fn split_parse<T: std::str::FromStr>(delim: char) -> SplitParse<T> {
+    SplitParse::new(delim)
+}
+
+/// The value can be checked if it is empty, used by [`NonEmpty`].
+pub trait IsEmpty {
+    fn is_empty(&self) -> bool;
+}
+
+impl IsEmpty for str {
+    fn is_empty(&self) -> bool {
+        str::is_empty(self)
+    }
+}
+
+impl IsEmpty for String {
+    fn is_empty(&self) -> bool {
+        String::is_empty(self)
+    }
+}
+
+impl<T> IsEmpty for [T] {
+    fn is_empty(&self) -> bool {
+        <[T]>::is_empty(self)
+    }
+}
+
+impl<T> IsEmpty for Vec<T> {
+    fn is_empty(&self) -> bool {
+        Vec::is_empty(self)
+    }
+}
+
+impl<T: IsEmpty + ?Sized> IsEmpty for &T {
+    fn is_empty(&self) -> bool {
+        T::is_empty(self)
+    }
+}
+
+impl<T: IsEmpty + ?Sized> IsEmpty for Box<T> {
+    fn is_empty(&self) -> bool {
+        T::is_empty(self)
+    }
+}
+
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, PartialOrd, Ord)]
+pub struct NonEmpty;
+
+impl NonEmpty {
+    pub fn new() -> Self {
+        Self {}
+    }
+}
+
+impl<I: IsEmpty> MapSingle<I, I> for NonEmpty {
+    fn map_to(&self, val: I) -> Result<I, Error> {
+        if val.is_empty() {
+            Err(Error::Empty)
+        } else {
+            Ok(val)
+        }
+    }
+}
+
+///
+/// Return [`Error::Empty`] if the value is empty, otherwise pass it through unchanged.
+///
+/// # Example
+///
+/// ```
+/// # use neure::prelude::*;
+/// #
+/// # fn main() -> color_eyre::Result<()> {
+/// #     color_eyre::install()?;
+///     let word = neu::ascii_alphabetic().repeat_zero_more();
+///     let words = word.sep(",").map(map::non_empty());
+///
+///     assert_eq!(CharsCtx::new("a,b").ctor(&words)?, ["a", "b"]);
+///     assert!(CharsCtx::new("").ctor(&word.map(map::non_empty())).is_err());
+///     Ok(())
+/// # }
+/// ```
+#[inline(always)]
+pub fn non_empty() -> NonEmpty {
+    NonEmpty::new()
+}
+
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, PartialOrd, Ord)]
+pub struct Transpose;
+
+impl Transpose {
+    pub fn new() -> Self {
+        Self {}
+    }
+}
+
+impl<T> MapSingle<Option<Result<T, Error>>, Option<T>> for Transpose {
+    fn map_to(&self, val: Option<Result<T, Error>>) -> Result<Option<T>, Error> {
+        val.transpose()
+    }
+}
+
+///
+/// Convert `Option<Result<T, Error>>` into `Result<Option<T>, Error>`,
+/// the error will be propagated and `None` stays `None`.
+///
+/// # Example
+///
+/// ```
+/// # use neure::prelude::*;
+/// # use neure::map::MapSingle;
+/// #
+/// # fn main() -> color_eyre::Result<()> {
+/// #     color_eyre::install()?;
+///     let num = neu::digit(10).repeat_one_more();
+///     let num = num.opt().map(|v: Option<&str>| Ok(v.map(|v| map::from_str::<u8>().map_to(v))));
+///     let num = num.map(map::transpose());
+///
+///     assert_eq!(CharsCtx::new("42").ctor(&num)?, Some(42));
+///     assert_eq!(CharsCtx::new("x").ctor(&num)?, None);
+///     assert!(CharsCtx::new("420").ctor(&num).is_err());
+///     Ok(())
+/// # }
+/// ```
+#[inline(always)]
+pub fn transpose() -> Transpose {
+    Transpose::new()
+}
+
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, PartialOrd, Ord)]
+pub struct AsRatio;
+
+impl AsRatio {
+    pub fn new() -> Self {
+        Self {}
+    }
+}
+
+impl MapSingle<(i64, i64), f64> for AsRatio {
+    fn map_to(&self, (num, den): (i64, i64)) -> Result<f64, Error> {
+        if den == 0 {
+            Err(Error::DivByZero)
+        } else {
+            Ok(num as f64 / den as f64)
+        }
+    }
+}
+
+///
+/// Divide the numerator by the denominator of `(i64, i64)`,
+/// return [`Error::DivByZero`] if the denominator is zero.
+///
+/// # Example
+///
+/// ```
+/// # use neure::prelude::*;
+/// #
+/// # fn main() -> color_eyre::Result<()> {
+/// #     color_eyre::install()?;
+///     let int = neu::digit(10).repeat_one_more().map(map::from_str::<i64>());
+///     let ratio = int.sep_once("/", int).map(map::as_ratio());
+///
+///     assert_eq!(CharsCtx::new("3/4").ctor(&ratio)?, 0.75);
+///     assert!(CharsCtx::new("1/0").ctor(&ratio).is_err());
+///     Ok(())
+/// # }
+/// ```
+#[inline(always)]
+pub fn as_ratio() -> AsRatio {
+    AsRatio::new()
+}
+
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, PartialOrd, Ord)]
+pub struct FixedPoint {
+    scale: u32,
+}
+
+impl FixedPoint {
+    pub fn new(scale: u32) -> Self {
+        Self { scale }
+    }
+
+    pub fn scale(&self) -> u32 {
+        self.scale
+    }
+}
+
+impl MapSingle<&str, i64> for FixedPoint {
+    fn map_to(&self, val: &str) -> Result<i64, Error> {
+        let (neg, val) = match val.strip_prefix('-') {
+            Some(val) => (true, val),
+            None => (false, val.strip_prefix('+').unwrap_or(val)),
+        };
+        let (int, frac) = match val.split_once('.') {
+            Some((int, frac)) if !frac.is_empty() => (int, frac),
+            Some(_) => return Err(Error::FixedPoint),
+            None => (val, ""),
+        };
+
+        if int.is_empty() || frac.len() > self.scale as usize {
+            return Err(Error::FixedPoint);
+        }
+        let mut ret = 0i64;
+
+        for ch in int.bytes().chain(frac.bytes()) {
+            if !ch.is_ascii_digit() {
+                return Err(Error::FixedPoint);
+            }
+            ret = ret
+                .checked_mul(10)
+                .and_then(|v| v.checked_add((ch - b'0') as i64))
+                .ok_or(Error::Overflow)?;
+        }
+        let ret = 10i64
+            .checked_pow(self.scale - frac.len() as u32)
+            .and_then(|v| ret.checked_mul(v))
+            .ok_or(Error::Overflow)?;
+
+        Ok(if neg { -ret } else { ret })
+    }
+}
+
+///
+/// Parse the decimal into an integer scaled by `10^scale`, such as `"12.34"` into `1234`
+/// with scale 2. Return [`Error::FixedPoint`] if the fraction has more digits than `scale`,
+/// and [`Error::Overflow`] if the result out of range of [`i64`].
+///
+/// # Example
+///
+/// ```
+/// # use neure::prelude::*;
+/// #
+/// # fn main() -> color_eyre::Result<()> {
+/// #     color_eyre::install()?;
+///     let digits = neu::digit(10).repeat_one_more();
+///     let price = digits.then(".".then(digits).opt()).pat();
+///     let cents = price.map(map::fixed_point(2));
+///
+///     assert_eq!(CharsCtx::new("12.34").ctor(&cents)?, 1234);
+///     assert_eq!(CharsCtx::new("1.2").ctor(&cents)?, 120);
+///     assert_eq!(CharsCtx::new("7").ctor(&cents)?, 700);
+///     assert!(CharsCtx::new("1.234").ctor(&cents).is_err());
+///     Ok(())
+/// # }
+/// ```
+#[inline(always)]
+pub fn fixed_point(scale: u32) -> FixedPoint {
+    FixedPoint::new(scale)
+}
+
+#[derive(Debug, Copy, PartialEq, Eq, PartialOrd, Ord)]
+pub struct ToArray<T, const N: usize>(PhantomData<T>);
+
+impl<T, const N: usize> Clone for ToArray<T, N> {
+    fn clone(&self) -> Self {
+        Self(self.0)
+    }
+}
+
+impl<T, const N: usize> Default for ToArray<T, N> {
+    fn default() -> Self {
+        Self(Default::default())
+    }
+}
+
+impl<T, const N: usize> ToArray<T, N> {
+    pub fn new() -> Self {
+        Self(PhantomData)
+    }
+}
+
+impl<T, const N: usize> MapSingle<Vec<T>, [T; N]> for ToArray<T, N> {
+    fn map_to(&self, val: Vec<T>) -> Result<[T; N], Error> {
+        val.try_into().map_err(|v: Vec<T>| Error::LenConstraint {
+            expect: N,
+            found: v.len(),
+        })
+    }
+}
+
+///
+/// Convert the [`Vec`] into an array `[T; N]`,
+/// return [`Error::LenConstraint`] if the length is not exactly `N`.
+///
+/// # Example
+///
+/// ```
+/// # use neure::prelude::*;
+/// #
+/// # fn main() -> color_eyre::Result<()> {
+/// #     color_eyre::install()?;
+///     let num = neu::digit(10).repeat_one_more().map(map::from_str::<u8>());
+///     let rgb = num.sep(",").map(map::to_array::<_, 3>());
+///
+///     assert_eq!(CharsCtx::new("255,128,0").ctor(&rgb)?, [255, 128, 0]);
+///     assert!(CharsCtx::new("255,128").ctor(&rgb).is_err());
+///     Ok(())
+/// # }
+/// ```
+#[inline(always)]
+pub fn to_array<T, const N: usize>() -> ToArray<T, N> {
+    ToArray::new()
+}
+
+#[derive(Debug, Clone, Default, PartialEq, Eq, PartialOrd, Ord)]
+pub struct SplitInclusiveSet {
+    delims: Vec<char>,
+}
+
+impl SplitInclusiveSet {
+    pub fn new(delims: &[char]) -> Self {
+        Self {
+            delims: delims.to_vec(),
+        }
+    }
+
+    pub fn delims(&self) -> &[char] {
+        &self.delims
+    }
+}
+
+impl<'a> MapSingle<&'a str, Vec<&'a str>> for SplitInclusiveSet {
+    fn map_to(&self, val: &'a str) -> Result<Vec<&'a str>, Error> {
+        let mut ret = vec![];
+        let mut beg = 0;
+
+        for (idx, ch) in val.match_indices(self.delims.as_slice()) {
+            if beg < idx {
+                ret.push(&val[beg..idx]);
+            }
+            ret.push(ch);
+            beg = idx + ch.len();
+        }
+        if beg < val.len() {
+            ret.push(&val[beg..]);
+        }
+        Ok(ret)
+    }
+}
+
+///
+/// Split the value on any of the `delims`, keep the delimiters as separate tokens.
+/// The empty content between two delimiters is skipped.
+///
+/// # Example
+///
+/// ```
+/// # use neure::prelude::*;
+/// #
+/// # fn main() -> color_eyre::Result<()> {
+/// #     color_eyre::install()?;
+///     let expr = neu::ascii_alphanumeric()
+///         .or(neu::ascii_punctuation())
+///         .repeat_one_more()
+///         .map(map::split_inclusive_set(&['*', '/']));
+///
+///     assert_eq!(CharsCtx::new("a*b/2").ctor(&expr)?, ["a", "*", "b", "/", "2"]);
+///     assert_eq!(CharsCtx::new("**a").ctor(&expr)?, ["*", "*", "a"]);
+///     Ok(())
+/// # }
+/// ```
+#[inline(always)]
+pub fn split_inclusive_set(delims: &[char]) -> SplitInclusiveSet {
+    SplitInclusiveSet::new(delims)
+}
+
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, PartialOrd, Ord)]
+pub struct ToCsvRow;
+
+impl ToCsvRow {
+    pub fn new() -> Self {
+        Self {}
+    }
+}
+
+impl<'a> MapSingle<Vec<&'a str>, String> for ToCsvRow {
+    fn map_to(&self, val: Vec<&'a str>) -> Result<String, Error> {
+        let mut ret = String::new();
+
+        for (idx, field) in val.iter().enumerate() {
+            if idx > 0 {
+                ret.push(',');
+            }
+            if field.contains([',', '"', '\r', '\n']) {
+                ret.push('"');
+                ret.push_str(&field.replace('"', "\"\""));
+                ret.push('"');
+            } else {
+                ret.push_str(field);
+            }
+        }
+        Ok(ret)
+    }
+}
+
+///
+/// Join the fields with `,` into a CSV row.
+/// The field contains `,`, `"` or line break will be quoted, and the `"` in it will be doubled,
+/// see [RFC 4180](https://www.rfc-editor.org/rfc/rfc4180).
+///
+/// # Example
+///
+/// ```
+/// # use neure::prelude::*;
+/// #
+/// # fn main() -> color_eyre::Result<()> {
+/// #     color_eyre::install()?;
+///     let field = neu::not('|').repeat_one_more();
+///     let row = field.sep("|").map(map::to_csv_row());
+///
+///     assert_eq!(CharsCtx::new("a|b,c").ctor(&row)?, r#"a,"b,c""#);
+///     assert_eq!(CharsCtx::new(r#"say "hi"|x"#).ctor(&row)?, r#""say ""hi""",x"#);
+///     Ok(())
+/// # }
+/// ```
+#[inline(always)]
+pub fn to_csv_row() -> ToCsvRow {
+    ToCsvRow::new()
+}
+
+#[derive(Debug, Clone, Default, PartialEq, Eq, PartialOrd, Ord)]
+pub struct Lookup<K, V> {
+    table: Vec<(K, V)>,
+    fallback: Option<V>,
+}
+
+impl<K: Ord, V> Lookup<K, V> {
+    pub fn new(table: impl IntoIterator<Item = (K, V)>, fallback: Option<V>) -> Self {
+        let mut table: Vec<_> = table.into_iter().collect();
+
+        table.sort_by(|a, b| a.0.cmp(&b.0));
+        Self { table, fallback }
+    }
+
+    pub fn table(&self) -> &[(K, V)] {
+        &self.table
+    }
+
+    /// Return the value used when the value not found in the table.
+    pub fn fallback(&self) -> Option<&V> {
+        self.fallback.as_ref()
+    }
+}
+
+impl<I, K, V> MapSingle<I, V> for Lookup<K, V>
+where
+    I: Ord,
+    K: Borrow<I>,
+    V: Clone,
+{
+    fn map_to(&self, val: I) -> Result<V, Error> {
+        match self.table.binary_search_by(|(k, _)| k.borrow().cmp(&val)) {
+            Ok(idx) => Ok(self.table[idx].1.clone()),
+            Err(_) => self.fallback.clone().ok_or(Error::Lookup),
+        }
+    }
+}
+
+///
+/// Map the value to `V` using the lookup table, return the `default` if value not found.
+///
+/// # Example
+///
+/// ```
+/// # use neure::prelude::*;
+/// #
+/// # fn main() -> color_eyre::Result<()> {
+/// #     color_eyre::install()?;
+///     let op = neu::ascii_punctuation().repeat_one();
+///     let prec = op.map(map::lookup([("+", 1), ("-", 1), ("*", 2), ("/", 2)], 0));
+///
+///     assert_eq!(CharsCtx::new("*").ctor(&prec)?, 2);
+///     assert_eq!(CharsCtx::new("+").ctor(&prec)?, 1);
+///     assert_eq!(CharsCtx::new("%").ctor(&prec)?, 0);
+///
+///     Ok(())
+/// # }
+/// ```
+pub fn lookup<K: Ord, V>(table: impl IntoIterator<Item = (K, V)>, default: V) -> Lookup<K, V> {
+    Lookup::new(table, Some(default))
+}
+
+///
+/// Map the value to `V` using the lookup table, return an error if value not found.
+///
+/// # Example
+///
+/// ```
+/// # use neure::prelude::*;
+/// #
+/// # fn main() -> color_eyre::Result<()> {
+/// #     color_eyre::install()?;
+///     let op = neu::ascii_punctuation().repeat_one();
+///     let prec = op.map(map::lookup_strict([("+", 1), ("-", 1), ("*", 2), ("/", 2)]));
+///
+///     assert_eq!(CharsCtx::new("*").ctor(&prec)?, 2);
+///     assert!(CharsCtx::new("%").ctor(&prec).is_err());
+///
+///     Ok(())
+/// # }
+/// ```
+pub fn lookup_strict<K: Ord, V>(table: impl IntoIterator<Item = (K, V)>) -> Lookup<K, V> {
+    Lookup::new(table, None)
+}
+
+///
+/// Alias of [`lookup`], classify the value into `V` which is usually an enum.
+///
+/// # Example
+///
+/// ```
+/// # use neure::prelude::*;
+/// #
+/// # fn main() -> color_eyre::Result<()> {
+/// #     color_eyre::install()?;
+///     #[derive(Debug, Clone, Copy, PartialEq, Eq)]
+///     pub enum Assoc {
+///         Left,
+///         Right,
+///         None,
+///     }
+///
+///     let op = neu::ascii_punctuation().repeat_one_more();
+///     let assoc = [("+", Assoc::Left), ("*", Assoc::Left), ("**", Assoc::Right)];
+///     let assoc = op.map(map::classify(assoc, Assoc::None));
+///
+///     assert_eq!(CharsCtx::new("**").ctor(&assoc)?, Assoc::Right);
+///     assert_eq!(CharsCtx::new("+").ctor(&assoc)?, Assoc::Left);
+///     assert_eq!(CharsCtx::new("==").ctor(&assoc)?, Assoc::None);
+///
+///     Ok(())
+/// # }
+/// ```
+#[inline(always)]
+pub fn classify<K: Ord, V>(table: impl IntoIterator<Item = (K, V)>, default: V) -> Lookup<K, V> {
+    lookup(table, default)
+}
+
+///
+/// Map the string to one of the enum variants using the match table,
+/// return [`Error::Lookup`] if the string not found, see [`lookup_strict`].
+///
+/// # Example
+///
+/// ```
+/// # use neure::prelude::*;
+/// #
+/// # fn main() -> color_eyre::Result<()> {
+/// #     color_eyre::install()?;
+///     #[derive(Debug, Clone, Copy, PartialEq, Eq)]
+///     enum Op {
+///         Add,
+///         Sub,
+///     }
+///
+///     let op = neu::ascii_alphabetic().repeat_one_more();
+///     let op = op.map(map::one_of_enum([("add", Op::Add), ("sub", Op::Sub)]));
+///
+///     assert_eq!(CharsCtx::new("add").ctor(&op)?, Op::Add);
+///     assert_eq!(CharsCtx::new("sub").ctor(&op)?, Op::Sub);
+///     assert!(CharsCtx::new("mul").ctor(&op).is_err());
+///
+///     Ok(())
+/// # }
+/// ```
+#[inline(always)]
+pub fn one_of_enum<K: Ord, V>(table: impl IntoIterator<Item = (K, V)>) -> Lookup<K, V> {
+    lookup_strict(table)
+}
+
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, PartialOrd, Ord)]
+pub struct ParseDuration;
+
+impl ParseDuration {
+    pub fn new() -> Self {
+        Self {}
+    }
+}
+
+impl<I> MapSingle<I, Duration> for ParseDuration
+where
+    I: AsRef<str>,
+{
+    fn map_to(&self, val: I) -> Result<Duration, Error> {
+        let mut val: &str = val.as_ref();
+        let mut ret = Duration::ZERO;
+
+        if val.is_empty() {
+            return Err(Error::Duration);
+        }
+        while !val.is_empty() {
+            let digits = val.find(|c: char| !c.is_ascii_digit()).unwrap_or(val.len());
+            let num = val[..digits].parse::<u64>().map_err(|_| Error::Duration)?;
+            let rest = &val[digits..];
+            let (dur, unit) = if rest.starts_with("ns") {
+                (Some(Duration::from_nanos(num)), 2)
+            } else if rest.starts_with("us") {
+                (Some(Duration::from_micros(num)), 2)
+            } else if rest.starts_with("ms") {
+                (Some(Duration::from_millis(num)), 2)
+            } else if rest.starts_with('s') {
+                (Some(Duration::from_secs(num)), 1)
+            } else if rest.starts_with('m') {
+                (num.checked_mul(60).map(Duration::from_secs), 1)
+            } else if rest.starts_with('h') {
+                (num.checked_mul(60 * 60).map(Duration::from_secs), 1)
+            } else if rest.starts_with('d') {
+                (num.checked_mul(24 * 60 * 60).map(Duration::from_secs), 1)
+            } else {
+                (None, 0)
+            };
+
+            ret = dur
+                .and_then(|dur| ret.checked_add(dur))
+                .ok_or(Error::Duration)?;
+            val = &rest[unit..];
+        }
+        Ok(ret)
+    }
+}
+
+///
+/// Parse the human readable duration such as `500ms` or `1h30m` into [`Duration`].
+///
+/// The supported units are `ns`, `us`, `ms`, `s`, `m`, `h` and `d`.
+///
+/// # Example
+///
+/// ```
+/// # use std::time::Duration;
+/// # use neure::prelude::*;
+/// #
+/// # fn main() -> color_eyre::Result<()> {
+/// #     color_eyre::install()?;
+///     let dur = re::humantime_duration().map(map::parse_duration());
+///
+///     assert_eq!(CharsCtx::new("500ms").ctor(&dur)?, Duration::from_millis(500));
+///     assert_eq!(CharsCtx::new("2h").ctor(&dur)?, Duration::from_secs(7200));
+///     assert_eq!(CharsCtx::new("1h30m").ctor(&dur)?, Duration::from_secs(5400));
+///     assert!(CharsCtx::new("1x").ctor(&dur).is_err());
+///
+///     Ok(())
+/// # }
+/// ```
+#[inline(always)]
+pub fn parse_duration() -> ParseDuration {
+    ParseDuration::new()
+}
+
 #[derive(Debug, Copy, PartialEq, Eq, PartialOrd, Ord)]
 pub struct FromLeBytes<T>(PhantomData<T>);
 
@@ -64,13 +64,18 @@ pub use self::units::ascii_lowercase;
 pub use self::units::ascii_punctuation;
 pub use self::units::ascii_uppercase;
 pub use self::units::ascii_whitespace;
+pub use self::units::byte_range;
 pub use self::units::control;
 pub use self::units::digit;
+pub use self::units::digit_or_underscore;
 pub use self::units::lowercase;
 pub use self::units::numeric;
+pub use self::units::printable;
 pub use self::units::uppercase;
+pub use self::units::visible;
 pub use self::units::whitespace;
 pub use self::units::wild;
+pub use self::units::word;
 pub use self::units::Alphabetic;
 pub use self::units::Alphanumeric;
 pub use self::units::Ascii;
@@ -84,13 +89,18 @@ pub use self::units::AsciiLowercase;
 pub use self::units::AsciiPunctuation;
 pub use self::units::AsciiUppercase;
 pub use self::units::AsciiWhiteSpace;
+pub use self::units::ByteRange;
 pub use self::units::Control;
 pub use self::units::Digit;
+pub use self::units::DigitOrUnderscore;
 pub use self::units::Lowercase;
 pub use self::units::Numeric;
+pub use self::units::Printable;
 pub use self::units::Uppercase;
+pub use self::units::Visible;
 pub use self::units::WhiteSpace;
 pub use self::units::Wild;
+pub use self::units::Word;
 
 pub trait Neu<T: ?Sized> {
     fn is_match(&self, other: &T) -> bool;
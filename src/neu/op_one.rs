@@ -161,8 +161,8 @@ where
     fn try_parse(&self, ctx: &mut C) -> Result<Self::Ret, crate::err::Error> {
         let mut g = CtxGuard::new(ctx);
         let mut iter = g.ctx().peek()?;
-        let mut ret = Err(Error::NeuOne);
         let beg = g.beg();
+        let mut ret = Err(Error::NeuOne { at: beg });
 
         trace!("neu_one", beg, ());
         if let Some((offset, item)) = iter.next() {
@@ -320,9 +320,9 @@ where
         let mut cnt = 0;
         let mut beg = None;
         let mut end = None;
-        let mut ret = Err(Error::NeuOneMore);
         let mut iter = g.ctx().peek()?;
         let offset = g.beg();
+        let mut ret = Err(Error::NeuOneMore { at: offset });
 
         trace!("neu_one_more", offset, ());
         for pair in iter.by_ref() {
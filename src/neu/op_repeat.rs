@@ -211,9 +211,9 @@ where
         let mut cnt = 0;
         let mut beg = None;
         let mut end = None;
-        let mut ret = Err(Error::NeuRepeat);
         let iter = g.ctx().peek();
         let offset = g.beg();
+        let mut ret = Err(Error::NeuRepeat { at: offset });
         let range = M..N;
 
         trace_v!("neu repeat", &range, offset, ());
@@ -390,9 +390,9 @@ where
         let mut cnt = 0;
         let mut beg = None;
         let mut end = None;
-        let mut ret = Err(Error::NeuRepeatRange);
         let iter = g.ctx().peek();
         let offset = g.beg();
+        let mut ret = Err(Error::NeuRepeatRange { at: offset });
 
         trace_v!("neu repeat_range", self.range, offset, ());
         if let Ok(mut iter) = iter {
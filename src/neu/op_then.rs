@@ -189,8 +189,8 @@ where
     fn try_parse(&self, ctx: &mut C) -> Result<Self::Ret, crate::err::Error> {
         let mut g = CtxGuard::new(ctx);
         let mut iter = g.ctx().peek()?;
-        let mut ret = Err(Error::NeuThen);
         let beg = g.beg();
+        let mut ret = Err(Error::NeuThen { at: beg });
 
         trace!("neu_then", beg, ());
         if let Some((fst_offset, item)) = iter.next() {
@@ -608,6 +608,69 @@ pub const fn ascii_whitespace() -> AsciiWhiteSpace {
     AsciiWhiteSpace
 }
 
+#[derive(Debug, Clone, Default, Copy)]
+pub struct ByteRange {
+    lo: u8,
+    hi: u8,
+}
+
+impl ByteRange {
+    pub const fn new(lo: u8, hi: u8) -> Self {
+        debug_assert!(
+            lo <= hi,
+            "the lower bound of byte range is greater than upper bound"
+        );
+        Self { lo, hi }
+    }
+
+    pub const fn lo(&self) -> u8 {
+        self.lo
+    }
+
+    pub const fn hi(&self) -> u8 {
+        self.hi
+    }
+}
+
+impl Neu<u8> for ByteRange {
+    #[inline(always)]
+    fn is_match(&self, other: &u8) -> bool {
+        trace_u!(
+            "byte_range",
+            self,
+            other,
+            self.lo <= *other && *other <= self.hi
+        )
+    }
+}
+
+///
+/// Match the byte in the inclusive range `lo..=hi`.
+///
+/// # Panics
+///
+/// Panic if `lo` is greater than `hi` in debug mode.
+///
+/// # Example
+///
+/// ```
+/// use neure::prelude::*;
+/// use neu::*;
+///
+/// fn main() -> Result<(), Box<dyn std::error::Error>> {
+///     let digit = byte_range(b'0', b'9');
+///     let year = digit.repeat_times::<4>();
+///     let mut ctx = BytesCtx::new(b"2024-01");
+///
+///     assert_eq!(ctx.try_mat(&year)?, Span::new(0, 4));
+///     assert!(ctx.try_mat(&digit.repeat_one()).is_err());
+///     Ok(())
+/// }
+/// ```
+pub const fn byte_range(lo: u8, hi: u8) -> ByteRange {
+    ByteRange::new(lo, hi)
+}
+
 #[derive(Debug, Clone, Default, Copy)]
 pub struct Control;
 
@@ -686,6 +749,52 @@ pub const fn digit(radix: u32) -> Digit {
     Digit::new(radix)
 }
 
+#[derive(Debug, Clone, Default, Copy)]
+pub struct DigitOrUnderscore(u32);
+
+impl DigitOrUnderscore {
+    pub const fn new(radix: u32) -> Self {
+        Self(radix)
+    }
+}
+
+impl Neu<char> for DigitOrUnderscore {
+    #[inline(always)]
+    fn is_match(&self, other: &char) -> bool {
+        trace_u!(
+            "digit_or_underscore",
+            self,
+            other,
+            *other == '_' || other.is_digit(self.0)
+        )
+    }
+}
+
+///
+/// Match the digit of given radix or the separator `_`.
+///
+/// Use [`int_with_separators`](crate::re::int_with_separators) if the separator should not be
+/// the leading, trailing or consecutive one.
+///
+/// # Example
+///
+/// ```
+/// use neure::prelude::*;
+/// use neu::*;
+///
+/// fn main() -> Result<(), Box<dyn std::error::Error>> {
+///     let num = digit_or_underscore(10);
+///     let num = num.repeat_one_more();
+///     let mut ctx = CharsCtx::new("1_000a");
+///
+///     assert_eq!(ctx.try_mat(&num)?, Span::new(0, 5));
+///     Ok(())
+/// }
+/// ```
+pub const fn digit_or_underscore(radix: u32) -> DigitOrUnderscore {
+    DigitOrUnderscore::new(radix)
+}
+
 #[derive(Debug, Clone, Default, Copy)]
 pub struct Lowercase;
 
@@ -764,6 +873,65 @@ pub const fn numeric() -> Numeric {
     Numeric
 }
 
+#[derive(Debug, Clone, Default, Copy)]
+pub struct Printable;
+
+impl Printable {
+    pub const fn new() -> Self {
+        Self {}
+    }
+}
+
+impl Neu<char> for Printable {
+    #[inline(always)]
+    fn is_match(&self, other: &char) -> bool {
+        trace_u!(
+            "printable",
+            self,
+            other,
+            *other == ' ' || !(other.is_control() || other.is_whitespace())
+        )
+    }
+}
+
+impl Neu<u8> for Printable {
+    #[inline(always)]
+    fn is_match(&self, other: &u8) -> bool {
+        trace_u!(
+            "printable",
+            self,
+            other,
+            *other == b' ' || other.is_ascii_graphic()
+        )
+    }
+}
+
+///
+/// Match the graphic character or the ASCII space.
+/// For [`char`] the graphic means neither [`is_control`](std::primitive::char::is_control)
+/// nor [`is_whitespace`](std::primitive::char::is_whitespace),
+/// for [`u8`] reference [`is_ascii_graphic`](std::primitive::u8::is_ascii_graphic).
+///
+/// # Example
+///
+/// ```
+/// use neure::prelude::*;
+/// use neu::*;
+///
+/// fn main() -> Result<(), Box<dyn std::error::Error>> {
+///     let printable = printable();
+///     let printable = printable.repeat_times::<3>();
+///     let mut ctx = CharsCtx::new("a 中\t");
+///
+///     assert_eq!(ctx.try_mat(&printable)?, Span::new(0, 5));
+///     assert!(ctx.try_mat(&printable).is_err());
+///     Ok(())
+/// }
+/// ```
+pub const fn printable() -> Printable {
+    Printable
+}
+
 #[derive(Debug, Clone, Default, Copy)]
 pub struct Uppercase;
 
@@ -803,6 +971,57 @@ pub const fn uppercase() -> Uppercase {
     Uppercase
 }
 
+#[derive(Debug, Clone, Default, Copy)]
+pub struct Visible;
+
+impl Visible {
+    pub const fn new() -> Self {
+        Self {}
+    }
+}
+
+impl Neu<char> for Visible {
+    #[inline(always)]
+    fn is_match(&self, other: &char) -> bool {
+        trace_u!(
+            "visible",
+            self,
+            other,
+            !(other.is_control() || other.is_whitespace())
+        )
+    }
+}
+
+impl Neu<u8> for Visible {
+    #[inline(always)]
+    fn is_match(&self, other: &u8) -> bool {
+        trace_u!("visible", self, other, other.is_ascii_graphic())
+    }
+}
+
+///
+/// Match the graphic character, same as [`printable`] but not match the space.
+///
+/// # Example
+///
+/// ```
+/// use neure::prelude::*;
+/// use neu::*;
+///
+/// fn main() -> Result<(), Box<dyn std::error::Error>> {
+///     let visible = visible();
+///     let visible = visible.repeat_times::<2>();
+///     let mut ctx = BytesCtx::new(b"a$ b");
+///
+///     assert_eq!(ctx.try_mat(&visible)?, Span::new(0, 2));
+///     assert!(ctx.try_mat(&visible).is_err());
+///     Ok(())
+/// }
+/// ```
+pub const fn visible() -> Visible {
+    Visible
+}
+
 #[derive(Debug, Clone, Default, Copy)]
 pub struct WhiteSpace;
 
@@ -882,3 +1101,59 @@ impl Neu<char> for Wild {
 pub const fn wild() -> Wild {
     Wild
 }
+
+#[derive(Debug, Clone, Default, Copy)]
+pub struct Word;
+
+impl Word {
+    pub const fn new() -> Self {
+        Self {}
+    }
+}
+
+impl Neu<char> for Word {
+    #[inline(always)]
+    fn is_match(&self, other: &char) -> bool {
+        trace_u!(
+            "word",
+            self,
+            other,
+            other.is_alphanumeric() || other == &'_'
+        )
+    }
+}
+
+impl Neu<u8> for Word {
+    #[inline(always)]
+    fn is_match(&self, other: &u8) -> bool {
+        trace_u!(
+            "word",
+            self,
+            other,
+            other.is_ascii_alphanumeric() || other == &b'_'
+        )
+    }
+}
+
+///
+/// Match the word characters, which is alphanumeric or `_`.
+///
+/// # Example
+///
+/// ```
+/// use neure::prelude::*;
+/// use neu::*;
+///
+/// fn main() -> Result<(), Box<dyn std::error::Error>> {
+///     let word = word();
+///     let word = word.repeat_one_more();
+///     let mut ctx = CharsCtx::new("foo_bar1 baz");
+///
+///     assert_eq!(ctx.try_mat(&word)?, Span::new(0, 8));
+///     assert!(ctx.try_mat(&word).is_err());
+///     Ok(())
+/// }
+/// ```
+pub const fn word() -> Word {
+    Word
+}
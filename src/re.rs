@@ -13,9 +13,16 @@ use std::rc::Rc;
 use std::sync::Arc;
 use std::sync::Mutex;
 
+pub use self::ctor::any_of;
 pub use self::ctor::branch;
+pub use self::ctor::copy_into;
+pub use self::ctor::count_all;
+pub use self::ctor::repeat_while;
 pub use self::ctor::Array;
+pub use self::ctor::Block;
 pub use self::ctor::ConstructOp;
+pub use self::ctor::CopyInto;
+pub use self::ctor::CountAll;
 pub use self::ctor::Ctor;
 pub use self::ctor::DynamicArcCtor;
 pub use self::ctor::DynamicBoxedCtor;
@@ -23,6 +30,10 @@ pub use self::ctor::DynamicBoxedCtorSync;
 pub use self::ctor::DynamicCreateCtorThen;
 pub use self::ctor::DynamicCreateCtorThenHelper;
 pub use self::ctor::DynamicRcCtor;
+pub use self::ctor::EndReason;
+#[cfg(feature = "hashing")]
+pub use self::ctor::Hashed;
+pub use self::ctor::IndexedVector;
 pub use self::ctor::PairArray;
 pub use self::ctor::PairSlice;
 pub use self::ctor::PairVector;
@@ -48,6 +59,8 @@ pub use self::rec::RecursiveParser;
 pub use self::rec::RecursiveParserSync;
 pub use self::regex::AnchorEnd;
 pub use self::regex::AnchorStart;
+pub use self::regex::AtColumn;
+pub use self::regex::Balanced;
 pub use self::regex::BoxedRegex;
 pub use self::regex::Consume;
 pub use self::regex::ConsumeAll;
@@ -55,9 +68,25 @@ pub use self::regex::DynamicArcRegex;
 pub use self::regex::DynamicBoxedRegex;
 pub use self::regex::DynamicCreateRegexThenHelper;
 pub use self::regex::DynamicRcRegex;
+pub use self::regex::Eof;
+pub use self::regex::Field;
+pub use self::regex::FollowedBy;
+pub use self::regex::HexFloat;
+pub use self::regex::HumanDuration;
+pub use self::regex::IntWithSeparators;
+pub use self::regex::Keyword;
+pub use self::regex::LexStep;
 pub use self::regex::LitSlice;
+pub use self::regex::LitSliceNoCase;
 pub use self::regex::LitString;
+pub use self::regex::LitStringNoCase;
+pub use self::regex::ReadArrayLe;
 pub use self::regex::RegexNot;
+pub use self::regex::RequireAscii;
+pub use self::regex::SkipTrivia;
+pub use self::regex::TakeTill;
+pub use self::regex::TakeUntil;
+pub use self::regex::WordBoundary;
 pub use self::wrap::Wrapped;
 pub use self::wrap::WrappedTy;
 
@@ -546,6 +575,140 @@ pub fn end() -> AnchorEnd {
     AnchorEnd::new()
 }
 
+///
+/// Match the end of data, return [`Error::TrailingInput`] if any data remains.
+///
+/// # Example
+///
+/// ```
+/// # use neure::prelude::*;
+/// #
+/// # fn main() -> color_eyre::Result<()> {
+/// #     color_eyre::install()?;
+///     let year = neu::digit(10).repeat_times::<4>();
+///     let eof = re::eof();
+///     let mut ctx = CharsCtx::new("2023");
+///
+///     assert_eq!(ctx.try_mat(&year)?, Span::new(0, 4));
+///     assert_eq!(ctx.try_mat(&eof)?, Span::new(4, 0));
+///
+///     let mut ctx = CharsCtx::new("20231");
+///
+///     assert_eq!(ctx.try_mat(&year)?, Span::new(0, 4));
+///     assert!(matches!(ctx.try_mat(&eof), Err(neure::err::Error::TrailingInput)));
+///     assert_eq!(ctx.offset(), 4);
+///
+///     Ok(())
+/// # }
+/// ```
+pub fn eof() -> Eof {
+    Eof::new()
+}
+
+///
+/// Match if the column of current offset is equal to `col`.
+///
+/// The column is 1-based and counted in chars for [`CharsCtx`](crate::ctx::CharsCtx),
+/// same as [`line_col`](crate::ctx::RegexCtx::line_col), see [`AtColumn`].
+///
+/// # Example
+///
+/// ```
+/// # use neure::prelude::*;
+/// #
+/// # fn main() -> color_eyre::Result<()> {
+/// #     color_eyre::install()?;
+///     let col = re::at_column(3);
+///     let mut ctx = CharsCtx::new("abcd\n变量z");
+///
+///     assert!(ctx.try_mat(&col).is_err());
+///     ctx.set_offset(2);
+///     assert_eq!(ctx.try_mat(&col)?, Span::new(2, 0));
+///     ctx.set_offset(11);
+///     assert_eq!(ctx.try_mat(&col)?, Span::new(11, 0));
+///     assert_eq!(ctx.line_col(11), (2, 3));
+///
+///     Ok(())
+/// # }
+/// ```
+pub fn at_column(col: usize) -> AtColumn {
+    AtColumn::new(col)
+}
+
+///
+/// Match the word boundary, like `\b` in regex.
+///
+/// # Example
+///
+/// ```
+/// # use neure::prelude::*;
+/// #
+/// # fn main() -> color_eyre::Result<()> {
+/// #     color_eyre::install()?;
+///     let b = re::word_boundary();
+///     let mut ctx = CharsCtx::new("let x");
+///
+///     assert_eq!(ctx.try_mat(&b)?, Span::new(0, 0));
+///     ctx.set_offset(1);
+///     assert!(ctx.try_mat(&b).is_err());
+///     ctx.set_offset(3);
+///     assert_eq!(ctx.try_mat(&b)?, Span::new(3, 0));
+///     ctx.set_offset(5);
+///     assert_eq!(ctx.try_mat(&b)?, Span::new(5, 0));
+///
+///     Ok(())
+/// # }
+/// ```
+pub fn word_boundary() -> WordBoundary {
+    WordBoundary::new()
+}
+
+///
+/// Match the position that is not a word boundary, like `\B` in regex.
+///
+/// # Example
+///
+/// ```
+/// # use neure::prelude::*;
+/// #
+/// # fn main() -> color_eyre::Result<()> {
+/// #     color_eyre::install()?;
+///     let nb = re::non_word_boundary();
+///     let mut ctx = CharsCtx::new("let x");
+///
+///     assert!(ctx.try_mat(&nb).is_err());
+///     ctx.set_offset(1);
+///     assert_eq!(ctx.try_mat(&nb)?, Span::new(1, 0));
+///
+///     Ok(())
+/// # }
+/// ```
+pub fn non_word_boundary() -> WordBoundary {
+    WordBoundary::new().with_negated(true)
+}
+
+///
+/// Check all the remaining data are ASCII without consuming them.
+///
+/// # Example
+///
+/// ```
+/// # use neure::prelude::*;
+/// #
+/// # fn main() -> color_eyre::Result<()> {
+/// #     color_eyre::install()?;
+///     let ascii = re::require_ascii();
+///     let mut ctx = BytesCtx::new(b"GET / HTTP/1.1");
+///
+///     assert_eq!(ctx.try_mat(&ascii)?, Span::new(0, 0));
+///     assert_eq!(ctx.offset(), 0);
+///     Ok(())
+/// # }
+/// ```
+pub fn require_ascii() -> RequireAscii {
+    RequireAscii::new()
+}
+
 ///
 /// Match given string.
 ///
@@ -568,6 +731,80 @@ pub fn string(lit: &str) -> LitString<'_> {
     LitString::new(lit)
 }
 
+///
+/// Match given string, skip the UTF-8 BOM if it at the start of data.
+///
+/// The BOM is only skipped at offset 0, it is not included in the result.
+///
+/// # Example
+///
+/// ```
+/// # use neure::prelude::*;
+/// #
+/// # fn main() -> color_eyre::Result<()> {
+/// #     color_eyre::install()?;
+///     let hello = re::string_bom("hello");
+///     let mut ctx = CharsCtx::new("\u{FEFF}hello");
+///
+///     assert_eq!(ctx.ctor(&hello)?, "hello");
+///     assert_eq!(ctx.offset(), 8);
+///     assert_eq!(CharsCtx::new("hello").ctor(&hello)?, "hello");
+///     Ok(())
+/// # }
+/// ```
+pub fn string_bom(lit: &str) -> LitString<'_> {
+    LitString::new(lit).with_bom(true)
+}
+
+///
+/// Match given string, ignoring the ASCII case.
+///
+/// # Example
+///
+/// ```
+/// # use neure::prelude::*;
+/// #
+/// # fn main() -> color_eyre::Result<()> {
+/// #     color_eyre::install()?;
+///     let get = re::string_ignore_case("GET");
+///
+///     assert_eq!(CharsCtx::new("get /").try_mat(&get)?, Span::new(0, 3));
+///     assert_eq!(CharsCtx::new("GeT /").try_mat(&get)?, Span::new(0, 3));
+///     assert!(CharsCtx::new("PUT /").try_mat(&get).is_err());
+///
+///     Ok(())
+/// # }
+/// ```
+pub fn string_ignore_case(lit: &str) -> LitStringNoCase<'_> {
+    LitStringNoCase::new(lit)
+}
+
+///
+/// Match given string as a whole word, the string must not followed by a
+/// [`word`](crate::neu::word) character.
+///
+/// # Example
+///
+/// ```
+/// # use neure::prelude::*;
+/// #
+/// # fn main() -> color_eyre::Result<()> {
+/// #     color_eyre::install()?;
+///     let kw = re::keyword("in");
+///
+///     assert_eq!(CharsCtx::new("in x").try_mat(&kw)?, Span::new(0, 2));
+///     assert_eq!(CharsCtx::new("in(x)").try_mat(&kw)?, Span::new(0, 2));
+///     assert_eq!(CharsCtx::new("in").try_mat(&kw)?, Span::new(0, 2));
+///     assert!(CharsCtx::new("integer").try_mat(&kw).is_err());
+///     assert!(CharsCtx::new("in_x").try_mat(&kw).is_err());
+///
+///     Ok(())
+/// # }
+/// ```
+pub fn keyword(lit: &str) -> Keyword<'_> {
+    Keyword::new(lit)
+}
+
 ///
 /// Match given data.
 ///
@@ -590,6 +827,28 @@ pub fn lit_slice<T>(lit: &[T]) -> LitSlice<'_, T> {
     LitSlice::new(lit)
 }
 
+///
+/// Match given bytes, ignoring the ASCII case.
+///
+/// # Example
+///
+/// ```
+/// # use neure::prelude::*;
+/// #
+/// # fn main() -> color_eyre::Result<()> {
+/// #     color_eyre::install()?;
+///     let get = re::lit_slice_ignore_case(b"GET");
+///
+///     assert_eq!(BytesCtx::new(b"get /").try_mat(&get)?, Span::new(0, 3));
+///     assert!(BytesCtx::new(b"ge").try_mat(&get).is_err());
+///
+///     Ok(())
+/// # }
+/// ```
+pub fn lit_slice_ignore_case(lit: &[u8]) -> LitSliceNoCase<'_> {
+    LitSliceNoCase::new(lit)
+}
+
 ///
 /// Consume given length datas.
 ///
@@ -612,6 +871,307 @@ pub fn consume(len: usize) -> Consume {
     Consume::new(len)
 }
 
+///
+/// Consume exactly `width` bytes, fail if the data is not enough.
+///
+/// For [`CharsCtx`](crate::ctx::CharsCtx), the end of the field must be on a char boundary.
+///
+/// # Example
+///
+/// ```
+/// # use neure::prelude::*;
+/// #
+/// # fn main() -> color_eyre::Result<()> {
+/// #     color_eyre::install()?;
+///     let field = re::field(4).map(map::trim());
+///     let record = field.then(field).then(field);
+///     let mut ctx = CharsCtx::new("ab  1234  cd");
+///
+///     assert_eq!(ctx.ctor(&record)?, (("ab", "1234"), "cd"));
+///     assert!(CharsCtx::new("ab  12").ctor(&record).is_err());
+///
+///     Ok(())
+/// # }
+/// ```
+pub fn field(width: usize) -> Field {
+    Field::new(width)
+}
+
+///
+/// Match the human readable duration such as `500ms`, `2h` or `1h30m`.
+///
+/// # Example
+///
+/// ```
+/// # use neure::prelude::*;
+/// #
+/// # fn main() -> color_eyre::Result<()> {
+/// #     color_eyre::install()?;
+///     let dur = re::humantime_duration();
+///
+///     assert_eq!(CharsCtx::new("1h30m later").try_mat(&dur)?, Span::new(0, 5));
+///     assert_eq!(BytesCtx::new(b"500ms").try_mat(&dur)?, Span::new(0, 5));
+///     assert_eq!(CharsCtx::new("15s30").try_mat(&dur)?, Span::new(0, 3));
+///     assert!(CharsCtx::new("1x").try_mat(&dur).is_err());
+///
+///     Ok(())
+/// # }
+/// ```
+pub fn humantime_duration() -> HumanDuration {
+    HumanDuration::new()
+}
+
+///
+/// Match the integer literal of given radix, the digits can be separated by single `_`,
+/// such as `1_000_000`.
+///
+/// Use [`from_str_radix_sep`](crate::map::from_str_radix_sep) strip the separators and
+/// parse the literal.
+///
+/// # Example
+///
+/// ```
+/// # use neure::prelude::*;
+/// #
+/// # fn main() -> color_eyre::Result<()> {
+/// #     color_eyre::install()?;
+///     let int = re::int_with_separators(10).map(map::from_str_radix_sep::<i64>(10));
+///     let hex = re::int_with_separators(16).map(map::from_str_radix_sep::<u32>(16));
+///
+///     assert_eq!(CharsCtx::new("1_000_000").ctor(&int)?, 1000000);
+///     assert_eq!(CharsCtx::new("ff_ff").ctor(&hex)?, 0xffff);
+///     assert!(CharsCtx::new("_1").ctor(&int).is_err());
+///     assert!(CharsCtx::new("1__0").ctor(&int).is_err());
+///     assert!(CharsCtx::new("1_").ctor(&int).is_err());
+///
+///     Ok(())
+/// # }
+/// ```
+pub fn int_with_separators(radix: u32) -> IntWithSeparators {
+    IntWithSeparators::new(radix)
+}
+
+///
+/// Match the C99 hexadecimal floating-point literal, such as `0x1.8p3`.
+///
+/// Use [`parse_hex_float`](crate::map::parse_hex_float) to get the value.
+///
+/// # Example
+///
+/// ```
+/// # use neure::prelude::*;
+/// #
+/// # fn main() -> color_eyre::Result<()> {
+/// #     color_eyre::install()?;
+///     let float = re::hex_float().map(map::parse_hex_float::<f64>());
+///
+///     assert_eq!(CharsCtx::new("0x1.8p3").ctor(&float)?, 12.0);
+///     assert_eq!(CharsCtx::new("0x1p-2;").ctor(&float)?, 0.25);
+///     assert!(CharsCtx::new("0x1.8").ctor(&float).is_err());
+///     assert!(CharsCtx::new("0x.p1").ctor(&float).is_err());
+///     Ok(())
+/// # }
+/// ```
+pub fn hex_float() -> HexFloat {
+    HexFloat::new()
+}
+
+///
+/// Match the data before the first `byte`, the `byte` is not consumed.
+/// Return [`Error::TakeTill`] if the `byte` not found.
+///
+/// # Example
+///
+/// ```
+/// # use neure::prelude::*;
+/// #
+/// # fn main() -> color_eyre::Result<()> {
+/// #     color_eyre::install()?;
+///     let line = re::take_till(b'\n');
+///     let mut ctx = BytesCtx::new(b"GET / HTTP/1.1\nHost");
+///
+///     assert_eq!(ctx.ctor(&line)?, b"GET / HTTP/1.1");
+///     assert_eq!(ctx.offset(), 14);
+///     assert!(ctx.inc(1).try_mat(&line).is_err());
+///     Ok(())
+/// # }
+/// ```
+pub fn take_till(byte: u8) -> TakeTill {
+    TakeTill::new(byte)
+}
+
+///
+/// Same as [`take_till`], but match the remaining data if the `byte` not found.
+///
+/// # Example
+///
+/// ```
+/// # use neure::prelude::*;
+/// #
+/// # fn main() -> color_eyre::Result<()> {
+/// #     color_eyre::install()?;
+///     let line = re::take_till_or_eof(b'\n');
+///     let lines = line.sep(b"\n");
+///     let mut ctx = BytesCtx::new(b"first\nsecond");
+///
+///     assert_eq!(ctx.ctor(&lines)?, [&b"first"[..], b"second"]);
+///     Ok(())
+/// # }
+/// ```
+pub fn take_till_or_eof(byte: u8) -> TakeTill {
+    TakeTill::new(byte).with_or_eof(true)
+}
+
+///
+/// Match the data before the first position where `pat` matches, the `pat` is not consumed.
+///
+/// Return [`Error::TakeUntil`] if the `pat` never matches.
+///
+/// # Example
+///
+/// ```
+/// # use neure::prelude::*;
+/// #
+/// # fn main() -> color_eyre::Result<()> {
+/// #     color_eyre::install()?;
+///     let msg = re::take_until(" - ");
+///     let mut ctx = CharsCtx::new("INFO 启动 - done");
+///
+///     assert_eq!(ctx.ctor(&msg)?, "INFO 启动");
+///     assert_eq!(ctx.offset(), 11);
+///     assert!(ctx.inc(3).try_mat(&msg).is_err());
+///     assert_eq!(ctx.offset(), 14);
+///     Ok(())
+/// # }
+/// ```
+pub fn take_until<P>(pat: P) -> TakeUntil<P> {
+    TakeUntil::new(pat)
+}
+
+///
+/// Same as [`take_until`], but the data matched by `pat` is included.
+///
+/// # Example
+///
+/// ```
+/// # use neure::prelude::*;
+/// #
+/// # fn main() -> color_eyre::Result<()> {
+/// #     color_eyre::install()?;
+///     let stmt = re::take_until_inclusive(";");
+///     let mut ctx = CharsCtx::new("let a = 1; a");
+///
+///     assert_eq!(ctx.ctor(&stmt)?, "let a = 1;");
+///     assert_eq!(ctx.offset(), 10);
+///     Ok(())
+/// # }
+/// ```
+pub fn take_until_inclusive<P>(pat: P) -> TakeUntil<P> {
+    TakeUntil::new(pat).with_inclusive(true)
+}
+
+///
+/// Match a group start with `open` and end with `close`, the nested groups are skipped.
+///
+/// Return [`Error::Balanced`] if the group is not closed.
+///
+/// # Example
+///
+/// ```
+/// # use neure::prelude::*;
+/// #
+/// # fn main() -> color_eyre::Result<()> {
+/// #     color_eyre::install()?;
+///     let group = re::balanced("{", "}");
+///     let mut ctx = CharsCtx::new("{ a { b } } c");
+///
+///     assert_eq!(ctx.ctor(&group)?, "{ a { b } }");
+///     assert_eq!(ctx.offset(), 11);
+///     assert!(CharsCtx::new("{ a { b }").try_mat(&group).is_err());
+///     Ok(())
+/// # }
+/// ```
+pub fn balanced<L, R>(open: L, close: R) -> Balanced<L, R> {
+    Balanced::new(open, close)
+}
+
+///
+/// Same as [`balanced`], but only the data between the outermost delimiters is returned.
+/// The delimiters are still consumed.
+///
+/// # Example
+///
+/// ```
+/// # use neure::prelude::*;
+/// #
+/// # fn main() -> color_eyre::Result<()> {
+/// #     color_eyre::install()?;
+///     let args = re::inside_balanced("(", ")");
+///     let mut ctx = CharsCtx::new("(a, (b, c)) + d");
+///
+///     assert_eq!(ctx.ctor(&args)?, "a, (b, c)");
+///     assert_eq!(ctx.offset(), 11);
+///     Ok(())
+/// # }
+/// ```
+pub fn inside_balanced<L, R>(open: L, close: R) -> Balanced<L, R> {
+    Balanced::new(open, close).with_inside(true)
+}
+
+///
+/// Match the longest one of the `rules`, return the index of rule with the result.
+/// The first rule wins if more than one rules matched the same length.
+///
+/// # Example
+///
+/// ```
+/// # use neure::prelude::*;
+/// #
+/// # fn main() -> color_eyre::Result<()> {
+/// #     color_eyre::install()?;
+///     let ident = neu::ascii_alphabetic().repeat_one_more();
+///     let rules = [
+///         "if".into_dyn_regex(),
+///         ident.into_dyn_regex(),
+///         "=".into_dyn_regex(),
+///         "==".into_dyn_regex(),
+///     ];
+///     let step = re::lex_step(rules).ws();
+///     let mut ctx = CharsCtx::new("if iffy == x");
+///
+///     assert_eq!(ctx.ctor(&step)?, (0, "if"));
+///     assert_eq!(ctx.ctor(&step)?, (1, "iffy"));
+///     assert_eq!(ctx.ctor(&step)?, (3, "=="));
+///     assert_eq!(ctx.ctor(&step)?, (1, "x"));
+///     Ok(())
+/// # }
+/// ```
+pub fn lex_step<T>(rules: impl IntoIterator<Item = T>) -> LexStep<T> {
+    LexStep::new(rules.into_iter().collect())
+}
+
+///
+/// Read `N` values of `T` from the bytes in little endianness into an array.
+///
+/// # Example
+///
+/// ```
+/// # use neure::prelude::*;
+/// #
+/// # fn main() -> color_eyre::Result<()> {
+/// #     color_eyre::install()?;
+///     let rgba = re::read_array_le::<u8, 4>();
+///     let size = re::read_array_le::<u16, 2>();
+///     let mut ctx = BytesCtx::new(&[0xff, 0x80, 0, 0xff, 0x20, 0, 0x10, 0]);
+///
+///     assert_eq!(ctx.ctor(&rgba.then(size))?, ([0xff, 0x80, 0, 0xff], [0x20, 0x10]));
+///     Ok(())
+/// # }
+/// ```
+pub fn read_array_le<T, const N: usize>() -> ReadArrayLe<T, N> {
+    ReadArrayLe::new()
+}
+
 ///
 /// Consume all the left datas.
 ///
@@ -678,6 +1238,125 @@ pub fn not<T>(re: T) -> RegexNot<T> {
     RegexNot::new(re)
 }
 
+///
+/// Positive lookahead, match if `re` matches at current offset without consuming any data.
+///
+/// # Example
+///
+/// ```
+/// # use neure::prelude::*;
+/// #
+/// # fn main() -> color_eyre::Result<()> {
+/// #     color_eyre::install()?;
+///     let fn_name = neu::word().repeat_one_more().then(re::followed_by("("))._0();
+///     let mut ctx = CharsCtx::new("print(x)");
+///
+///     assert_eq!(ctx.ctor(&fn_name)?, "print");
+///     assert_eq!(ctx.offset(), 5);
+///     assert!(CharsCtx::new("print x").ctor(&fn_name).is_err());
+///     Ok(())
+/// # }
+/// ```
+pub fn followed_by<T>(re: T) -> FollowedBy<T> {
+    FollowedBy::new(re)
+}
+
+///
+/// Negative lookahead, match if `re` not matches at current offset without consuming any data.
+///
+/// # Example
+///
+/// ```
+/// # use neure::prelude::*;
+/// #
+/// # fn main() -> color_eyre::Result<()> {
+/// #     color_eyre::install()?;
+///     let var = neu::word().repeat_one_more().then(re::not_followed_by("("))._0();
+///     let mut ctx = CharsCtx::new("x + 1");
+///
+///     assert_eq!(ctx.ctor(&var)?, "x");
+///     assert_eq!(ctx.offset(), 1);
+///     assert!(CharsCtx::new("f(x)").ctor(&var).is_err());
+///     Ok(())
+/// # }
+/// ```
+pub fn not_followed_by<T>(re: T) -> FollowedBy<T> {
+    FollowedBy::new(re).with_negated(true)
+}
+
+///
+/// Return a regex that skips the trivia `re` as many times as possible,
+/// and records the span of each trivia into the [`TriviaSink`](crate::ctx::TriviaSink).
+///
+/// # Example
+///
+/// ```
+/// # use neure::prelude::*;
+/// #
+/// # fn main() -> color_eyre::Result<()> {
+/// #     color_eyre::install()?;
+///     let comment = "#".then(re::not("\n").then(re::consume(1)).repeat(0..));
+///     let trivia = re::skip_trivia(comment.or(neu::whitespace().repeat_one_more()));
+///     let word = neu::ascii_alphabetic().repeat_one_more();
+///     let words = word.padded(trivia).collect::<_, Vec<_>>();
+///     let str = "# hello\nfoo # x\nbar";
+///     let mut ctx = CharsCtx::new(str).with_sink();
+///
+///     assert_eq!(ctx.ctor(&words)?, ["foo", "bar"]);
+///     assert_eq!(ctx.trivia().len(), 5);
+///     assert_eq!(ctx.trivia()[3], Span::new(12, 3));
+///     Ok(())
+/// # }
+/// ```
+pub fn skip_trivia<T>(re: T) -> SkipTrivia<T> {
+    SkipTrivia::new(re)
+}
+
+///
+/// Match the `begin`, the items `pat` separated by `sep`, and the `end`.
+///
+/// # Example
+///
+/// ```
+/// # use neure::prelude::*;
+/// #
+/// # fn main() -> color_eyre::Result<()> {
+/// #     color_eyre::install()?;
+///     let num = neu::digit(10).repeat_one_more().map(map::from_str::<i32>());
+///     let block = re::block("begin".ws(), num.ws(), ",".ws(), "end");
+///
+///     assert_eq!(CharsCtx::new("begin 1,2,3 end").ctor(&block)?, [1, 2, 3]);
+///     assert_eq!(CharsCtx::new("begin 1,2,3 end").try_mat(&block)?, Span::new(0, 15));
+///     Ok(())
+/// # }
+/// ```
+pub fn block<C, B, P, S, E>(begin: B, pat: P, sep: S, end: E) -> Block<C, B, P, S, E> {
+    Block::new(begin, pat, sep, end)
+}
+
+///
+/// Match `pat`, return the result and the [`fxhash`](crate::re::ctor::fxhash) of the matched data.
+///
+/// # Example
+///
+/// ```
+/// # use neure::prelude::*;
+/// #
+/// # fn main() -> color_eyre::Result<()> {
+/// #     color_eyre::install()?;
+///     let hex = re::hashed(neu::ascii_hexdigit().repeat_one_more());
+///     let (val, hash) = BytesCtx::new(b"c0ffee").ctor(&hex)?;
+///
+///     assert_eq!(val, b"c0ffee");
+///     assert_eq!(hash, re::ctor::fxhash(b"c0ffee"));
+///     Ok(())
+/// # }
+/// ```
+#[cfg(feature = "hashing")]
+pub fn hashed<C, P>(pat: P) -> Hashed<C, P> {
+    Hashed::new(pat)
+}
+
 /// Iterate over the vector and match the regex against the [`Context`].
 /// It will return the result of first regex that matches.
 ///
@@ -750,6 +1429,42 @@ pub fn pair_vector<K, V: Clone>(val: impl IntoIterator<Item = (K, V)>) -> PairVe
     PairVector::new(val.into_iter().collect())
 }
 
+/// Iterate over the vector and match the regex against the [`Context`].
+/// It will return the index and the result of first regex that matches.
+///
+/// # Example
+///
+/// ```
+/// # use neure::prelude::*;
+/// #
+/// # fn main() -> color_eyre::Result<()> {
+/// #     color_eyre::install()?;
+///     #[derive(Debug, PartialEq, Eq)]
+///     enum Lit<'a> {
+///         Int(&'a str),
+///         Str(&'a str),
+///         Bool(&'a str),
+///     }
+///     let int = neu::digit(10).repeat_one_more();
+///     let str = neu::not('"').repeat_zero_more().quote("\"", "\"");
+///     let bool = "true".or("false");
+///     let vec = re::vector_indexed([int.into_dyn_regex(), str.into_dyn_regex(), bool.into_dyn_regex()]);
+///     let lit = vec.map(|(idx, val)| match idx {
+///         0 => Ok(Lit::Int(val)),
+///         1 => Ok(Lit::Str(val)),
+///         _ => Ok(Lit::Bool(val)),
+///     });
+///
+///     assert_eq!(CharsCtx::new("42").ctor(&lit)?, Lit::Int("42"));
+///     assert_eq!(CharsCtx::new(r#""hi""#).ctor(&lit)?, Lit::Str(r#""hi""#));
+///     assert_eq!(CharsCtx::new("false").ctor(&lit)?, Lit::Bool("false"));
+///     Ok(())
+/// # }
+/// ```
+pub fn vector_indexed<T>(val: impl IntoIterator<Item = T>) -> IndexedVector<T> {
+    IndexedVector::new(val.into_iter().collect())
+}
+
 pub fn array<const N: usize, T>(val: [T; N]) -> Array<N, T> {
     Array::new(val)
 }
@@ -1,21 +1,42 @@
+mod affix;
 mod array;
+mod as_str;
+mod block;
 mod boxed;
+mod capture;
 mod collect;
+mod copy;
+mod count;
+mod dbg;
+mod depth;
 mod dthen;
 mod dynamic;
+mod expect;
+mod fold;
+mod greedy;
+#[cfg(feature = "hashing")]
+mod hashed;
+mod hook;
 mod r#if;
+mod interleave;
 mod ltm;
 mod map;
+mod mapwhile;
 mod opt;
 mod or;
 mod pad;
 mod pat;
 mod quote;
+mod rebase;
+mod recover;
 mod repeat;
+mod retry;
 mod sep;
 mod slice;
+mod tap;
 mod then;
 mod vec;
+mod verify;
 
 use std::cell::Cell;
 use std::cell::RefCell;
@@ -23,41 +44,93 @@ use std::rc::Rc;
 use std::sync::Arc;
 use std::sync::Mutex;
 
+pub use self::affix::NotFollowedBy;
 pub use self::array::Array;
 pub use self::array::PairArray;
+pub use self::as_str::MatchedStr;
+pub use self::as_str::WithSlice;
+pub use self::block::Block;
 pub use self::boxed::BoxedCtor;
+pub use self::capture::Capture;
 pub use self::collect::Collect;
+pub use self::collect::CollectCounted;
+pub use self::collect::IndexBy;
+pub use self::copy::copy_into;
+pub use self::copy::CopyInto;
+pub use self::count::count_all;
+pub use self::count::CountAll;
+pub use self::dbg::DbgOnErr;
+pub use self::depth::MaxDepth;
 pub use self::dthen::DynamicCreateCtorThen;
 pub use self::dthen::DynamicCreateCtorThenHelper;
 pub use self::dynamic::DynamicArcCtor;
 pub use self::dynamic::DynamicBoxedCtor;
 pub use self::dynamic::DynamicBoxedCtorSync;
 pub use self::dynamic::DynamicRcCtor;
+pub use self::expect::Expect;
+pub use self::fold::Fold;
+pub use self::greedy::GreedyUntil;
+#[cfg(feature = "hashing")]
+pub use self::hashed::fxhash;
+#[cfg(feature = "hashing")]
+pub use self::hashed::Hashed;
+pub use self::hook::OnSuccess;
+pub use self::interleave::Interleave;
 pub use self::ltm::LongestTokenMatch;
 pub use self::map::Map;
+pub use self::map::MapEach;
+pub use self::map::MapOrElse;
+pub use self::mapwhile::MapWhile;
 pub use self::opt::OptionPat;
+pub use self::opt::OrDefault;
+pub use self::or::any_of;
 pub use self::or::Or;
+pub use self::or::OrAny;
 pub use self::pad::Pad;
 pub use self::pad::Padded;
 pub use self::pat::Pattern;
 pub use self::quote::Quote;
 pub use self::r#if::branch;
 pub use self::r#if::IfRegex;
+pub use self::rebase::Rebase;
+pub use self::recover::RecoverTo;
+pub use self::repeat::repeat_while;
 pub use self::repeat::Repeat;
+pub use self::repeat::RepeatLazy;
+pub use self::repeat::RepeatString;
+pub use self::repeat::RepeatWhile;
+pub use self::repeat::SkipMany;
+pub use self::retry::OrTransform;
+pub use self::retry::Scratch;
+pub use self::sep::EndReason;
+pub use self::sep::ManyTillBounded;
 pub use self::sep::SepCollect;
+pub use self::sep::SepEndBy;
+pub use self::sep::SepExactly;
+pub use self::sep::SepMapCollect;
 pub use self::sep::SepOnce;
+pub use self::sep::SepSpanned;
+pub use self::sep::SepString;
+pub use self::sep::SepTrivia;
+pub use self::sep::SepTwoPlus;
 pub use self::sep::Separate;
+pub use self::sep::WsList;
+pub use self::sep::WsTrivia;
 pub use self::slice::PairSlice;
 pub use self::slice::Slice;
+pub use self::tap::TapSpans;
 pub use self::then::IfThen;
 pub use self::then::Then;
+pub use self::vec::IndexedVector;
 pub use self::vec::PairVector;
 pub use self::vec::Vector;
+pub use self::verify::Verify;
 
 use crate::ctx::Context;
 use crate::ctx::Match;
 use crate::ctx::Span;
 use crate::err::Error;
+use crate::map::MapInto;
 use crate::neu::AsciiWhiteSpace;
 use crate::neu::CRange;
 use crate::neu::NeureZeroMore;
@@ -274,7 +347,7 @@ where
     }
 }
 
-impl<'a, C, M, O, H, A> Ctor<'a, C, M, O, H, A> for Box<dyn Ctor<'a, C, M, O, H, A>>
+impl<'a, C, M, O, H, A> Ctor<'a, C, M, O, H, A> for Box<dyn Ctor<'a, C, M, O, H, A> + '_>
 where
     C: Context<'a> + Match<C>,
     H: Handler<A, Out = M, Error = Error>,
@@ -314,30 +387,123 @@ where
 {
     fn map<F, O>(self, f: F) -> Map<C, Self, F, O>;
 
+    fn map_into<O, V>(self) -> Map<C, Self, MapInto<V>, O>;
+
+    fn map_each<F, O>(self, f: F) -> MapEach<C, Self, F, O>;
+
+    fn map_or_else<F, G, O, V>(self, on_ok: F, on_err: G) -> MapOrElse<C, Self, F, G, O>
+    where
+        F: Fn(O) -> V,
+        G: Fn(&Error) -> V;
+
     fn pat(self) -> Pattern<C, Self>;
 
+    fn matched_str<O>(self) -> MatchedStr<C, Self, O>;
+
+    fn with_slice<O>(self) -> WithSlice<C, Self, O>;
+
+    fn tap_spans<F>(self, func: F) -> TapSpans<C, Self, F>;
+
+    fn on_success<F>(self, func: F) -> OnSuccess<C, Self, F>;
+
+    fn verify<F>(self, func: F) -> Verify<C, Self, F>;
+
+    fn rebase(self, base: usize) -> Rebase<C, Self>;
+
     fn opt(self) -> OptionPat<C, Self>;
 
+    fn or_default(self) -> OrDefault<C, Self>;
+
+    fn recover_to<T>(self, sync: T) -> RecoverTo<C, Self, T>;
+
+    fn or_transform<'s, F, D>(
+        self,
+        scratch: &'s Scratch<D>,
+        func: F,
+    ) -> OrTransform<'s, C, Self, F, D>
+    where
+        D: ?Sized;
+
+    fn capture(self, id: usize) -> Capture<C, Self>;
+
+    fn max_depth(self, max: usize) -> MaxDepth<C, Self>;
+
+    fn dbg_on_err(self, name: &'static str) -> DbgOnErr<C, Self>;
+
+    fn expect(self, what: &'static str) -> Expect<C, Self>;
+
     fn quote<L, R>(self, left: L, right: R) -> Quote<C, Self, L, R>;
 
+    fn surrounded_by<D: Clone>(self, delim: D) -> Quote<C, Self, D, D>;
+
     fn sep<S>(self, sep: S) -> Separate<C, Self, S>;
 
     fn sep_once<S, R>(self, sep: S, right: R) -> SepOnce<C, Self, S, R>;
 
     fn sep_collect<S, O, V>(self, sep: S) -> SepCollect<C, Self, S, O, V>;
 
+    fn sep_to_string<S, O>(self, sep: S, join: &str) -> SepString<C, Self, S, O>;
+
+    fn sep_map_collect<S, F, O, U, V>(
+        self,
+        sep: S,
+        mapper: F,
+    ) -> SepMapCollect<C, Self, S, F, O, U, V>;
+
+    fn sep_two_plus<S>(self, sep: S) -> SepTwoPlus<C, Self, S>;
+
+    fn sep_end_by<S>(self, sep: S) -> SepEndBy<C, Self, S>;
+
+    fn sep_exactly<const N: usize, S>(self, sep: S) -> SepExactly<C, Self, S, N>;
+
+    fn sep_spanned<S>(self, sep: S) -> SepSpanned<C, Self, S>;
+
+    fn ws_list<S, L, R>(self, sep: S, open: L, close: R) -> WsList<C, Self, S, L, R>
+    where
+        C: Context<'a, Item = char>;
+
+    fn fold<S, I, F, O>(self, sep: S, init: I, func: F) -> Fold<C, Self, S, I, F, O>;
+
+    fn many_till_bounded<P, O, V>(self, term: P, max: usize) -> ManyTillBounded<C, Self, P, O, V>;
+
     fn or<P>(self, pat: P) -> Or<C, Self, P>;
 
+    fn or_any(self, others: Vec<Self>) -> OrAny<C, Self>
+    where
+        Self: Sized;
+
     fn ltm<P>(self, pat: P) -> LongestTokenMatch<C, Self, P>;
 
     fn then<T>(self, then: T) -> Then<C, Self, T>;
 
+    fn interleave<T>(self, other: T) -> Interleave<C, Self, T>;
+
+    fn greedy_until<T>(self, term: T) -> GreedyUntil<C, Self, T>;
+
+    fn not_followed_by<T>(self, la: T) -> NotFollowedBy<C, Self, T>;
+
     fn if_then<I, T>(self, r#if: I, then: T) -> IfThen<C, Self, I, T>;
 
     fn repeat(self, range: impl Into<CRange<usize>>) -> Repeat<C, Self>;
 
+    fn repeat_with_progress_guard(self, range: impl Into<CRange<usize>>) -> Repeat<C, Self>;
+
+    fn repeat_string(self, range: impl Into<CRange<usize>>) -> RepeatString<C, Self>;
+
+    fn repeat_lazy<P>(self, range: impl Into<CRange<usize>>, term: P) -> RepeatLazy<C, Self, P>;
+
+    fn skip_many(self) -> SkipMany<C, Self>;
+
+    fn skip_many1(self) -> SkipMany<C, Self>;
+
     fn collect<O, T>(self) -> Collect<C, Self, O, T>;
 
+    fn collect_counted<O, V>(self) -> CollectCounted<C, Self, O, V>;
+
+    fn index_by<F>(self, key: F) -> IndexBy<C, Self, F>;
+
+    fn map_while<F, O, V>(self, func: F) -> MapWhile<C, Self, F, O, V>;
+
     fn r#if<I, E>(self, r#if: I, r#else: E) -> IfRegex<C, Self, I, E>
     where
         I: Fn(&C) -> Result<bool, Error>;
@@ -360,6 +526,79 @@ where
         Map::new(self, func)
     }
 
+    ///
+    /// Convert the result of `P` into `V` using [`From`], the `V` can be inferred from context.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use neure::prelude::*;
+    /// #
+    /// # fn main() -> color_eyre::Result<()> {
+    /// #     color_eyre::install()?;
+    ///     let name = neu::ascii_alphabetic().repeat_one_more().map_into();
+    ///     let name: String = CharsCtx::new("neure").ctor(&name)?;
+    ///
+    ///     assert_eq!(name, "neure");
+    ///     Ok(())
+    /// # }
+    /// ```
+    fn map_into<O, V>(self) -> Map<C, Self, MapInto<V>, O> {
+        Map::new(self, MapInto::new())
+    }
+
+    ///
+    /// Map each element of the result using `F`, stop and return the error at the first failure.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use neure::prelude::*;
+    /// #
+    /// # fn main() -> color_eyre::Result<()> {
+    /// #     color_eyre::install()?;
+    ///     let num = neu::digit(10).repeat_one_more();
+    ///     let nums = num.sep(",".ws()).map_each(map::from_str::<u8>());
+    ///     let nums = nums.quote("[", "]");
+    ///
+    ///     assert_eq!(CharsCtx::new("[1, 2, 42]").ctor(&nums)?, [1, 2, 42]);
+    ///     assert!(CharsCtx::new("[1, 256, 42]").ctor(&nums).is_err());
+    ///     Ok(())
+    /// # }
+    /// ```
+    fn map_each<F, O>(self, func: F) -> MapEach<C, Self, F, O> {
+        MapEach::new(self, func)
+    }
+
+    ///
+    /// Match `P`, map the result with `on_ok` if it succeeds, otherwise map the error with `on_err`.
+    /// It never fails.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use neure::prelude::*;
+    /// #
+    /// # fn main() -> color_eyre::Result<()> {
+    /// #     color_eyre::install()?;
+    ///     let num = neu::digit(10).repeat_one_more().map(map::from_str::<i32>());
+    ///     let num = num.map_or_else(Ok, |e| Err(e.to_string()));
+    ///     let mut ctx = CharsCtx::new("x");
+    ///
+    ///     assert_eq!(CharsCtx::new("42").ctor(&num)?, Ok(42));
+    ///     assert!(ctx.ctor(&num)?.is_err());
+    ///     assert_eq!(ctx.offset(), 0);
+    ///     Ok(())
+    /// # }
+    /// ```
+    fn map_or_else<F, G, O, V>(self, on_ok: F, on_err: G) -> MapOrElse<C, Self, F, G, O>
+    where
+        F: Fn(O) -> V,
+        G: Fn(&Error) -> V,
+    {
+        MapOrElse::new(self, on_ok, on_err)
+    }
+
     ///
     /// Call [`.try_mat`](crate::ctx::Match#tymethod.try_mat) to match regex `P`.
     ///
@@ -386,6 +625,158 @@ where
         Pattern::new(self)
     }
 
+    ///
+    /// Construct the value of `P` and discard it, return the data covered by `P`.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use neure::prelude::*;
+    /// #
+    /// # fn main() -> color_eyre::Result<()> {
+    /// #     color_eyre::install()?;
+    ///     let int = neu::digit(10).repeat_one_more();
+    ///     let num = int.sep_once(".", int);
+    ///     let num = num.map(|(a, b): (&str, &str)| Ok((a.len(), b.len())));
+    ///     let text = num.matched_str();
+    ///     let mut ctx = CharsCtx::new("3.14 rad");
+    ///
+    ///     assert_eq!(ctx.ctor(&num)?, (1, 2));
+    ///     assert_eq!(ctx.reset().ctor(&text)?, "3.14");
+    ///     assert_eq!(ctx.offset(), 4);
+    ///     Ok(())
+    /// # }
+    /// ```
+    fn matched_str<O>(self) -> MatchedStr<C, Self, O> {
+        MatchedStr::new(self)
+    }
+
+    ///
+    /// Construct the value of `P`, return it with the data covered by `P`.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use neure::prelude::*;
+    /// #
+    /// # fn main() -> color_eyre::Result<()> {
+    /// #     color_eyre::install()?;
+    ///     let digit = neu::digit(10).repeat_one_more().map(map::from_str::<i64>());
+    ///     let digit = digit.with_slice();
+    ///     let mut ctx = CharsCtx::new("42 apples");
+    ///
+    ///     assert_eq!(ctx.ctor(&digit)?, (42i64, "42"));
+    ///     assert_eq!(ctx.offset(), 2);
+    ///     Ok(())
+    /// # }
+    /// ```
+    fn with_slice<O>(self) -> WithSlice<C, Self, O> {
+        WithSlice::new(self)
+    }
+
+    ///
+    /// Match `P` and pass the [`Span`] of `P` to the callback if it succeeds.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use std::cell::RefCell;
+    /// #
+    /// # use neure::prelude::*;
+    /// #
+    /// # fn main() -> color_eyre::Result<()> {
+    /// #     color_eyre::install()?;
+    ///     let spans = RefCell::new(vec![]);
+    ///     let num = neu::digit(10).repeat_one_more().map(map::from_str::<i32>());
+    ///     let num = num.tap_spans(|span: &Span| spans.borrow_mut().push(*span));
+    ///     let list = num.sep(",".ws()).quote("[", "]");
+    ///
+    ///     assert_eq!(CharsCtx::new("[1, 22, 333]").ctor(&list)?, [1, 22, 333]);
+    ///     assert_eq!(
+    ///         spans.take(),
+    ///         [Span::new(1, 1), Span::new(4, 2), Span::new(8, 3)]
+    ///     );
+    ///     Ok(())
+    /// # }
+    /// ```
+    fn tap_spans<F>(self, func: F) -> TapSpans<C, Self, F> {
+        TapSpans::new(self, func)
+    }
+
+    ///
+    /// Match `P` and pass the result of `P` to the callback if it succeeds.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use std::cell::Cell;
+    /// #
+    /// # use neure::prelude::*;
+    /// #
+    /// # fn main() -> color_eyre::Result<()> {
+    /// #     color_eyre::install()?;
+    ///     let sum = Cell::new(0);
+    ///     let num = neu::digit(10).repeat_one_more().map(map::from_str::<i32>());
+    ///     let num = num.on_success(|v: &i32| sum.set(sum.get() + v));
+    ///
+    ///     assert_eq!(CharsCtx::new("1,2,3").ctor(&num.sep(","))?, [1, 2, 3]);
+    ///     assert_eq!(sum.get(), 6);
+    ///     Ok(())
+    /// # }
+    /// ```
+    fn on_success<F>(self, func: F) -> OnSuccess<C, Self, F> {
+        OnSuccess::new(self, func)
+    }
+
+    ///
+    /// Construct the value of `P` and reject it if the predicate returns false.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use neure::prelude::*;
+    /// #
+    /// # fn main() -> color_eyre::Result<()> {
+    /// #     color_eyre::install()?;
+    ///     let port = neu::digit(10).repeat_one_more().map(map::from_str::<u32>());
+    ///     let port = port.verify(|v: &u32| *v <= 65535);
+    ///
+    ///     assert_eq!(CharsCtx::new("8080").ctor(&port)?, 8080);
+    ///     assert!(CharsCtx::new("99999").ctor(&port).is_err());
+    ///     Ok(())
+    /// # }
+    /// ```
+    fn verify<F>(self, func: F) -> Verify<C, Self, F> {
+        Verify::new(self, func)
+    }
+
+    ///
+    /// Add `base` to the [`Span`] returned by `P`.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use neure::prelude::*;
+    /// #
+    /// # fn main() -> color_eyre::Result<()> {
+    /// #     color_eyre::install()?;
+    ///     let str = "fn main() {let a; let b;}";
+    ///     let body = neu::not('}').repeat_zero_more().quote("{", "}");
+    ///     let body = CharsCtx::new(str).ctor_span(&"fn main() ".then(body))?.1;
+    ///     let stmt = neu::ascii_alphabetic().repeat_one_more();
+    ///     let stmt = "let ".then(stmt).map(map::select1()).rebase(body.beg);
+    ///     let stmts = stmt.sep(";".ws());
+    ///     let spans = CharsCtx::new(&str[body.beg..body.beg + body.len]).ctor_span(&stmts)?;
+    ///
+    ///     assert_eq!(spans, [Span::new(15, 1), Span::new(22, 1)]);
+    ///     assert_eq!(&str[spans[1].beg..][..spans[1].len], "b");
+    ///     Ok(())
+    /// # }
+    /// ```
+    fn rebase(self, base: usize) -> Rebase<C, Self> {
+        Rebase::new(self, base)
+    }
+
     /// Match `P` and return the result wrapped by `Option`, ignoring the error.
     ///
     /// # Example
@@ -400,18 +791,474 @@ where
     ///         .map(map::from_str::<usize>())
     ///         .opt();
     ///
-    ///     assert_eq!(CharsCtx::new("foo").ctor(&num)?, None);
-    ///     assert_eq!(CharsCtx::new("955").ctor(&num)?, Some(955));
+    ///     assert_eq!(CharsCtx::new("foo").ctor(&num)?, None);
+    ///     assert_eq!(CharsCtx::new("955").ctor(&num)?, Some(955));
+    ///     Ok(())
+    /// # }
+    /// ```
+    fn opt(self) -> OptionPat<C, Self> {
+        OptionPat::new(self)
+    }
+
+    /// Match `P` and return the result, return the default value of result if match failed.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use neure::prelude::*;
+    /// #
+    /// # fn main() -> color_eyre::Result<()> {
+    /// #     color_eyre::install()?;
+    ///     let num = neu::digit(10)
+    ///         .repeat_one_more()
+    ///         .map(map::from_str::<usize>())
+    ///         .or_default();
+    ///
+    ///     assert_eq!(CharsCtx::new("foo").ctor(&num)?, 0);
+    ///     assert_eq!(CharsCtx::new("955").ctor(&num)?, 955);
+    ///     Ok(())
+    /// # }
+    /// ```
+    fn or_default(self) -> OrDefault<C, Self> {
+        OrDefault::new(self)
+    }
+
+    ///
+    /// Match `P`, if it failed, record the error and skip the data until `T` matched.
+    ///
+    /// The [`Context`] must implement [`ErrorSink`](crate::ctx::ErrorSink),
+    /// such as [`SinkCtx`](crate::ctx::SinkCtx).
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use neure::prelude::*;
+    /// #
+    /// # fn main() -> color_eyre::Result<()> {
+    /// #     color_eyre::install()?;
+    ///     let item = neu::ascii_alphabetic().repeat_one().ws();
+    ///     let item = item.pad(";".ws().or(re::end())).recover_to(";".ws());
+    ///     let mut ctx = CharsCtx::new("a; @; c").with_sink();
+    ///
+    ///     assert_eq!(ctx.ctor(&item.repeat(1..))?, [Some("a"), None, Some("c")]);
+    ///     assert_eq!(ctx.errors().len(), 1);
+    ///     Ok(())
+    /// # }
+    /// ```
+    fn recover_to<P>(self, sync: P) -> RecoverTo<C, Self, P> {
+        RecoverTo::new(self, sync)
+    }
+
+    ///
+    /// Match `P`, if it failed, repair the upcoming data with `F` and try `P` again.
+    /// The repaired data is kept in the [`Scratch`], see [`OrTransform`].
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use neure::prelude::*;
+    /// # use neure::re::ctor::Scratch;
+    /// #
+    /// # fn main() -> color_eyre::Result<()> {
+    /// #     color_eyre::install()?;
+    ///     let scratch = Scratch::new();
+    ///     let digits = neu::digit(10).repeat_one_more();
+    ///     let num = digits.then(".").then(digits).pat();
+    ///     let num = num.map(map::from_str::<f64>());
+    ///     // accept the comma as decimal separator
+    ///     let num = num.or_transform(&scratch, |s: &str| {
+    ///         let len = s.find(|c: char| !c.is_ascii_digit() && c != ',').unwrap_or(s.len());
+    ///
+    ///         Some((len, s[..len].replace(',', ".").into()))
+    ///     });
+    ///
+    ///     assert_eq!(CharsCtx::new("3.14").ctor(&num)?, 3.14);
+    ///     assert_eq!(CharsCtx::new("2,5").ctor(&num)?, 2.5);
+    ///     Ok(())
+    /// # }
+    /// ```
+    fn or_transform<'s, F, D>(
+        self,
+        scratch: &'s Scratch<D>,
+        func: F,
+    ) -> OrTransform<'s, C, Self, F, D>
+    where
+        D: ?Sized,
+    {
+        OrTransform::new(self, scratch, func)
+    }
+
+    ///
+    /// Match `P`, if it succeeds, store the span into the storer of [`Context`] with given `id`.
+    ///
+    /// The [`Context`] must implement [`CaptureSink`](crate::ctx::CaptureSink),
+    /// such as [`SinkCtx`](crate::ctx::SinkCtx).
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use neure::prelude::*;
+    /// #
+    /// # fn main() -> color_eyre::Result<()> {
+    /// #     color_eyre::install()?;
+    ///     let name = neu::ascii_alphabetic().repeat_one_more().capture(0);
+    ///     let names = name.sep(",".ws());
+    ///     let str = "rust, c, go";
+    ///     let mut ctx = CharsCtx::new(str).with_sink();
+    ///
+    ///     assert_eq!(ctx.ctor(&names)?, ["rust", "c", "go"]);
+    ///     assert_eq!(
+    ///         ctx.captures().slice_iter(str, 0).unwrap().collect::<Vec<_>>(),
+    ///         ["rust", "c", "go"]
+    ///     );
+    ///     Ok(())
+    /// # }
+    /// ```
+    fn capture(self, id: usize) -> Capture<C, Self> {
+        Capture::new(self, id)
+    }
+
+    ///
+    /// Limit the nesting depth of `P`, return [`Error::DepthExceeded`] if more than `max`
+    /// `P` are nested, the [`Context`] must have a [`DepthSink`](crate::ctx::DepthSink).
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use neure::prelude::*;
+    /// #
+    /// # fn main() -> color_eyre::Result<()> {
+    /// #     color_eyre::install()?;
+    ///     let deep = format!("{}{}", "[".repeat(10000), "]".repeat(10000));
+    ///     let arr = re::rec_parser(|ctor| {
+    ///         ctor.repeat(0..)
+    ///             .quote("[", "]")
+    ///             .map(|v: Vec<usize>| Ok(v.len()))
+    ///             .max_depth(8)
+    ///     });
+    ///
+    ///     assert_eq!(CharsCtx::new("[[][[]]]").with_sink().ctor(&arr)?, 2);
+    ///     assert!(CharsCtx::new(&deep).with_sink().ctor(&arr).is_err());
+    ///     Ok(())
+    /// # }
+    /// ```
+    fn max_depth(self, max: usize) -> MaxDepth<C, Self> {
+        MaxDepth::new(self, max)
+    }
+
+    ///
+    /// Match `P`, if it failed, print the `name`, offset and the following input to stderr.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use neure::prelude::*;
+    /// #
+    /// # fn main() -> color_eyre::Result<()> {
+    /// #     color_eyre::install()?;
+    ///     let key = neu::ascii_alphabetic().repeat_one_more().dbg_on_err("key");
+    ///     let val = neu::digit(10).repeat_one_more().dbg_on_err("val");
+    ///     let pair = key.sep_once("=", val);
+    ///
+    ///     assert_eq!(CharsCtx::new("a=1").ctor(&pair)?, ("a", "1"));
+    ///     // print r`val` failed @2: "x" (..) to stderr
+    ///     assert!(CharsCtx::new("a=x").ctor(&pair).is_err());
+    ///     Ok(())
+    /// # }
+    /// ```
+    fn dbg_on_err(self, name: &'static str) -> DbgOnErr<C, Self> {
+        DbgOnErr::new(self, name)
+    }
+
+    ///
+    /// Match `P`, replace the error with [`Error::Expected`] if it failed.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use neure::prelude::*;
+    /// #
+    /// # fn main() -> color_eyre::Result<()> {
+    /// #     color_eyre::install()?;
+    ///     let ident = neu::ascii_alphabetic().repeat_one_more().expect("identifier");
+    ///     let stmt = "let ".then(ident);
+    ///     let err = CharsCtx::new("let 1").ctor(&stmt).unwrap_err();
+    ///
+    ///     assert_eq!(err.to_string(), "expected identifier at offset 4");
+    ///     Ok(())
+    /// # }
+    /// ```
+    fn expect(self, what: &'static str) -> Expect<C, Self> {
+        Expect::new(self, what)
+    }
+
+    ///
+    /// First try to match `L`. If it is succeeds, then try to match `P`.
+    /// If it is succeeds, then try to match `R`.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use neure::prelude::*;
+    /// #
+    /// # fn main() -> color_eyre::Result<()> {
+    /// #     color_eyre::install()?;
+    ///     let ascii = neu::ascii().repeat_one();
+    ///     let lit = ascii.quote("'", "'");
+    ///     let ele = lit.sep(",".ws());
+    ///     let arr = ele.quote("[", "]");
+    ///     let mut ctx = CharsCtx::new("['a', 'c', 'd', 'f']");
+    ///
+    ///     assert_eq!(ctx.ctor(&arr)?, ["a", "c", "d", "f"]);
+    ///
+    ///     Ok(())
+    /// # }
+    /// ```
+    fn quote<L, R>(self, left: L, right: R) -> Quote<C, Self, L, R> {
+        Quote::new(self, left, right)
+    }
+
+    ///
+    /// Same as [`quote`](ConstructOp::quote), but use the same delimiter `D` on both sides.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use neure::prelude::*;
+    /// #
+    /// # fn main() -> color_eyre::Result<()> {
+    /// #     color_eyre::install()?;
+    ///     let name = neu::ascii_alphabetic().repeat_one_more();
+    ///     let var = name.surrounded_by("%");
+    ///     let mut ctx = CharsCtx::new("%HOME%");
+    ///
+    ///     assert_eq!(ctx.ctor(&var)?, "HOME");
+    ///     Ok(())
+    /// # }
+    /// ```
+    fn surrounded_by<D: Clone>(self, delim: D) -> Quote<C, Self, D, D> {
+        Quote::new(self, delim.clone(), delim)
+    }
+
+    ///
+    /// Match regex `P` as many times as possible, with S as the delimiter.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use neure::prelude::*;
+    /// #
+    /// # fn main() -> color_eyre::Result<()> {
+    /// #     color_eyre::install()?;
+    ///     let name = re!([^ ',' ']' '[']+);
+    ///     let sep = ','.repeat_one().ws();
+    ///     let arr = name.sep(sep);
+    ///     let arr = arr.quote("[", "]");
+    ///     let mut ctx = CharsCtx::new(r#"[c, rust, java, c++]"#);
+    ///
+    ///     assert_eq!(ctx.ctor(&arr)?, vec!["c", "rust", "java", "c++"]);
+    ///     Ok(())
+    /// # }
+    /// ```
+    fn sep<S>(self, sep: S) -> Separate<C, Self, S> {
+        Separate::new(self, sep)
+    }
+
+    ///
+    /// Match `L` and `R` separated by `S`.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use neure::prelude::*;
+    /// #
+    /// # fn main() -> color_eyre::Result<()> {
+    /// #     color_eyre::install()?;
+    ///     let key = neu::alphabetic().repeat_one_more().ws();
+    ///     let val = neu::whitespace().or(',').not().repeat_one_more().ws();
+    ///     let sep = "=>".ws();
+    ///     let ele = key.sep_once(sep, val);
+    ///     let hash = ele.sep(",".ws()).quote("{".ws(), "}");
+    ///     let mut ctx = CharsCtx::new(
+    ///         r#"{
+    ///         c => c11,
+    ///         cpp => c++23,
+    ///         rust => 2021,
+    ///     }"#,
+    ///     );
+    ///
+    ///     assert_eq!(
+    ///         ctx.ctor(&hash)?,
+    ///         [("c", "c11"), ("cpp", "c++23"), ("rust", "2021")]
+    ///     );
+    ///     Ok(())
+    /// # }
+    /// ```
+    fn sep_once<S, R>(self, sep: S, right: R) -> SepOnce<C, Self, S, R> {
+        SepOnce::new(self, sep, right)
+    }
+
+    ///
+    /// Match regex `P` as many times as possible, with S as the delimiter.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use std::collections::HashMap;
+    /// #
+    /// # use neure::prelude::*;
+    /// #
+    /// # fn main() -> color_eyre::Result<()> {
+    /// #     color_eyre::install()?;
+    ///     let key = neu::alphabetic().repeat_one_more().ws();
+    ///     let val = neu::whitespace().or(',').not().repeat_one_more().ws();
+    ///     let sep = "=>".ws();
+    ///     let ele = key.sep_once(sep, val);
+    ///     let hash = ele.sep_collect(",".ws()).quote("{".ws(), "}");
+    ///     let mut ctx = CharsCtx::new(
+    ///         r#"{
+    ///         c => c11,
+    ///         cpp => c++23,
+    ///         rust => 2021,
+    ///     }"#,
+    ///     );
+    ///
+    ///     let hash: HashMap<&str, &str> = ctx.ctor(&hash)?;
+    ///
+    ///     assert_eq!(hash.get("c"), Some(&"c11"));
+    ///     assert_eq!(hash.get("cpp"), Some(&"c++23"));
+    ///     assert_eq!(hash.get("rust"), Some(&"2021"));
+    ///     Ok(())
+    /// # }
+    /// ```
+    fn sep_collect<S, O, V>(self, sep: S) -> SepCollect<C, Self, S, O, V> {
+        SepCollect::new(self, sep)
+    }
+
+    ///
+    /// Match regex `P` as many times as possible, with S as the delimiter,
+    /// join the results into a [`String`] with `join`.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use neure::prelude::*;
+    /// #
+    /// # fn main() -> color_eyre::Result<()> {
+    /// #     color_eyre::install()?;
+    ///     let item = neu::ascii_alphabetic().repeat_one();
+    ///     let list = item.sep_to_string(",", "|");
+    ///     let mut ctx = CharsCtx::new("a,b,c");
+    ///
+    ///     assert_eq!(ctx.ctor(&list)?, "a|b|c");
+    ///     Ok(())
+    /// # }
+    /// ```
+    fn sep_to_string<S, O>(self, sep: S, join: &str) -> SepString<C, Self, S, O> {
+        SepString::new(self, sep, join)
+    }
+
+    ///
+    /// Match regex `P` as many times as possible, with S as the delimiter,
+    /// map each result using `F` and collect the values into `V`.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use std::collections::HashSet;
+    /// #
+    /// # use neure::prelude::*;
+    /// #
+    /// # fn main() -> color_eyre::Result<()> {
+    /// #     color_eyre::install()?;
+    ///     let num = neu::digit(10).repeat_one_more();
+    ///     let nums = num.sep_map_collect(",", map::from_str::<u8>());
+    ///     let set: HashSet<u8> = CharsCtx::new("1,2,2,3,1").ctor(&nums)?;
+    ///
+    ///     assert_eq!(set, HashSet::from([1, 2, 3]));
+    ///     assert!(CharsCtx::new("1,256").ctor::<_, HashSet<u8>>(&nums).is_err());
+    ///     Ok(())
+    /// # }
+    /// ```
+    fn sep_map_collect<S, F, O, U, V>(
+        self,
+        sep: S,
+        mapper: F,
+    ) -> SepMapCollect<C, Self, S, F, O, U, V> {
+        SepMapCollect::new(self, sep, mapper)
+    }
+
+    ///
+    /// Match regex `P` at least twice with S as the delimiter,
+    /// return the first result and the rest results.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use neure::prelude::*;
+    /// #
+    /// # fn main() -> color_eyre::Result<()> {
+    /// #     color_eyre::install()?;
+    ///     let num = neu::digit(10).repeat_one_more().map(map::from_str::<i32>());
+    ///     let nums = num.sep_two_plus(",");
+    ///
+    ///     assert_eq!(CharsCtx::new("1,2,3").ctor(&nums)?, (1, vec![2, 3]));
+    ///     assert!(CharsCtx::new("1").ctor(&nums).is_err());
+    ///     Ok(())
+    /// # }
+    /// ```
+    fn sep_two_plus<S>(self, sep: S) -> SepTwoPlus<C, Self, S> {
+        SepTwoPlus::new(self, sep)
+    }
+
+    ///
+    /// Match regex `P` as many times as possible, every `P` must be followed by the delimiter `S`.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use neure::prelude::*;
+    /// #
+    /// # fn main() -> color_eyre::Result<()> {
+    /// #     color_eyre::install()?;
+    ///     let num = neu::digit(10).repeat_one_more().map(map::from_str::<i32>());
+    ///     let nums = num.sep_end_by(";");
+    ///
+    ///     assert_eq!(CharsCtx::new("1;2;3;").ctor(&nums)?, [1, 2, 3]);
+    ///     assert!(CharsCtx::new("1;2;3").ctor(&nums).is_err());
+    ///     Ok(())
+    /// # }
+    /// ```
+    fn sep_end_by<S>(self, sep: S) -> SepEndBy<C, Self, S> {
+        SepEndBy::new(self, sep)
+    }
+
+    ///
+    /// Match regex `P` exactly `N` times with S as the delimiter, return an array of the results.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use neure::prelude::*;
+    /// #
+    /// # fn main() -> color_eyre::Result<()> {
+    /// #     color_eyre::install()?;
+    ///     let num = neu::digit(10).repeat_one_more().map(map::from_str::<u8>());
+    ///     let rgba = num.sep_exactly::<4, _>(",");
+    ///
+    ///     assert_eq!(CharsCtx::new("1,2,3,4").ctor(&rgba)?, [1, 2, 3, 4]);
+    ///     assert!(CharsCtx::new("1,2,3").ctor(&rgba).is_err());
+    ///     assert!(CharsCtx::new("1,2,3,4,").ctor(&rgba).is_err());
     ///     Ok(())
     /// # }
     /// ```
-    fn opt(self) -> OptionPat<C, Self> {
-        OptionPat::new(self)
+    fn sep_exactly<const N: usize, S>(self, sep: S) -> SepExactly<C, Self, S, N> {
+        SepExactly::new(self, sep)
     }
 
     ///
-    /// First try to match `L`. If it is succeeds, then try to match `P`.
-    /// If it is succeeds, then try to match `R`.
+    /// Match regex `P` as many times as possible, with S as the delimiter,
+    /// every result of `P` is paired with its [`Span`].
     ///
     /// # Example
     ///
@@ -420,23 +1267,25 @@ where
     /// #
     /// # fn main() -> color_eyre::Result<()> {
     /// #     color_eyre::install()?;
-    ///     let ascii = neu::ascii().repeat_one();
-    ///     let lit = ascii.quote("'", "'");
-    ///     let ele = lit.sep(",".ws());
-    ///     let arr = ele.quote("[", "]");
-    ///     let mut ctx = CharsCtx::new("['a', 'c', 'd', 'f']");
-    ///
-    ///     assert_eq!(ctx.ctor(&arr)?, ["a", "c", "d", "f"]);
+    ///     let name = neu::ascii_alphabetic().repeat_one_more();
+    ///     let names = name.sep_spanned(",");
+    ///     let mut ctx = CharsCtx::new("foo,bar");
     ///
+    ///     assert_eq!(
+    ///         ctx.ctor(&names)?,
+    ///         [(Span::new(0, 3), "foo"), (Span::new(4, 3), "bar")]
+    ///     );
     ///     Ok(())
     /// # }
     /// ```
-    fn quote<L, R>(self, left: L, right: R) -> Quote<C, Self, L, R> {
-        Quote::new(self, left, right)
+    fn sep_spanned<S>(self, sep: S) -> SepSpanned<C, Self, S> {
+        SepSpanned::new(self, sep)
     }
 
     ///
-    /// Match regex `P` as many times as possible, with S as the delimiter.
+    /// Match regex `P` as many times as possible with S as the delimiter, quoted by `open` and `close`.
+    /// The whitespace around the `open`, `close` and every delimiter is skipped.
+    /// It also accepts an empty list or a trailing delimiter.
     ///
     /// # Example
     ///
@@ -445,22 +1294,30 @@ where
     /// #
     /// # fn main() -> color_eyre::Result<()> {
     /// #     color_eyre::install()?;
-    ///     let name = re!([^ ',' ']' '[']+);
-    ///     let sep = ','.repeat_one().ws();
-    ///     let arr = name.sep(sep);
-    ///     let arr = arr.quote("[", "]");
-    ///     let mut ctx = CharsCtx::new(r#"[c, rust, java, c++]"#);
+    ///     let num = neu::digit(10).repeat_one_more().map(map::from_str::<i32>());
+    ///     let nums = num.ws_list(",", "[", "]");
     ///
-    ///     assert_eq!(ctx.ctor(&arr)?, vec!["c", "rust", "java", "c++"]);
+    ///     assert_eq!(CharsCtx::new("[ 1 , 2 ,3 ]").ctor(&nums)?, [1, 2, 3]);
+    ///     assert_eq!(CharsCtx::new("[ ]").ctor(&nums)?, []);
     ///     Ok(())
     /// # }
     /// ```
-    fn sep<S>(self, sep: S) -> Separate<C, Self, S> {
-        Separate::new(self, sep)
+    fn ws_list<S, L, R>(self, sep: S, open: L, close: R) -> WsList<C, Self, S, L, R>
+    where
+        C: Context<'a, Item = char>,
+    {
+        let ws = || NeureZeroMore::new(AsciiWhiteSpace, NullCond);
+
+        Quote::new(
+            Separate::new(self, Pad::new(Padded::new(sep, ws()), ws())).at_least(0),
+            Pad::new(Padded::new(open, ws()), ws()),
+            Pad::new(Padded::new(close, ws()), ws()),
+        )
     }
 
     ///
-    /// Match `L` and `R` separated by `S`.
+    /// Match regex `P` as many times as possible with S as the delimiter,
+    /// fold the results into the accumulator created by `init` without collecting them.
     ///
     /// # Example
     ///
@@ -469,65 +1326,46 @@ where
     /// #
     /// # fn main() -> color_eyre::Result<()> {
     /// #     color_eyre::install()?;
-    ///     let key = neu::alphabetic().repeat_one_more().ws();
-    ///     let val = neu::whitespace().or(',').not().repeat_one_more().ws();
-    ///     let sep = "=>".ws();
-    ///     let ele = key.sep_once(sep, val);
-    ///     let hash = ele.sep(",".ws()).quote("{".ws(), "}");
-    ///     let mut ctx = CharsCtx::new(
-    ///         r#"{
-    ///         c => c11,
-    ///         cpp => c++23,
-    ///         rust => 2021,
-    ///     }"#,
-    ///     );
+    ///     let num = neu::digit(10).repeat_one_more().map(map::from_str::<i32>());
+    ///     let sum = num.fold("+", || 0, |acc, v| acc + v);
     ///
-    ///     assert_eq!(
-    ///         ctx.ctor(&hash)?,
-    ///         [("c", "c11"), ("cpp", "c++23"), ("rust", "2021")]
-    ///     );
+    ///     assert_eq!(CharsCtx::new("1+2+3").ctor(&sum)?, 6);
     ///     Ok(())
     /// # }
     /// ```
-    fn sep_once<S, R>(self, sep: S, right: R) -> SepOnce<C, Self, S, R> {
-        SepOnce::new(self, sep, right)
+    fn fold<S, I, F, O>(self, sep: S, init: I, func: F) -> Fold<C, Self, S, I, F, O> {
+        Fold::new(self, sep, init, func)
     }
 
     ///
-    /// Match regex `P` as many times as possible, with S as the delimiter.
+    /// Match regex `P` until the terminator `T` matched or the count reached `max`,
+    /// return the results and the [`EndReason`].
     ///
     /// # Example
     ///
     /// ```
-    /// # use std::collections::HashMap;
-    /// #
     /// # use neure::prelude::*;
+    /// # use neure::re::EndReason;
     /// #
     /// # fn main() -> color_eyre::Result<()> {
     /// #     color_eyre::install()?;
-    ///     let key = neu::alphabetic().repeat_one_more().ws();
-    ///     let val = neu::whitespace().or(',').not().repeat_one_more().ws();
-    ///     let sep = "=>".ws();
-    ///     let ele = key.sep_once(sep, val);
-    ///     let hash = ele.sep_collect(",".ws()).quote("{".ws(), "}");
-    ///     let mut ctx = CharsCtx::new(
-    ///         r#"{
-    ///         c => c11,
-    ///         cpp => c++23,
-    ///         rust => 2021,
-    ///     }"#,
-    ///     );
+    ///     let num = neu::digit(10).repeat_one_more().map(map::from_str::<i32>());
+    ///     let nums = num.pad(",").many_till_bounded::<_, _, Vec<_>>(";", 3);
     ///
-    ///     let hash: HashMap<&str, &str> = ctx.ctor(&hash)?;
-    ///
-    ///     assert_eq!(hash.get("c"), Some(&"c11"));
-    ///     assert_eq!(hash.get("cpp"), Some(&"c++23"));
-    ///     assert_eq!(hash.get("rust"), Some(&"2021"));
+    ///     assert_eq!(
+    ///         CharsCtx::new("1,2,;").ctor(&nums)?,
+    ///         (vec![1, 2], EndReason::Terminator)
+    ///     );
+    ///     assert_eq!(
+    ///         CharsCtx::new("1,2,3,4,;").ctor(&nums)?,
+    ///         (vec![1, 2, 3], EndReason::MaxReached)
+    ///     );
+    ///     assert!(CharsCtx::new("1,x;").ctor(&nums).is_err());
     ///     Ok(())
     /// # }
     /// ```
-    fn sep_collect<S, O, V>(self, sep: S) -> SepCollect<C, Self, S, O, V> {
-        SepCollect::new(self, sep)
+    fn many_till_bounded<P, O, V>(self, term: P, max: usize) -> ManyTillBounded<C, Self, P, O, V> {
+        ManyTillBounded::new(self, term, max)
     }
 
     ///
@@ -564,6 +1402,32 @@ where
         Or::new(self, pat)
     }
 
+    ///
+    /// First try to match `self`, if it fails, then try the `others` in order.
+    /// Return [`Error::NoAlternative`] if none of them matches.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use neure::prelude::*;
+    /// #
+    /// # fn main() -> color_eyre::Result<()> {
+    /// #     color_eyre::install()?;
+    ///     let bool = "true".or_any(vec!["false", "yes", "no"]);
+    ///
+    ///     assert_eq!(CharsCtx::new("no").ctor(&bool)?, "no");
+    ///     assert!(CharsCtx::new("on").ctor(&bool).is_err());
+    ///     Ok(())
+    /// # }
+    /// ```
+    fn or_any(self, others: Vec<Self>) -> OrAny<C, Self> {
+        let mut pats = Vec::with_capacity(others.len() + 1);
+
+        pats.push(self);
+        pats.extend(others);
+        OrAny::new(pats)
+    }
+
     ///
     /// Match `L` and `R`, return the longest match result.
     ///
@@ -617,6 +1481,79 @@ where
         Then::new(self, then)
     }
 
+    ///
+    /// Match `P` and `T` alternately until one of them failed,
+    /// return the results of `P` and `T` in two [`Vec`].
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use neure::prelude::*;
+    /// #
+    /// # fn main() -> color_eyre::Result<()> {
+    /// #     color_eyre::install()?;
+    ///     let letter = neu::ascii_alphabetic().repeat_one().map(map::char_code());
+    ///     let letter = letter.map(|v: u32| Ok(char::from_u32(v).unwrap()));
+    ///     let digit = neu::digit(10).repeat_one().map(map::from_str::<i32>());
+    ///     let list = letter.interleave(digit);
+    ///
+    ///     assert_eq!(
+    ///         CharsCtx::new("a1b2c3").ctor(&list)?,
+    ///         (vec!['a', 'b', 'c'], vec![1, 2, 3])
+    ///     );
+    ///     assert_eq!(CharsCtx::new("a1b2c").try_mat(&list)?, Span::new(0, 5));
+    ///     Ok(())
+    /// # }
+    /// ```
+    fn interleave<P>(self, other: P) -> Interleave<C, Self, P> {
+        Interleave::new(self, other)
+    }
+
+    /// Repeat `P` greedily, then give back the results one by one until the terminator matched.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use neure::prelude::*;
+    /// #
+    /// # fn main() -> color_eyre::Result<()> {
+    /// #     color_eyre::install()?;
+    ///     let a = neu::ascii_alphabetic().repeat_one();
+    ///     let greedy = a.greedy_until("ab");
+    ///     let mut ctx = CharsCtx::new("aaab");
+    ///
+    ///     assert_eq!(ctx.ctor(&greedy)?, (vec!["a", "a"], ()));
+    ///     assert_eq!(ctx.offset(), 4);
+    ///     assert!(CharsCtx::new("aaa").ctor(&greedy).is_err());
+    ///     Ok(())
+    /// # }
+    /// ```
+    fn greedy_until<P>(self, term: P) -> GreedyUntil<C, Self, P> {
+        GreedyUntil::new(self, term)
+    }
+
+    ///
+    /// First try to match `P`. If it succeeds, then check that `T` does not match at the new position.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use neure::prelude::*;
+    /// #
+    /// # fn main() -> color_eyre::Result<()> {
+    /// #     color_eyre::install()?;
+    ///     let keyword = "if".not_followed_by(neu::word().repeat_one());
+    ///     let cond = keyword.ws().then(neu::word().repeat_one_more())._1();
+    ///
+    ///     assert_eq!(CharsCtx::new("if ready").ctor(&cond)?, "ready");
+    ///     assert!(CharsCtx::new("iffy").ctor(&cond).is_err());
+    ///     Ok(())
+    /// # }
+    /// ```
+    fn not_followed_by<P>(self, la: P) -> NotFollowedBy<C, Self, P> {
+        NotFollowedBy::new(self, la)
+    }
+
     ///
     /// First try to match `P`. If it succeeds, then try to match `I`.
     /// If it succeeds, then try to match `T`.
@@ -681,6 +1618,123 @@ where
         Repeat::new(self, range)
     }
 
+    ///
+    /// Same as [`repeat`](ConstructOp::repeat), but return [`Error::NoProgress`]
+    /// if an iteration of `P` matched nothing.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use neure::prelude::*;
+    /// # use neure::err::Error;
+    /// #
+    /// # fn main() -> color_eyre::Result<()> {
+    /// #     color_eyre::install()?;
+    ///     let digits = neu::digit(10).repeat_zero_more();
+    ///     let nums = digits.ws().repeat_with_progress_guard(1..);
+    ///     let mut ctx = CharsCtx::new(r#"1 23 456 abc"#);
+    ///
+    ///     assert!(matches!(ctx.ctor(&nums), Err(Error::NoProgress { at: 9 })));
+    ///     Ok(())
+    /// # }
+    /// ```
+    fn repeat_with_progress_guard(self, range: impl Into<CRange<usize>>) -> Repeat<C, Self> {
+        Repeat::new(self, range).with_guard(true)
+    }
+
+    ///
+    /// Repeatedly match `P` which returns [`char`], push the chars into a [`String`].
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use neure::prelude::*;
+    /// #
+    /// # fn main() -> color_eyre::Result<()> {
+    /// #     color_eyre::install()?;
+    ///     let hex = neu::ascii_hexdigit().repeat_times::<2>();
+    ///     let hex = hex.map(map::from_str_radix::<u8>(16)).map(|v: u8| Ok(v as char));
+    ///     let str = hex.repeat_string(1..);
+    ///     let mut ctx = CharsCtx::new("6e657572650a");
+    ///
+    ///     assert_eq!(ctx.ctor(&str)?, "neure\n");
+    ///     Ok(())
+    /// # }
+    /// ```
+    fn repeat_string(self, range: impl Into<CRange<usize>>) -> RepeatString<C, Self> {
+        RepeatString::new(self, range)
+    }
+
+    ///
+    /// Repeatedly match `P` as few times as possible until the terminator `T` matched.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use neure::prelude::*;
+    /// #
+    /// # fn main() -> color_eyre::Result<()> {
+    /// #     color_eyre::install()?;
+    ///     let any = neu::any().repeat_one();
+    ///     let tag = "<".then(any.repeat_lazy(1.., ">"));
+    ///     let mut ctx = CharsCtx::new("<a><b>");
+    ///
+    ///     assert_eq!(ctx.try_mat(&tag)?, Span::new(0, 3));
+    ///     assert_eq!(ctx.try_mat(&tag)?, Span::new(3, 3));
+    ///     assert!(CharsCtx::new("<>").try_mat(&tag).is_err());
+    ///     Ok(())
+    /// # }
+    /// ```
+    fn repeat_lazy<P>(self, range: impl Into<CRange<usize>>, term: P) -> RepeatLazy<C, Self, P> {
+        RepeatLazy::new(self, range, term)
+    }
+
+    ///
+    /// Match regex `P` zero or more times, discard the results.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use neure::prelude::*;
+    /// #
+    /// # fn main() -> color_eyre::Result<()> {
+    /// #     color_eyre::install()?;
+    ///     let sp = neu::whitespace().repeat_one().skip_many();
+    ///     let word = neu::ascii_alphabetic().repeat_one_more();
+    ///     let word = sp.then(word).map(map::select1());
+    ///
+    ///     assert_eq!(CharsCtx::new("    hello").ctor(&word)?, "hello");
+    ///     assert_eq!(CharsCtx::new("hello").ctor(&word)?, "hello");
+    ///     Ok(())
+    /// # }
+    /// ```
+    fn skip_many(self) -> SkipMany<C, Self> {
+        SkipMany::new(self)
+    }
+
+    ///
+    /// Match regex `P` one or more times, discard the results.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use neure::prelude::*;
+    /// #
+    /// # fn main() -> color_eyre::Result<()> {
+    /// #     color_eyre::install()?;
+    ///     let sp = neu::whitespace().repeat_one().skip_many1();
+    ///     let word = neu::ascii_alphabetic().repeat_one_more();
+    ///     let words = word.sep(sp);
+    ///
+    ///     assert_eq!(CharsCtx::new("a  b\tc").ctor(&words)?, ["a", "b", "c"]);
+    ///     assert!(CharsCtx::new("").ctor(&sp).is_err());
+    ///     Ok(())
+    /// # }
+    /// ```
+    fn skip_many1(self) -> SkipMany<C, Self> {
+        SkipMany::new(self).at_least(1)
+    }
+
     ///
     /// Repeatedly match the regex `P` at least [`min`](crate::re::ctor::Collect#tymethod.min) times.
     ///
@@ -707,6 +1761,83 @@ where
         Collect::new(self)
     }
 
+    ///
+    /// Repeatedly match the regex `P`, return the count of matched iterations
+    /// and the collection of the results.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use neure::prelude::*;
+    /// # use std::collections::BTreeSet;
+    /// #
+    /// # fn main() -> color_eyre::Result<()> {
+    /// #     color_eyre::install()?;
+    ///     let num = neu::digit(10).repeat_one_more().map(map::from_str::<u8>());
+    ///     let nums = num.pad(" ").collect_counted::<_, BTreeSet<_>>();
+    ///     let (cnt, set) = CharsCtx::new("3 1 3 2 ").ctor(&nums)?;
+    ///
+    ///     assert_eq!(cnt, 4);
+    ///     assert_eq!(set.into_iter().collect::<Vec<_>>(), [1, 2, 3]);
+    ///     Ok(())
+    /// # }
+    /// ```
+    fn collect_counted<O, V>(self) -> CollectCounted<C, Self, O, V> {
+        CollectCounted::new(self)
+    }
+
+    ///
+    /// Repeatedly match the regex `P`, and collect the results into a
+    /// [`HashMap`](std::collections::HashMap) keyed by `F`.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use neure::prelude::*;
+    /// #
+    /// # fn main() -> color_eyre::Result<()> {
+    /// #     color_eyre::install()?;
+    ///     let name = neu::ascii_alphabetic().repeat_one_more();
+    ///     let id = neu::digit(10).repeat_one_more().map(map::from_str::<u32>());
+    ///     let user = id.sep_once("=", name).pad(";");
+    ///     let users = user.index_by(|u: &(u32, &str)| u.0).strict();
+    ///     let index = CharsCtx::new("1=foo;2=bar;").ctor(&users)?;
+    ///
+    ///     assert_eq!(index[&1], (1, "foo"));
+    ///     assert_eq!(index[&2], (2, "bar"));
+    ///     assert!(CharsCtx::new("1=foo;1=bar;").ctor(&users).is_err());
+    ///     Ok(())
+    /// # }
+    /// ```
+    fn index_by<F>(self, key: F) -> IndexBy<C, Self, F> {
+        IndexBy::new(self, key)
+    }
+
+    ///
+    /// Repeatedly match the regex `P` and map the result using `F`,
+    /// stop the collection when `P` failed or `F` return `None`.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use neure::prelude::*;
+    /// #
+    /// # fn main() -> color_eyre::Result<()> {
+    /// #     color_eyre::install()?;
+    ///     let num = neu::digit(10).repeat_one_more().map(map::from_str::<i32>());
+    ///     let num = num.pad(",");
+    ///     let nums = num.map_while::<_, _, Vec<_>>(|v| (v != 0).then_some(v));
+    ///     let mut ctx = CharsCtx::new("1,2,0,3");
+    ///
+    ///     assert_eq!(ctx.ctor(&nums)?, [1, 2]);
+    ///     assert_eq!(ctx.offset(), 6);
+    ///     Ok(())
+    /// # }
+    /// ```
+    fn map_while<F, O, V>(self, func: F) -> MapWhile<C, Self, F, O, V> {
+        MapWhile::new(self, func)
+    }
+
     ///
     /// Construct a branch struct base on the test `I`(Fn(&C) -> Result<bool, Error>).
     ///
@@ -719,7 +1850,7 @@ where
     /// #     color_eyre::install()?;
     ///     let val = "file://".r#if(
     ///         // test if it is a file url
-    ///         |ctx: &CharsCtx| Ok(ctx.orig()?.starts_with("file")),
+    ///         |ctx: &CharsCtx| Ok(ctx.current() == Some('f')),
     ///         "http://",
     ///     );
     ///
@@ -0,0 +1,150 @@
+use std::fmt::Debug;
+use std::marker::PhantomData;
+
+use crate::ctx::Context;
+use crate::ctx::CtxGuard;
+use crate::ctx::Match;
+use crate::ctx::Span;
+use crate::err::Error;
+use crate::re::def_not;
+use crate::re::trace;
+use crate::re::Ctor;
+use crate::re::Extract;
+use crate::re::FollowedBy;
+use crate::re::Handler;
+use crate::re::Regex;
+
+///
+/// First try to match `P`. If it succeeds, then check that `T` does not match
+/// at the new position, the offset will not be changed by `T`.
+///
+/// # Ctor
+///
+/// It will return the result of `P`, or fail if `T` matches.
+///
+/// # Example
+///
+/// ```
+/// # use neure::prelude::*;
+/// #
+/// # fn main() -> color_eyre::Result<()> {
+/// #     color_eyre::install()?;
+///     let keyword = "if".not_followed_by(neu::word().repeat_one());
+///
+///     assert_eq!(CharsCtx::new("if ").try_mat(&keyword)?, Span::new(0, 2));
+///     assert_eq!(CharsCtx::new("if").try_mat(&keyword)?, Span::new(0, 2));
+///     assert!(CharsCtx::new("iffy").try_mat(&keyword).is_err());
+///
+///     Ok(())
+/// # }
+/// ```
+#[derive(Default, Copy)]
+pub struct NotFollowedBy<C, P, T> {
+    pat: P,
+    la: FollowedBy<T>,
+    marker: PhantomData<C>,
+}
+
+def_not!(NotFollowedBy<C, P, T>);
+
+impl<C, P, T> Debug for NotFollowedBy<C, P, T>
+where
+    P: Debug,
+    T: Debug,
+{
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("NotFollowedBy")
+            .field("pat", &self.pat)
+            .field("la", self.la.val())
+            .finish()
+    }
+}
+
+impl<C, P, T> Clone for NotFollowedBy<C, P, T>
+where
+    P: Clone,
+    T: Clone,
+{
+    fn clone(&self) -> Self {
+        Self {
+            pat: self.pat.clone(),
+            la: self.la.clone(),
+            marker: self.marker,
+        }
+    }
+}
+
+impl<C, P, T> NotFollowedBy<C, P, T> {
+    pub fn new(pat: P, la: T) -> Self {
+        Self {
+            pat,
+            la: FollowedBy::new(la).with_negated(true),
+            marker: PhantomData,
+        }
+    }
+
+    pub fn pat(&self) -> &P {
+        &self.pat
+    }
+
+    pub fn pat_mut(&mut self) -> &mut P {
+        &mut self.pat
+    }
+
+    pub fn la(&self) -> &T {
+        self.la.val()
+    }
+
+    pub fn la_mut(&mut self) -> &mut T {
+        self.la.val_mut()
+    }
+
+    pub fn set_pat(&mut self, pat: P) -> &mut Self {
+        self.pat = pat;
+        self
+    }
+
+    pub fn set_la(&mut self, la: T) -> &mut Self {
+        self.la.set_val(la);
+        self
+    }
+}
+
+impl<'a, C, P, T, M, O, H, A> Ctor<'a, C, M, O, H, A> for NotFollowedBy<C, P, T>
+where
+    P: Ctor<'a, C, M, O, H, A>,
+    T: Regex<C>,
+    C: Context<'a> + Match<C>,
+    H: Handler<A, Out = M, Error = Error>,
+    A: Extract<'a, C, Span, Out<'a> = A, Error = Error>,
+{
+    #[inline(always)]
+    fn construct(&self, ctx: &mut C, func: &mut H) -> Result<O, Error> {
+        let mut g = CtxGuard::new(ctx);
+        let beg = g.beg();
+        let ret = trace!("not_followed_by", beg @ "pat", self.pat.construct(g.ctx(), func));
+        let ret = ret.and_then(|val| g.ctx().try_mat(&self.la).map(|_| val));
+
+        trace!("not_followed_by", beg -> g.end(), ret.is_ok());
+        g.process_ret(ret)
+    }
+}
+
+impl<'a, C, P, T> Regex<C> for NotFollowedBy<C, P, T>
+where
+    P: Regex<C, Ret = Span>,
+    T: Regex<C>,
+    C: Context<'a> + Match<C>,
+{
+    type Ret = P::Ret;
+
+    #[inline(always)]
+    fn try_parse(&self, ctx: &mut C) -> Result<Self::Ret, Error> {
+        let mut g = CtxGuard::new(ctx);
+        let beg = g.beg();
+        let ret = trace!("not_followed_by", beg @ "pat", g.try_mat(&self.pat));
+        let ret = ret.and_then(|span| g.ctx().try_mat(&self.la).map(|_| span));
+
+        trace!("not_followed_by", beg => g.end(), g.process_ret(ret))
+    }
+}
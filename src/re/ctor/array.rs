@@ -83,6 +83,8 @@ where
             if ret.is_ok() {
                 trace!("array", beg -> g.end(), true);
                 return ret;
+            } else if ret.as_ref().is_err_and(Error::is_fatal) {
+                return g.process_ret(ret);
             } else {
                 g.reset();
             }
@@ -109,6 +111,8 @@ where
             if ret.is_ok() {
                 trace!("array", beg => g.end(), true);
                 return ret;
+            } else if ret.as_ref().is_err_and(Error::is_fatal) {
+                return g.process_ret(ret);
             } else {
                 g.reset();
             }
@@ -194,6 +198,8 @@ where
             if ret.is_ok() {
                 trace!("pair_array", beg -> g.end(), true);
                 return Ok((ret?, value.clone()));
+            } else if ret.as_ref().is_err_and(Error::is_fatal) {
+                return g.process_ret(ret).map(|ret| (ret, value.clone()));
             } else {
                 g.reset();
             }
@@ -220,6 +226,8 @@ where
             if ret.is_ok() {
                 trace!("pair_array", beg => g.end(), true);
                 return ret;
+            } else if ret.as_ref().is_err_and(Error::is_fatal) {
+                return g.process_ret(ret);
             } else {
                 g.reset();
             }
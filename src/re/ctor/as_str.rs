@@ -0,0 +1,247 @@
+use std::fmt::Debug;
+use std::marker::PhantomData;
+
+use crate::ctx::Context;
+use crate::ctx::CtxGuard;
+use crate::ctx::Match;
+use crate::ctx::Span;
+use crate::err::Error;
+use crate::re::def_not;
+use crate::re::trace;
+use crate::re::Ctor;
+use crate::re::Extract;
+use crate::re::Handler;
+use crate::re::Regex;
+
+///
+/// Construct the value of `P`, discard it and return the data covered by `P`.
+///
+/// Unlike [`Pattern`](crate::re::ctor::Pattern), `P` is constructed, so the
+/// failure of mapping in `P` will fail the match.
+///
+/// # Ctor
+///
+/// Return the [`Orig`](crate::ctx::Context::Orig) matched by `P`,
+/// such as `&str` for [`CharsCtx`](crate::ctx::CharsCtx) or `&[u8]` for [`BytesCtx`](crate::ctx::BytesCtx).
+///
+/// # Example
+///
+/// ```
+/// # use neure::prelude::*;
+/// #
+/// # fn main() -> color_eyre::Result<()> {
+/// #     color_eyre::install()?;
+///     let byte = neu::digit(10).repeat_one_more().map(map::from_str::<u8>());
+///     let bytes = byte.sep(".").matched_str();
+///     let mut ctx = CharsCtx::new("127.0.0.1");
+///
+///     assert_eq!(ctx.ctor(&bytes)?, "127.0.0.1");
+///     assert!(CharsCtx::new("256").ctor(&bytes).is_err());
+///     Ok(())
+/// # }
+/// ```
+#[derive(Default, Copy)]
+pub struct MatchedStr<C, P, O> {
+    pat: P,
+    marker: PhantomData<(C, O)>,
+}
+
+def_not!(MatchedStr<C, P, O>);
+
+impl<C, P, O> Debug for MatchedStr<C, P, O>
+where
+    P: Debug,
+{
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("MatchedStr")
+            .field("pat", &self.pat)
+            .finish()
+    }
+}
+
+impl<C, P, O> Clone for MatchedStr<C, P, O>
+where
+    P: Clone,
+{
+    fn clone(&self) -> Self {
+        Self {
+            pat: self.pat.clone(),
+            marker: self.marker,
+        }
+    }
+}
+
+impl<C, P, O> MatchedStr<C, P, O> {
+    pub fn new(pat: P) -> Self {
+        Self {
+            pat,
+            marker: PhantomData,
+        }
+    }
+
+    pub fn pat(&self) -> &P {
+        &self.pat
+    }
+
+    pub fn pat_mut(&mut self) -> &mut P {
+        &mut self.pat
+    }
+
+    pub fn set_pat(&mut self, pat: P) -> &mut Self {
+        self.pat = pat;
+        self
+    }
+}
+
+impl<'a, C, P, M, O, H, A> Ctor<'a, C, M, &'a C::Orig, H, A> for MatchedStr<C, P, O>
+where
+    P: Ctor<'a, C, M, O, H, A>,
+    C: Context<'a> + Match<C> + 'a,
+    H: Handler<A, Out = M, Error = Error>,
+    A: Extract<'a, C, Span, Out<'a> = A, Error = Error>,
+{
+    #[inline(always)]
+    fn construct(&self, ctx: &mut C, func: &mut H) -> Result<&'a C::Orig, Error> {
+        let mut g = CtxGuard::new(ctx);
+        let beg = g.beg();
+        let ret = trace!("as_str", beg @ "pat", self.pat.construct(g.ctx(), func));
+        let ret = ret.and_then(|_| {
+            let len = g.end() - beg;
+
+            g.ctx().orig_sub(beg, len)
+        });
+
+        trace!("as_str", beg -> g.end(), ret.is_ok());
+        g.process_ret(ret)
+    }
+}
+
+impl<'a, C, P, O> Regex<C> for MatchedStr<C, P, O>
+where
+    P: Regex<C, Ret = Span>,
+    C: Context<'a> + Match<C>,
+{
+    type Ret = P::Ret;
+
+    #[inline(always)]
+    fn try_parse(&self, ctx: &mut C) -> Result<Self::Ret, Error> {
+        let mut g = CtxGuard::new(ctx);
+        let beg = g.beg();
+        let ret = trace!("as_str", beg @ "pat", g.try_mat(&self.pat));
+
+        trace!("as_str", beg => g.end(), g.process_ret(ret))
+    }
+}
+
+///
+/// Construct the value of `P`, return it with the data covered by `P`.
+///
+/// # Ctor
+///
+/// Return a tuple of the value of `P` and the [`Orig`](crate::ctx::Context::Orig) matched by `P`.
+///
+/// # Example
+///
+/// ```
+/// # use neure::prelude::*;
+/// #
+/// # fn main() -> color_eyre::Result<()> {
+/// #     color_eyre::install()?;
+///     let byte = neu::digit(10).repeat_one_more().map(map::from_str::<u8>());
+///     let bytes = byte.sep(".").with_slice();
+///     let mut ctx = CharsCtx::new("127.0.0.1");
+///
+///     assert_eq!(ctx.ctor(&bytes)?, (vec![127, 0, 0, 1], "127.0.0.1"));
+///     Ok(())
+/// # }
+/// ```
+#[derive(Default, Copy)]
+pub struct WithSlice<C, P, O> {
+    pat: P,
+    marker: PhantomData<(C, O)>,
+}
+
+def_not!(WithSlice<C, P, O>);
+
+impl<C, P, O> Debug for WithSlice<C, P, O>
+where
+    P: Debug,
+{
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("WithSlice").field("pat", &self.pat).finish()
+    }
+}
+
+impl<C, P, O> Clone for WithSlice<C, P, O>
+where
+    P: Clone,
+{
+    fn clone(&self) -> Self {
+        Self {
+            pat: self.pat.clone(),
+            marker: self.marker,
+        }
+    }
+}
+
+impl<C, P, O> WithSlice<C, P, O> {
+    pub fn new(pat: P) -> Self {
+        Self {
+            pat,
+            marker: PhantomData,
+        }
+    }
+
+    pub fn pat(&self) -> &P {
+        &self.pat
+    }
+
+    pub fn pat_mut(&mut self) -> &mut P {
+        &mut self.pat
+    }
+
+    pub fn set_pat(&mut self, pat: P) -> &mut Self {
+        self.pat = pat;
+        self
+    }
+}
+
+impl<'a, C, P, M, O, H, A> Ctor<'a, C, M, (O, &'a C::Orig), H, A> for WithSlice<C, P, O>
+where
+    P: Ctor<'a, C, M, O, H, A>,
+    C: Context<'a> + Match<C> + 'a,
+    H: Handler<A, Out = M, Error = Error>,
+    A: Extract<'a, C, Span, Out<'a> = A, Error = Error>,
+{
+    #[inline(always)]
+    fn construct(&self, ctx: &mut C, func: &mut H) -> Result<(O, &'a C::Orig), Error> {
+        let mut g = CtxGuard::new(ctx);
+        let beg = g.beg();
+        let ret = trace!("with_slice", beg @ "pat", self.pat.construct(g.ctx(), func));
+        let ret = ret.and_then(|val| {
+            let len = g.end() - beg;
+
+            Ok((val, g.ctx().orig_sub(beg, len)?))
+        });
+
+        trace!("with_slice", beg -> g.end(), ret.is_ok());
+        g.process_ret(ret)
+    }
+}
+
+impl<'a, C, P, O> Regex<C> for WithSlice<C, P, O>
+where
+    P: Regex<C, Ret = Span>,
+    C: Context<'a> + Match<C>,
+{
+    type Ret = P::Ret;
+
+    #[inline(always)]
+    fn try_parse(&self, ctx: &mut C) -> Result<Self::Ret, Error> {
+        let mut g = CtxGuard::new(ctx);
+        let beg = g.beg();
+        let ret = trace!("with_slice", beg @ "pat", g.try_mat(&self.pat));
+
+        trace!("with_slice", beg => g.end(), g.process_ret(ret))
+    }
+}
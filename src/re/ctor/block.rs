@@ -0,0 +1,257 @@
+use std::fmt::Debug;
+use std::marker::PhantomData;
+
+use crate::ctx::Context;
+use crate::ctx::CtxGuard;
+use crate::ctx::Match;
+use crate::ctx::Ret;
+use crate::ctx::Span;
+use crate::err::Error;
+use crate::re::def_not;
+use crate::re::trace;
+use crate::re::Ctor;
+use crate::re::Extract;
+use crate::re::Handler;
+use crate::re::Regex;
+
+///
+/// Match the `begin`, then match the items `P` separated by `S`, finally match the `end`.
+/// The trailing separator is allowed.
+///
+/// # Ctor
+///
+/// Return a [`Vec`] contains the result of items, the list may be empty.
+/// Return [`Error::BlockEnd`] with the offset where the `end` is expected if `end` not matched.
+///
+/// # Example
+///
+/// ```
+/// # use neure::{err::Error, prelude::*};
+/// #
+/// # fn main() -> color_eyre::Result<()> {
+/// #     color_eyre::install()?;
+///     let item = neu::ascii_alphabetic().repeat_one_more();
+///     let list = re::block("(", item, ",".ws(), ")");
+///
+///     assert_eq!(CharsCtx::new("(a, b, c)").ctor(&list)?, ["a", "b", "c"]);
+///     assert_eq!(CharsCtx::new("(a, )").ctor(&list)?, ["a"]);
+///     assert_eq!(CharsCtx::new("()").ctor(&list)?, Vec::<&str>::new());
+///     assert!(matches!(
+///         CharsCtx::new("(a, b c)").ctor(&list),
+///         Err(Error::BlockEnd { at: 5 })
+///     ));
+///     Ok(())
+/// # }
+/// ```
+#[derive(Default, Copy)]
+pub struct Block<C, B, P, S, E> {
+    begin: B,
+    pat: P,
+    sep: S,
+    end: E,
+    marker: PhantomData<C>,
+}
+
+def_not!(Block<C, B, P, S, E>);
+
+impl<C, B, P, S, E> Debug for Block<C, B, P, S, E>
+where
+    B: Debug,
+    P: Debug,
+    S: Debug,
+    E: Debug,
+{
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("Block")
+            .field("begin", &self.begin)
+            .field("pat", &self.pat)
+            .field("sep", &self.sep)
+            .field("end", &self.end)
+            .finish()
+    }
+}
+
+impl<C, B, P, S, E> Clone for Block<C, B, P, S, E>
+where
+    B: Clone,
+    P: Clone,
+    S: Clone,
+    E: Clone,
+{
+    fn clone(&self) -> Self {
+        Self {
+            begin: self.begin.clone(),
+            pat: self.pat.clone(),
+            sep: self.sep.clone(),
+            end: self.end.clone(),
+            marker: self.marker,
+        }
+    }
+}
+
+impl<C, B, P, S, E> Block<C, B, P, S, E> {
+    pub fn new(begin: B, pat: P, sep: S, end: E) -> Self {
+        Self {
+            begin,
+            pat,
+            sep,
+            end,
+            marker: PhantomData,
+        }
+    }
+
+    pub fn begin(&self) -> &B {
+        &self.begin
+    }
+
+    pub fn pat(&self) -> &P {
+        &self.pat
+    }
+
+    pub fn sep(&self) -> &S {
+        &self.sep
+    }
+
+    pub fn end(&self) -> &E {
+        &self.end
+    }
+
+    pub fn begin_mut(&mut self) -> &mut B {
+        &mut self.begin
+    }
+
+    pub fn pat_mut(&mut self) -> &mut P {
+        &mut self.pat
+    }
+
+    pub fn sep_mut(&mut self) -> &mut S {
+        &mut self.sep
+    }
+
+    pub fn end_mut(&mut self) -> &mut E {
+        &mut self.end
+    }
+
+    pub fn set_begin(&mut self, begin: B) -> &mut Self {
+        self.begin = begin;
+        self
+    }
+
+    pub fn set_pat(&mut self, pat: P) -> &mut Self {
+        self.pat = pat;
+        self
+    }
+
+    pub fn set_sep(&mut self, sep: S) -> &mut Self {
+        self.sep = sep;
+        self
+    }
+
+    pub fn set_end(&mut self, end: E) -> &mut Self {
+        self.end = end;
+        self
+    }
+}
+
+impl<'a, C, B, P, S, E, M, O, H, A> Ctor<'a, C, M, Vec<O>, H, A> for Block<C, B, P, S, E>
+where
+    B: Regex<C, Ret = Span>,
+    S: Regex<C, Ret = Span>,
+    E: Regex<C, Ret = Span>,
+    P: Ctor<'a, C, M, O, H, A>,
+    C: Context<'a> + Match<C>,
+    H: Handler<A, Out = M, Error = Error>,
+    A: Extract<'a, C, Span, Out<'a> = A, Error = Error>,
+{
+    #[inline(always)]
+    fn construct(&self, ctx: &mut C, func: &mut H) -> Result<Vec<O>, Error> {
+        let mut g = CtxGuard::new(ctx);
+        let mut vals = vec![];
+        let beg = g.beg();
+        let ret = trace!("block", beg @ "begin", g.try_mat(&self.begin));
+
+        if ret.is_ok() {
+            loop {
+                let offset = g.end();
+
+                match g
+                    .non_fatal(|ctx| trace!("block", beg @ "pat", self.pat.construct(ctx, func)))?
+                {
+                    Some(val) => vals.push(val),
+                    None => {
+                        g.ctx().set_offset(offset);
+                        break;
+                    }
+                }
+                let offset = g.end();
+
+                match trace!("block", beg @ "sep", g.ctx().try_mat(&self.sep)) {
+                    Ok(_) if !g.is_stuck(offset) => {}
+                    _ => {
+                        g.ctx().set_offset(offset);
+                        break;
+                    }
+                };
+            }
+        }
+        let ret = ret.and_then(|_| {
+            let offset = g.end();
+
+            trace!("block", beg @ "end", g.try_mat(&self.end))
+                .map_err(|_| Error::BlockEnd { at: offset })
+        });
+
+        trace!("block", beg -> g.end(), ret.is_ok());
+        g.process_ret(ret).map(|_| vals)
+    }
+}
+
+impl<'a, C, B, P, S, E> Regex<C> for Block<C, B, P, S, E>
+where
+    B: Regex<C, Ret = Span>,
+    S: Regex<C, Ret = Span>,
+    E: Regex<C, Ret = Span>,
+    P: Regex<C, Ret = Span>,
+    C: Context<'a> + Match<C>,
+{
+    type Ret = P::Ret;
+
+    #[inline(always)]
+    fn try_parse(&self, ctx: &mut C) -> Result<Self::Ret, Error> {
+        let mut g = CtxGuard::new(ctx);
+        let beg = g.beg();
+        let mut ret = trace!("block", beg @ "begin", g.try_mat(&self.begin));
+
+        if let Ok(span) = &mut ret {
+            loop {
+                let offset = g.end();
+
+                match g.non_fatal(|ctx| trace!("block", beg @ "pat", ctx.try_mat(&self.pat)))? {
+                    Some(ret) => span.add_assign(ret),
+                    None => {
+                        g.ctx().set_offset(offset);
+                        break;
+                    }
+                };
+                let offset = g.end();
+
+                match trace!("block", beg @ "sep", g.ctx().try_mat(&self.sep)) {
+                    Ok(ret) if !g.is_stuck(offset) => span.add_assign(ret),
+                    _ => {
+                        g.ctx().set_offset(offset);
+                        break;
+                    }
+                };
+            }
+        }
+        let ret = ret.and_then(|mut span| {
+            let offset = g.end();
+
+            trace!("block", beg @ "end", g.try_mat(&self.end))
+                .map(|ret| *span.add_assign(ret))
+                .map_err(|_| Error::BlockEnd { at: offset })
+        });
+
+        trace!("block", beg => g.end(), g.process_ret(ret))
+    }
+}
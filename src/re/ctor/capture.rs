@@ -0,0 +1,152 @@
+use std::fmt::Debug;
+use std::marker::PhantomData;
+
+use crate::ctx::CaptureSink;
+use crate::ctx::Context;
+use crate::ctx::CtxGuard;
+use crate::ctx::Match;
+use crate::ctx::Span;
+use crate::err::Error;
+use crate::re::def_not;
+use crate::re::trace;
+use crate::re::Ctor;
+use crate::re::Extract;
+use crate::re::Handler;
+use crate::re::Regex;
+
+///
+/// Match `P`, if it succeeds, store the span of match into the [`CaptureSink`] of [`Context`]
+/// with the given `id`.
+///
+/// The span will not be removed from the storer if the outer regex fails later.
+///
+/// # Ctor
+///
+/// It will return the result of `P`.
+///
+/// # Example
+///
+/// ```
+/// # use neure::prelude::*;
+/// #
+/// # fn main() -> color_eyre::Result<()> {
+/// #     color_eyre::install()?;
+///     let key = neu::word().repeat_one_more().capture(0);
+///     let val = neu::digit(10).repeat_one_more().capture(1);
+///     let pair = key.sep_once("=", val);
+///     let mut ctx = CharsCtx::new("a=1").with_sink();
+///
+///     assert_eq!(ctx.ctor(&pair)?, ("a", "1"));
+///     assert_eq!(ctx.captures().span(0, 0), Some(&Span::new(0, 1)));
+///     assert_eq!(ctx.captures().span(1, 0), Some(&Span::new(2, 1)));
+///     Ok(())
+/// # }
+/// ```
+#[derive(Default, Copy)]
+pub struct Capture<C, P> {
+    pat: P,
+    id: usize,
+    marker: PhantomData<C>,
+}
+
+def_not!(Capture<C, P>);
+
+impl<C, P> Debug for Capture<C, P>
+where
+    P: Debug,
+{
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("Capture")
+            .field("pat", &self.pat)
+            .field("id", &self.id)
+            .finish()
+    }
+}
+
+impl<C, P> Clone for Capture<C, P>
+where
+    P: Clone,
+{
+    fn clone(&self) -> Self {
+        Self {
+            pat: self.pat.clone(),
+            id: self.id,
+            marker: self.marker,
+        }
+    }
+}
+
+impl<C, P> Capture<C, P> {
+    pub fn new(pat: P, id: usize) -> Self {
+        Self {
+            pat,
+            id,
+            marker: PhantomData,
+        }
+    }
+
+    pub fn pat(&self) -> &P {
+        &self.pat
+    }
+
+    pub fn pat_mut(&mut self) -> &mut P {
+        &mut self.pat
+    }
+
+    pub fn id(&self) -> usize {
+        self.id
+    }
+
+    pub fn set_pat(&mut self, pat: P) -> &mut Self {
+        self.pat = pat;
+        self
+    }
+
+    pub fn set_id(&mut self, id: usize) -> &mut Self {
+        self.id = id;
+        self
+    }
+}
+
+impl<'a, C, P, M, O, H, A> Ctor<'a, C, M, O, H, A> for Capture<C, P>
+where
+    P: Ctor<'a, C, M, O, H, A>,
+    C: Context<'a> + Match<C> + CaptureSink,
+    H: Handler<A, Out = M, Error = Error>,
+    A: Extract<'a, C, Span, Out<'a> = A, Error = Error>,
+{
+    #[inline(always)]
+    fn construct(&self, ctx: &mut C, func: &mut H) -> Result<O, Error> {
+        let mut g = CtxGuard::new(ctx);
+        let beg = g.beg();
+        let mut ret = trace!("capture", beg @ "pat", self.pat.construct(g.ctx(), func));
+
+        if ret.is_ok() {
+            let span = Span::new(beg, g.end() - beg);
+
+            if let Err(e) = g.ctx().capture(self.id, span) {
+                ret = Err(e);
+            }
+        }
+        trace!("capture", beg -> g.end(), ret.is_ok());
+        g.process_ret(ret)
+    }
+}
+
+impl<'a, C, P> Regex<C> for Capture<C, P>
+where
+    P: Regex<C, Ret = Span>,
+    C: Context<'a> + Match<C> + CaptureSink,
+{
+    type Ret = P::Ret;
+
+    #[inline(always)]
+    fn try_parse(&self, ctx: &mut C) -> Result<Self::Ret, Error> {
+        let mut g = CtxGuard::new(ctx);
+        let beg = g.beg();
+        let ret = trace!("capture", beg @ "pat", g.try_mat(&self.pat));
+        let ret = ret.and_then(|span| g.ctx().capture(self.id, span).map(|_| span));
+
+        trace!("capture", beg => g.end(), g.process_ret(ret))
+    }
+}
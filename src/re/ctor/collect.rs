@@ -1,4 +1,6 @@
+use std::collections::HashMap;
 use std::fmt::Debug;
+use std::hash::Hash;
 use std::marker::PhantomData;
 
 use crate::ctx::Context;
@@ -22,6 +24,8 @@ use crate::re::Regex;
 /// Return a type `V` that collects the result of regex `P`.
 /// `Collect` will always succeed if the minimum size is 0, be careful to use it with `.sep` faimly APIs.
 /// The default size is 1.
+/// With the progress guard enabled, it will return [`Error::NoProgress`]
+/// if an iteration of `P` matched nothing.
 ///
 /// # Example
 ///
@@ -42,6 +46,7 @@ use crate::re::Regex;
 pub struct Collect<C, P, O, V> {
     pat: P,
     min: usize,
+    guard: bool,
     marker: PhantomData<(O, V, C)>,
 }
 
@@ -55,6 +60,7 @@ where
         f.debug_struct("Collect")
             .field("pat", &self.pat)
             .field("min", &self.min)
+            .field("guard", &self.guard)
             .finish()
     }
 }
@@ -67,6 +73,7 @@ where
         Self {
             pat: self.pat.clone(),
             min: self.min,
+            guard: self.guard,
             marker: self.marker,
         }
     }
@@ -77,6 +84,7 @@ impl<C, P, O, V> Collect<C, P, O, V> {
         Self {
             pat,
             min: 1,
+            guard: false,
             marker: PhantomData,
         }
     }
@@ -93,6 +101,10 @@ impl<C, P, O, V> Collect<C, P, O, V> {
         self.min
     }
 
+    pub fn guard(&self) -> bool {
+        self.guard
+    }
+
     pub fn set_pat(&mut self, pat: P) -> &mut Self {
         self.pat = pat;
         self
@@ -103,10 +115,21 @@ impl<C, P, O, V> Collect<C, P, O, V> {
         self
     }
 
+    pub fn set_guard(&mut self, guard: bool) -> &mut Self {
+        self.guard = guard;
+        self
+    }
+
     pub fn at_least(mut self, min: usize) -> Self {
         self.min = min;
         self
     }
+
+    /// Return [`Error::NoProgress`] instead of looping if an iteration matched nothing.
+    pub fn with_guard(mut self, guard: bool) -> Self {
+        self.guard = guard;
+        self
+    }
 }
 
 impl<'a, C, P, M, O, V, H, A> Ctor<'a, C, M, V, H, A> for Collect<C, P, O, V>
@@ -121,23 +144,35 @@ where
     fn construct(&self, ctx: &mut C, func: &mut H) -> Result<V, Error> {
         let mut g = CtxGuard::new(ctx);
         let mut cnt = 0;
+        let mut fatal = None;
         let mut ret = Err(Error::Collect);
         let beg = g.beg();
         let val = trace!(
             "collect",
             beg,
             V::from_iter(std::iter::from_fn(|| {
+                let offset = g.end();
+
                 match self.pat.construct(g.ctx(), func) {
+                    Ok(_) if self.guard && g.is_stuck(offset) => {
+                        fatal = Some(Error::NoProgress { at: offset });
+                        None
+                    }
                     Ok(ret) => {
                         cnt += 1;
                         Some(ret)
                     }
-                    Err(_) => None,
+                    Err(e) => {
+                        fatal = e.is_fatal().then_some(e);
+                        None
+                    }
                 }
             }))
         );
 
-        if cnt >= self.min {
+        if let Some(e) = fatal {
+            ret = Err(e);
+        } else if cnt >= self.min {
             ret = Ok(val);
         }
         trace!("collect", beg -> g.end(), ret.is_ok());
@@ -162,13 +197,412 @@ where
 
         // don't use g.try_mat
         trace!("collect", beg, ());
-        while let Ok(ret) = g.ctx().try_mat(&self.pat) {
+        let mut offset = g.end();
+        let mut fatal = None;
+
+        while let Some(ret) = g.non_fatal(|ctx| ctx.try_mat(&self.pat))? {
+            if self.guard && g.is_stuck(offset) {
+                fatal = Some(Error::NoProgress { at: offset });
+                break;
+            }
             cnt += 1;
             span.add_assign(ret);
+            offset = g.end();
         }
-        if cnt >= self.min {
+        if let Some(e) = fatal {
+            ret = Err(e);
+        } else if cnt >= self.min {
             ret = Ok(span);
         }
         trace!("collect", beg => g.end(), g.process_ret(ret))
     }
 }
+
+///
+/// Repeatedly match `P` at least [`min`](crate::re::ctor::CollectCounted#method.min) times,
+/// and count the matched iterations.
+///
+/// # Ctor
+///
+/// Return the count of matched iterations and a type `V` that collects the results of `P`.
+/// The count may differ from the length of `V` if `V` drops some items, such as a
+/// [`HashSet`](std::collections::HashSet).
+/// The default minimum size is 1.
+///
+/// # Example
+///
+/// ```
+/// # use neure::prelude::*;
+/// # use std::collections::HashSet;
+/// #
+/// # fn main() -> color_eyre::Result<()> {
+/// #     color_eyre::install()?;
+///     let tag = neu::ascii_alphabetic().repeat_one_more().pad(",");
+///     let tags = tag.collect_counted::<_, HashSet<_>>();
+///     let (cnt, tags) = CharsCtx::new("a,b,a,c,").ctor(&tags)?;
+///
+///     assert_eq!(cnt, 4);
+///     assert_eq!(tags.len(), 3);
+///     Ok(())
+/// # }
+/// ```
+#[derive(Default, Copy)]
+pub struct CollectCounted<C, P, O, V> {
+    pat: P,
+    min: usize,
+    marker: PhantomData<(O, V, C)>,
+}
+
+def_not!(CollectCounted<C, P, O, V>);
+
+impl<C, P, O, V> Debug for CollectCounted<C, P, O, V>
+where
+    P: Debug,
+{
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("CollectCounted")
+            .field("pat", &self.pat)
+            .field("min", &self.min)
+            .finish()
+    }
+}
+
+impl<C, P, O, V> Clone for CollectCounted<C, P, O, V>
+where
+    P: Clone,
+{
+    fn clone(&self) -> Self {
+        Self {
+            pat: self.pat.clone(),
+            min: self.min,
+            marker: self.marker,
+        }
+    }
+}
+
+impl<C, P, O, V> CollectCounted<C, P, O, V> {
+    pub fn new(pat: P) -> Self {
+        Self {
+            pat,
+            min: 1,
+            marker: PhantomData,
+        }
+    }
+
+    pub fn pat(&self) -> &P {
+        &self.pat
+    }
+
+    pub fn pat_mut(&mut self) -> &mut P {
+        &mut self.pat
+    }
+
+    pub fn min(&self) -> usize {
+        self.min
+    }
+
+    pub fn set_pat(&mut self, pat: P) -> &mut Self {
+        self.pat = pat;
+        self
+    }
+
+    pub fn set_min(&mut self, min: usize) -> &mut Self {
+        self.min = min;
+        self
+    }
+
+    pub fn at_least(mut self, min: usize) -> Self {
+        self.min = min;
+        self
+    }
+}
+
+impl<'a, C, P, M, O, V, H, A> Ctor<'a, C, M, (usize, V), H, A> for CollectCounted<C, P, O, V>
+where
+    V: FromIterator<O>,
+    P: Ctor<'a, C, M, O, H, A>,
+    C: Context<'a> + Match<C>,
+    H: Handler<A, Out = M, Error = Error>,
+    A: Extract<'a, C, Span, Out<'a> = A, Error = Error>,
+{
+    #[inline(always)]
+    fn construct(&self, ctx: &mut C, func: &mut H) -> Result<(usize, V), Error> {
+        let mut g = CtxGuard::new(ctx);
+        let mut cnt = 0;
+        let mut end = false;
+        let mut fatal = None;
+        let mut ret = Err(Error::Collect);
+        let beg = g.beg();
+        let val = trace!(
+            "collect_counted",
+            beg,
+            V::from_iter(std::iter::from_fn(|| {
+                let offset = g.end();
+
+                if end {
+                    return None;
+                }
+                match self.pat.construct(g.ctx(), func) {
+                    Ok(ret) => {
+                        cnt += 1;
+                        end = g.is_stuck(offset);
+                        Some(ret)
+                    }
+                    Err(e) => {
+                        fatal = e.is_fatal().then_some(e);
+                        None
+                    }
+                }
+            }))
+        );
+
+        if let Some(e) = fatal {
+            ret = Err(e);
+        } else if cnt >= self.min {
+            ret = Ok((cnt, val));
+        }
+        trace!("collect_counted", beg -> g.end(), ret.is_ok());
+        g.process_ret(ret)
+    }
+}
+
+impl<'a, C, P, O, V> Regex<C> for CollectCounted<C, P, O, V>
+where
+    P: Regex<C, Ret = Span>,
+    C: Context<'a> + Match<C>,
+{
+    type Ret = P::Ret;
+
+    #[inline(always)]
+    fn try_parse(&self, ctx: &mut C) -> Result<Self::Ret, Error> {
+        let mut g = CtxGuard::new(ctx);
+        let mut cnt = 0;
+        let mut span = <Span as Ret>::from_ctx(g.ctx(), (0, 0));
+        let mut ret = Err(Error::Collect);
+        let beg = g.beg();
+
+        trace!("collect_counted", beg, ());
+        let mut offset = g.end();
+
+        while let Some(ret) = g.non_fatal(|ctx| ctx.try_mat(&self.pat))? {
+            cnt += 1;
+            span.add_assign(ret);
+            if g.is_stuck(offset) {
+                break;
+            }
+            offset = g.end();
+        }
+        if cnt >= self.min {
+            ret = Ok(span);
+        }
+        trace!("collect_counted", beg => g.end(), g.process_ret(ret))
+    }
+}
+
+///
+/// Repeatedly match `P` at least [`min`](crate::re::ctor::IndexBy#method.min) times,
+/// and insert the results into a [`HashMap`] keyed by `F`.
+///
+/// # Ctor
+///
+/// Return a [`HashMap`] that maps `F(&O)` to the result `O` of `P`.
+/// The later one wins if the key is duplicated, or return [`Error::IndexBy`] in [`strict`](IndexBy::strict) mode.
+/// The default minimum size is 1.
+///
+/// # Example
+///
+/// ```
+/// # use neure::prelude::*;
+/// #
+/// # fn main() -> color_eyre::Result<()> {
+/// #     color_eyre::install()?;
+///     let word = neu::ascii_alphabetic().repeat_one_more();
+///     let words = word.pad(" ").index_by(|w: &&str| w.len());
+///     let index = CharsCtx::new("a be cat do ").ctor(&words)?;
+///
+///     assert_eq!(index.len(), 3);
+///     assert_eq!(index[&1], "a");
+///     assert_eq!(index[&2], "do");
+///     assert_eq!(index[&3], "cat");
+///     assert!(CharsCtx::new("a be cat do ").ctor(&words.strict()).is_err());
+///     Ok(())
+/// # }
+/// ```
+#[derive(Default, Copy)]
+pub struct IndexBy<C, P, F> {
+    pat: P,
+    key: F,
+    min: usize,
+    strict: bool,
+    marker: PhantomData<C>,
+}
+
+def_not!(IndexBy<C, P, F>);
+
+impl<C, P, F> Debug for IndexBy<C, P, F>
+where
+    P: Debug,
+{
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("IndexBy")
+            .field("pat", &self.pat)
+            .field("min", &self.min)
+            .field("strict", &self.strict)
+            .finish()
+    }
+}
+
+impl<C, P, F> Clone for IndexBy<C, P, F>
+where
+    P: Clone,
+    F: Clone,
+{
+    fn clone(&self) -> Self {
+        Self {
+            pat: self.pat.clone(),
+            key: self.key.clone(),
+            min: self.min,
+            strict: self.strict,
+            marker: self.marker,
+        }
+    }
+}
+
+impl<C, P, F> IndexBy<C, P, F> {
+    pub fn new(pat: P, key: F) -> Self {
+        Self {
+            pat,
+            key,
+            min: 1,
+            strict: false,
+            marker: PhantomData,
+        }
+    }
+
+    pub fn pat(&self) -> &P {
+        &self.pat
+    }
+
+    pub fn pat_mut(&mut self) -> &mut P {
+        &mut self.pat
+    }
+
+    pub fn key(&self) -> &F {
+        &self.key
+    }
+
+    pub fn key_mut(&mut self) -> &mut F {
+        &mut self.key
+    }
+
+    pub fn min(&self) -> usize {
+        self.min
+    }
+
+    pub fn is_strict(&self) -> bool {
+        self.strict
+    }
+
+    pub fn set_pat(&mut self, pat: P) -> &mut Self {
+        self.pat = pat;
+        self
+    }
+
+    pub fn set_key(&mut self, key: F) -> &mut Self {
+        self.key = key;
+        self
+    }
+
+    pub fn set_min(&mut self, min: usize) -> &mut Self {
+        self.min = min;
+        self
+    }
+
+    pub fn set_strict(&mut self, strict: bool) -> &mut Self {
+        self.strict = strict;
+        self
+    }
+
+    pub fn at_least(mut self, min: usize) -> Self {
+        self.min = min;
+        self
+    }
+
+    /// Return [`Error::IndexBy`] if the key is duplicated.
+    pub fn strict(mut self) -> Self {
+        self.strict = true;
+        self
+    }
+}
+
+impl<'a, C, P, F, M, O, K, H, A> Ctor<'a, C, M, HashMap<K, O>, H, A> for IndexBy<C, P, F>
+where
+    K: Eq + Hash,
+    F: Fn(&O) -> K,
+    P: Ctor<'a, C, M, O, H, A>,
+    C: Context<'a> + Match<C>,
+    H: Handler<A, Out = M, Error = Error>,
+    A: Extract<'a, C, Span, Out<'a> = A, Error = Error>,
+{
+    #[inline(always)]
+    fn construct(&self, ctx: &mut C, func: &mut H) -> Result<HashMap<K, O>, Error> {
+        let mut g = CtxGuard::new(ctx);
+        let mut cnt = 0;
+        let mut map = HashMap::new();
+        let mut ret = Err(Error::Collect);
+        let mut dup = false;
+        let beg = g.beg();
+
+        trace!("index_by", beg, ());
+        let mut offset = g.end();
+
+        while let Some(val) = g.non_fatal(|ctx| self.pat.construct(ctx, func))? {
+            cnt += 1;
+            dup = map.insert((self.key)(&val), val).is_some() && self.strict;
+            if dup || g.is_stuck(offset) {
+                break;
+            }
+            offset = g.end();
+        }
+        if dup {
+            ret = Err(Error::IndexBy);
+        } else if cnt >= self.min {
+            ret = Ok(map);
+        }
+        trace!("index_by", beg -> g.end(), ret.is_ok());
+        g.process_ret(ret)
+    }
+}
+
+impl<'a, C, P, F> Regex<C> for IndexBy<C, P, F>
+where
+    P: Regex<C, Ret = Span>,
+    C: Context<'a> + Match<C>,
+{
+    type Ret = P::Ret;
+
+    #[inline(always)]
+    fn try_parse(&self, ctx: &mut C) -> Result<Self::Ret, Error> {
+        let mut g = CtxGuard::new(ctx);
+        let mut cnt = 0;
+        let mut span = <Span as Ret>::from_ctx(g.ctx(), (0, 0));
+        let mut ret = Err(Error::Collect);
+        let beg = g.beg();
+
+        trace!("index_by", beg, ());
+        let mut offset = g.end();
+
+        while let Some(ret) = g.non_fatal(|ctx| ctx.try_mat(&self.pat))? {
+            cnt += 1;
+            span.add_assign(ret);
+            if g.is_stuck(offset) {
+                break;
+            }
+            offset = g.end();
+        }
+        if cnt >= self.min {
+            ret = Ok(span);
+        }
+        trace!("index_by", beg => g.end(), g.process_ret(ret))
+    }
+}
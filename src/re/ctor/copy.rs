@@ -0,0 +1,184 @@
+use std::cell::Cell;
+use std::cell::RefCell;
+use std::fmt::Debug;
+use std::marker::PhantomData;
+
+use crate::ctx::Context;
+use crate::ctx::CtxGuard;
+use crate::ctx::Match;
+use crate::ctx::Span;
+use crate::err::Error;
+use crate::re::def_not;
+use crate::re::trace;
+use crate::re::Ctor;
+use crate::re::Extract;
+use crate::re::Handler;
+use crate::re::Regex;
+
+///
+/// Match `P`, append the matched bytes into the buffer `buf`.
+///
+/// It will return [`Error::CopyInto`] if the remaining space of `buf` is not enough,
+/// nothing will be written in that case.
+///
+/// # Ctor
+///
+/// Return the count of bytes written by this match, the handler will not be invoked.
+///
+/// # Example
+///
+/// ```
+/// # use neure::prelude::*;
+/// #
+/// # fn main() -> color_eyre::Result<()> {
+/// #     color_eyre::install()?;
+///     let mut buf = [0; 10];
+///     let hex = neu::ascii_hexdigit().repeat_one_more();
+///     let copy = re::copy_into(&mut buf, hex);
+///     let mut ctx = BytesCtx::new(b"dead,beef");
+///     let lens = ctx.ctor(&copy.sep(b","))?;
+///
+///     assert_eq!(lens, [4, 4]);
+///     assert_eq!(&buf[..lens.iter().sum::<usize>()], b"deadbeef");
+///     Ok(())
+/// # }
+/// ```
+pub struct CopyInto<'b, C, P> {
+    pat: P,
+    buf: RefCell<&'b mut [u8]>,
+    len: Cell<usize>,
+    marker: PhantomData<C>,
+}
+
+def_not!(CopyInto<'b, C, P>);
+
+impl<C, P> Debug for CopyInto<'_, C, P>
+where
+    P: Debug,
+{
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("CopyInto")
+            .field("pat", &self.pat)
+            .field("buf", &self.buf)
+            .field("len", &self.len)
+            .finish()
+    }
+}
+
+impl<'b, C, P> CopyInto<'b, C, P> {
+    pub fn new(buf: &'b mut [u8], pat: P) -> Self {
+        Self {
+            pat,
+            buf: RefCell::new(buf),
+            len: Cell::new(0),
+            marker: PhantomData,
+        }
+    }
+
+    pub fn pat(&self) -> &P {
+        &self.pat
+    }
+
+    pub fn pat_mut(&mut self) -> &mut P {
+        &mut self.pat
+    }
+
+    /// Return the count of bytes written into the buffer.
+    pub fn len(&self) -> usize {
+        self.len.get()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.len() == 0
+    }
+
+    /// Return the capacity of the buffer.
+    pub fn capacity(&self) -> usize {
+        self.buf.borrow().len()
+    }
+
+    pub fn set_pat(&mut self, pat: P) -> &mut Self {
+        self.pat = pat;
+        self
+    }
+
+    /// Write from the start of the buffer again.
+    pub fn reset(&self) -> &Self {
+        self.len.set(0);
+        self
+    }
+
+    /// Return the written part of the buffer.
+    pub fn into_written(self) -> &'b mut [u8] {
+        let len = self.len.get();
+
+        &mut self.buf.into_inner()[..len]
+    }
+}
+
+impl<'a, C, P> CopyInto<'_, C, P>
+where
+    C::Orig: AsRef<[u8]>,
+    C: Context<'a> + 'a,
+{
+    fn copy(&self, ctx: &C, span: &Span) -> Result<(), Error> {
+        let bytes = ctx.orig_sub(span.beg, span.len)?.as_ref();
+        let mut buf = self.buf.borrow_mut();
+        let beg = self.len.get();
+        let end = beg + bytes.len();
+
+        if end > buf.len() {
+            return Err(Error::CopyInto {
+                need: bytes.len(),
+                remain: buf.len() - beg,
+            });
+        }
+        buf[beg..end].copy_from_slice(bytes);
+        self.len.set(end);
+        Ok(())
+    }
+}
+
+impl<'a, C, P, M, H, A> Ctor<'a, C, M, usize, H, A> for CopyInto<'_, C, P>
+where
+    P: Regex<C, Ret = Span>,
+    C::Orig: AsRef<[u8]>,
+    C: Context<'a> + Match<C> + 'a,
+    H: Handler<A, Out = M, Error = Error>,
+    A: Extract<'a, C, Span, Out<'a> = A, Error = Error>,
+{
+    #[inline(always)]
+    fn construct(&self, ctx: &mut C, _: &mut H) -> Result<usize, Error> {
+        ctx.try_mat(self).map(|span| span.len)
+    }
+}
+
+impl<'a, C, P> Regex<C> for CopyInto<'_, C, P>
+where
+    P: Regex<C, Ret = Span>,
+    C::Orig: AsRef<[u8]>,
+    C: Context<'a> + Match<C> + 'a,
+{
+    type Ret = Span;
+
+    #[inline(always)]
+    fn try_parse(&self, ctx: &mut C) -> Result<Self::Ret, Error> {
+        let mut g = CtxGuard::new(ctx);
+        let beg = g.beg();
+        let ret = trace!("copy_into", beg @ "pat", g.try_mat(&self.pat));
+        let ret = ret.and_then(|span| self.copy(g.ctx(), &span).map(|_| span));
+
+        trace!("copy_into", beg => g.end(), g.process_ret(ret))
+    }
+}
+
+///
+/// Create a [`CopyInto`] that appends the bytes matched by `pat` into `buf`.
+///
+pub fn copy_into<'a, 'b, C, P>(buf: &'b mut [u8], pat: P) -> CopyInto<'b, C, P>
+where
+    C: Context<'a>,
+    P: Regex<C, Ret = Span>,
+{
+    CopyInto::new(buf, pat)
+}
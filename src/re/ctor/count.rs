@@ -0,0 +1,171 @@
+use std::fmt::Debug;
+use std::marker::PhantomData;
+
+use crate::ctx::Context;
+use crate::ctx::CtxGuard;
+use crate::ctx::Match;
+use crate::ctx::Span;
+use crate::err::Error;
+use crate::neu::length_of;
+use crate::re::def_not;
+use crate::re::trace;
+use crate::re::Ctor;
+use crate::re::Extract;
+use crate::re::Handler;
+use crate::re::Regex;
+
+///
+/// Scan from the current offset to the end of input, count the non-overlapping matches of `P`.
+///
+/// If `P` not matched at current offset, skip one item and try again.
+/// Empty matches are not counted.
+///
+/// # Ctor
+///
+/// Return the count of matches, the offset of context will be at the end of input.
+///
+/// # Example
+///
+/// ```
+/// # use neure::prelude::*;
+/// #
+/// # fn main() -> color_eyre::Result<()> {
+/// #     color_eyre::install()?;
+///     let foo = re::count_all("foo");
+///     let mut ctx = CharsCtx::new("foo bar foofoo");
+///
+///     assert_eq!(ctx.ctor(&foo)?, 3);
+///     assert_eq!(ctx.offset(), 14);
+///     Ok(())
+/// # }
+/// ```
+#[derive(Default, Copy)]
+pub struct CountAll<C, P> {
+    pat: P,
+    marker: PhantomData<C>,
+}
+
+def_not!(CountAll<C, P>);
+
+impl<C, P> Debug for CountAll<C, P>
+where
+    P: Debug,
+{
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("CountAll").field("pat", &self.pat).finish()
+    }
+}
+
+impl<C, P> Clone for CountAll<C, P>
+where
+    P: Clone,
+{
+    fn clone(&self) -> Self {
+        Self {
+            pat: self.pat.clone(),
+            marker: self.marker,
+        }
+    }
+}
+
+impl<C, P> CountAll<C, P> {
+    pub fn new(pat: P) -> Self {
+        Self {
+            pat,
+            marker: PhantomData,
+        }
+    }
+
+    pub fn pat(&self) -> &P {
+        &self.pat
+    }
+
+    pub fn pat_mut(&mut self) -> &mut P {
+        &mut self.pat
+    }
+
+    pub fn set_pat(&mut self, pat: P) -> &mut Self {
+        self.pat = pat;
+        self
+    }
+}
+
+impl<'a, C, P> CountAll<C, P>
+where
+    P: Regex<C, Ret = Span>,
+    C: Context<'a> + Match<C> + 'a,
+{
+    fn count(&self, ctx: &mut C) -> Result<usize, Error> {
+        let mut cnt = 0;
+
+        loop {
+            let offset = ctx.offset();
+
+            match ctx.try_mat(&self.pat) {
+                Ok(_) if ctx.offset() > offset => cnt += 1,
+                Err(e) if e.is_fatal() => return Err(e),
+                _ => {
+                    ctx.set_offset(offset);
+
+                    let mut iter = ctx.peek()?;
+
+                    match iter.next() {
+                        Some((beg, _)) => {
+                            let len = length_of(beg, ctx, iter.next().map(|v| v.0));
+
+                            ctx.inc(len);
+                        }
+                        None => return Ok(cnt),
+                    }
+                }
+            }
+        }
+    }
+}
+
+impl<'a, C, P, M, H, A> Ctor<'a, C, M, usize, H, A> for CountAll<C, P>
+where
+    P: Regex<C, Ret = Span>,
+    C: Context<'a> + Match<C> + 'a,
+    H: Handler<A, Out = M, Error = Error>,
+    A: Extract<'a, C, Span, Out<'a> = A, Error = Error>,
+{
+    #[inline(always)]
+    fn construct(&self, ctx: &mut C, _: &mut H) -> Result<usize, Error> {
+        let mut g = CtxGuard::new(ctx);
+        let beg = g.beg();
+        let ret = trace!("count_all", beg @ "pat", self.count(g.ctx()));
+
+        trace!("count_all", beg -> g.end(), ret.is_ok());
+        g.process_ret(ret)
+    }
+}
+
+impl<'a, C, P> Regex<C> for CountAll<C, P>
+where
+    P: Regex<C, Ret = Span>,
+    C: Context<'a> + Match<C> + 'a,
+{
+    type Ret = Span;
+
+    #[inline(always)]
+    fn try_parse(&self, ctx: &mut C) -> Result<Self::Ret, Error> {
+        let mut g = CtxGuard::new(ctx);
+        let beg = g.beg();
+        let ret = trace!("count_all", beg @ "pat", self.count(g.ctx()));
+        let ret = ret.map(|_| Span::new(beg, g.end() - beg));
+
+        trace!("count_all", beg => g.end(), g.process_ret(ret))
+    }
+}
+
+///
+/// Create a [`CountAll`] that counts the non-overlapping matches of `pat` until the end of input.
+///
+pub fn count_all<'a, C, P>(pat: P) -> CountAll<C, P>
+where
+    C: Context<'a>,
+    P: Regex<C, Ret = Span>,
+{
+    CountAll::new(pat)
+}
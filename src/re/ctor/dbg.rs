@@ -0,0 +1,198 @@
+use std::fmt::Debug;
+use std::marker::PhantomData;
+
+use crate::ctx::Context;
+use crate::ctx::CtxGuard;
+use crate::ctx::Match;
+use crate::ctx::Span;
+use crate::err::Error;
+use crate::re::def_not;
+use crate::re::trace;
+use crate::re::Ctor;
+use crate::re::Extract;
+use crate::re::Handler;
+use crate::re::Regex;
+
+/// The maximum number of items display in the message of [`DbgOnErr`].
+const DBG_WINDOW: usize = 20;
+
+///
+/// Match `P`, if it failed, print the `name`, offset and the following input to stderr.
+/// The error of `P` will be returned unchanged.
+///
+/// Use [`set_hook`](DbgOnErr::set_hook) to replace the default printer.
+///
+/// # Ctor
+///
+/// It will return the result of `P`.
+///
+/// # Example
+///
+/// ```
+/// # use neure::prelude::*;
+/// #
+/// # fn main() -> color_eyre::Result<()> {
+/// #     color_eyre::install()?;
+///     let num = neu::digit(10).repeat_one_more().dbg_on_err("num");
+///     let mut ctx = CharsCtx::new("42");
+///
+///     // print `r`num` failed @0: "abc" (Error: ...)` to stderr
+///     assert!(CharsCtx::new("abc").ctor(&num).is_err());
+///     assert_eq!(ctx.ctor(&num)?, "42");
+///     Ok(())
+/// # }
+/// ```
+#[derive(Default, Copy)]
+pub struct DbgOnErr<C, P> {
+    pat: P,
+    name: &'static str,
+    hook: Option<fn(&str)>,
+    marker: PhantomData<C>,
+}
+
+def_not!(DbgOnErr<C, P>);
+
+impl<C, P> Debug for DbgOnErr<C, P>
+where
+    P: Debug,
+{
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("DbgOnErr")
+            .field("pat", &self.pat)
+            .field("name", &self.name)
+            .field("hook", &self.hook)
+            .finish()
+    }
+}
+
+impl<C, P> Clone for DbgOnErr<C, P>
+where
+    P: Clone,
+{
+    fn clone(&self) -> Self {
+        Self {
+            pat: self.pat.clone(),
+            name: self.name,
+            hook: self.hook,
+            marker: self.marker,
+        }
+    }
+}
+
+impl<C, P> DbgOnErr<C, P> {
+    pub fn new(pat: P, name: &'static str) -> Self {
+        Self {
+            pat,
+            name,
+            hook: None,
+            marker: PhantomData,
+        }
+    }
+
+    pub fn pat(&self) -> &P {
+        &self.pat
+    }
+
+    pub fn pat_mut(&mut self) -> &mut P {
+        &mut self.pat
+    }
+
+    pub fn name(&self) -> &'static str {
+        self.name
+    }
+
+    pub fn hook(&self) -> Option<fn(&str)> {
+        self.hook
+    }
+
+    pub fn set_pat(&mut self, pat: P) -> &mut Self {
+        self.pat = pat;
+        self
+    }
+
+    pub fn set_name(&mut self, name: &'static str) -> &mut Self {
+        self.name = name;
+        self
+    }
+
+    /// Set the function called with the message instead of printing to stderr.
+    pub fn set_hook(&mut self, hook: fn(&str)) -> &mut Self {
+        self.hook = Some(hook);
+        self
+    }
+
+    pub fn with_hook(mut self, hook: fn(&str)) -> Self {
+        self.hook = Some(hook);
+        self
+    }
+}
+
+impl<'a, C, P> DbgOnErr<C, P>
+where
+    C: Context<'a> + 'a,
+    C::Orig: Debug,
+{
+    fn report(&self, ctx: &C, offset: usize, err: &Error) {
+        // the index of peek_at is relative to offset
+        let len = ctx
+            .peek_at(offset)
+            .ok()
+            .and_then(|mut iter| iter.nth(DBG_WINDOW).map(|v| v.0))
+            .unwrap_or(ctx.len().saturating_sub(offset));
+        let msg = match ctx.orig_sub(offset, len) {
+            Ok(window) => format!(
+                "r`{}` failed @{}: {:?} ({:?})",
+                self.name, offset, window, err
+            ),
+            Err(_) => format!("r`{}` failed @{}: ({:?})", self.name, offset, err),
+        };
+
+        match self.hook {
+            Some(hook) => hook(&msg),
+            None => eprintln!("{msg}"),
+        }
+    }
+}
+
+impl<'a, C, P, M, O, H, A> Ctor<'a, C, M, O, H, A> for DbgOnErr<C, P>
+where
+    P: Ctor<'a, C, M, O, H, A>,
+    C: Context<'a> + Match<C> + 'a,
+    C::Orig: Debug,
+    H: Handler<A, Out = M, Error = Error>,
+    A: Extract<'a, C, Span, Out<'a> = A, Error = Error>,
+{
+    #[inline(always)]
+    fn construct(&self, ctx: &mut C, func: &mut H) -> Result<O, Error> {
+        let mut g = CtxGuard::new(ctx);
+        let beg = g.beg();
+        let ret = trace!("dbg_on_err", beg @ "pat", self.pat.construct(g.ctx(), func));
+
+        if let Err(e) = &ret {
+            self.report(g.ctx(), beg, e);
+        }
+        trace!("dbg_on_err", beg -> g.end(), ret.is_ok());
+        g.process_ret(ret)
+    }
+}
+
+impl<'a, C, P> Regex<C> for DbgOnErr<C, P>
+where
+    P: Regex<C, Ret = Span>,
+    C: Context<'a> + Match<C> + 'a,
+    C::Orig: Debug,
+{
+    type Ret = P::Ret;
+
+    #[inline(always)]
+    fn try_parse(&self, ctx: &mut C) -> Result<Self::Ret, Error> {
+        let mut g = CtxGuard::new(ctx);
+        let beg = g.beg();
+        let ret = trace!("dbg_on_err", beg @ "pat", g.try_mat(&self.pat));
+
+        if let Err(e) = &ret {
+            self.report(g.ctx(), beg, e);
+        }
+        trace!("dbg_on_err", beg => g.end(), g.process_ret(ret))
+    }
+}
@@ -0,0 +1,169 @@
+use std::fmt::Debug;
+use std::marker::PhantomData;
+
+use crate::ctx::Context;
+use crate::ctx::CtxGuard;
+use crate::ctx::DepthSink;
+use crate::ctx::Match;
+use crate::ctx::Span;
+use crate::err::Error;
+use crate::re::def_not;
+use crate::re::trace;
+use crate::re::Ctor;
+use crate::re::Extract;
+use crate::re::Handler;
+use crate::re::Regex;
+
+///
+/// Match `P` with the nesting depth of the [`DepthSink`] of [`Context`] increased by one,
+/// return [`Error::DepthExceeded`] if the depth is already `max`.
+///
+/// The depth is increased when entering `P`, before anything matched, so a failed attempt
+/// of the recursive `P` also counts. It will be restored after `P` returned.
+/// [`Error::DepthExceeded`] is [`fatal`](Error::is_fatal), so it will not be swallowed by
+/// the loops and alternatives, such as [`sep`](crate::re::ConstructOp::sep) and [`or`](crate::re::ConstructOp::or).
+///
+/// # Ctor
+///
+/// It will return the result of `P`.
+///
+/// # Example
+///
+/// ```
+/// # use neure::prelude::*;
+/// #
+/// # fn main() -> color_eyre::Result<()> {
+/// #     color_eyre::install()?;
+///     let list = re::rec_parser(|ctor| {
+///         ctor.repeat(0..)
+///             .quote("(", ")")
+///             .map(|v: Vec<usize>| Ok(v.len()))
+///             .max_depth(3)
+///     });
+///
+///     assert_eq!(CharsCtx::new("(()())").with_sink().ctor(&list)?, 2);
+///     assert!(matches!(
+///         CharsCtx::new("((()))").with_sink().ctor(&list),
+///         Err(neure::err::Error::DepthExceeded)
+///     ));
+///     Ok(())
+/// # }
+/// ```
+#[derive(Default, Copy)]
+pub struct MaxDepth<C, P> {
+    pat: P,
+    max: usize,
+    marker: PhantomData<C>,
+}
+
+def_not!(MaxDepth<C, P>);
+
+impl<C, P> Debug for MaxDepth<C, P>
+where
+    P: Debug,
+{
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("MaxDepth")
+            .field("pat", &self.pat)
+            .field("max", &self.max)
+            .finish()
+    }
+}
+
+impl<C, P> Clone for MaxDepth<C, P>
+where
+    P: Clone,
+{
+    fn clone(&self) -> Self {
+        Self {
+            pat: self.pat.clone(),
+            max: self.max,
+            marker: self.marker,
+        }
+    }
+}
+
+impl<C, P> MaxDepth<C, P> {
+    pub fn new(pat: P, max: usize) -> Self {
+        Self {
+            pat,
+            max,
+            marker: PhantomData,
+        }
+    }
+
+    pub fn pat(&self) -> &P {
+        &self.pat
+    }
+
+    pub fn pat_mut(&mut self) -> &mut P {
+        &mut self.pat
+    }
+
+    pub fn max(&self) -> usize {
+        self.max
+    }
+
+    pub fn set_pat(&mut self, pat: P) -> &mut Self {
+        self.pat = pat;
+        self
+    }
+
+    pub fn set_max(&mut self, max: usize) -> &mut Self {
+        self.max = max;
+        self
+    }
+}
+
+impl<'a, C, P, M, O, H, A> Ctor<'a, C, M, O, H, A> for MaxDepth<C, P>
+where
+    P: Ctor<'a, C, M, O, H, A>,
+    C: Context<'a> + Match<C> + DepthSink,
+    H: Handler<A, Out = M, Error = Error>,
+    A: Extract<'a, C, Span, Out<'a> = A, Error = Error>,
+{
+    #[inline(always)]
+    fn construct(&self, ctx: &mut C, func: &mut H) -> Result<O, Error> {
+        let mut g = CtxGuard::new(ctx);
+        let beg = g.beg();
+        let depth = g.ctx().depth();
+        let ret = if depth >= self.max {
+            Err(Error::DepthExceeded)
+        } else {
+            g.ctx().set_depth(depth + 1);
+            let ret = trace!("max_depth", beg @ "pat", self.pat.construct(g.ctx(), func));
+
+            g.ctx().set_depth(depth);
+            ret
+        };
+
+        trace!("max_depth", beg -> g.end(), ret.is_ok());
+        g.process_ret(ret)
+    }
+}
+
+impl<'a, C, P> Regex<C> for MaxDepth<C, P>
+where
+    P: Regex<C, Ret = Span>,
+    C: Context<'a> + Match<C> + DepthSink,
+{
+    type Ret = P::Ret;
+
+    #[inline(always)]
+    fn try_parse(&self, ctx: &mut C) -> Result<Self::Ret, Error> {
+        let mut g = CtxGuard::new(ctx);
+        let beg = g.beg();
+        let depth = g.ctx().depth();
+        let ret = if depth >= self.max {
+            Err(Error::DepthExceeded)
+        } else {
+            g.ctx().set_depth(depth + 1);
+            let ret = trace!("max_depth", beg @ "pat", g.ctx().try_mat(&self.pat));
+
+            g.ctx().set_depth(depth);
+            ret
+        };
+
+        trace!("max_depth", beg => g.end(), g.process_ret(ret))
+    }
+}
@@ -0,0 +1,149 @@
+use std::fmt::Debug;
+use std::marker::PhantomData;
+
+use crate::ctx::Context;
+use crate::ctx::CtxGuard;
+use crate::ctx::Match;
+use crate::ctx::Span;
+use crate::err::Error;
+use crate::re::def_not;
+use crate::re::trace;
+use crate::re::Ctor;
+use crate::re::Extract;
+use crate::re::Handler;
+use crate::re::Regex;
+
+///
+/// Match `P`, replace the error with [`Error::Expected`] if it failed.
+///
+/// # Ctor
+///
+/// It will return the result of `P`,
+/// or [`Error::Expected`] with the description and the offset where `P` start matching.
+///
+/// # Example
+///
+/// ```
+/// # use neure::{err::Error, prelude::*};
+/// #
+/// # fn main() -> color_eyre::Result<()> {
+/// #     color_eyre::install()?;
+///     let num = neu::digit(10).repeat_one_more().expect("number");
+///     let pair = num.sep_once(",", num);
+///
+///     assert_eq!(CharsCtx::new("1,2").ctor(&pair)?, ("1", "2"));
+///     assert!(matches!(
+///         CharsCtx::new("1,x").ctor(&pair),
+///         Err(Error::Expected { what: "number", at: 2 })
+///     ));
+///     Ok(())
+/// # }
+/// ```
+#[derive(Default, Copy)]
+pub struct Expect<C, P> {
+    pat: P,
+    what: &'static str,
+    marker: PhantomData<C>,
+}
+
+def_not!(Expect<C, P>);
+
+impl<C, P> Debug for Expect<C, P>
+where
+    P: Debug,
+{
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("Expect")
+            .field("pat", &self.pat)
+            .field("what", &self.what)
+            .finish()
+    }
+}
+
+impl<C, P> Clone for Expect<C, P>
+where
+    P: Clone,
+{
+    fn clone(&self) -> Self {
+        Self {
+            pat: self.pat.clone(),
+            what: self.what,
+            marker: self.marker,
+        }
+    }
+}
+
+impl<C, P> Expect<C, P> {
+    pub fn new(pat: P, what: &'static str) -> Self {
+        Self {
+            pat,
+            what,
+            marker: PhantomData,
+        }
+    }
+
+    pub fn pat(&self) -> &P {
+        &self.pat
+    }
+
+    pub fn pat_mut(&mut self) -> &mut P {
+        &mut self.pat
+    }
+
+    pub fn what(&self) -> &'static str {
+        self.what
+    }
+
+    pub fn set_pat(&mut self, pat: P) -> &mut Self {
+        self.pat = pat;
+        self
+    }
+
+    pub fn set_what(&mut self, what: &'static str) -> &mut Self {
+        self.what = what;
+        self
+    }
+}
+
+impl<'a, C, P, M, O, H, A> Ctor<'a, C, M, O, H, A> for Expect<C, P>
+where
+    P: Ctor<'a, C, M, O, H, A>,
+    C: Context<'a> + Match<C>,
+    H: Handler<A, Out = M, Error = Error>,
+    A: Extract<'a, C, Span, Out<'a> = A, Error = Error>,
+{
+    #[inline(always)]
+    fn construct(&self, ctx: &mut C, func: &mut H) -> Result<O, Error> {
+        let mut g = CtxGuard::new(ctx);
+        let beg = g.beg();
+        let ret = trace!("expect", beg @ "pat", self.pat.construct(g.ctx(), func));
+        let ret = ret.map_err(|_| Error::Expected {
+            what: self.what,
+            at: beg,
+        });
+
+        trace!("expect", beg -> g.end(), ret.is_ok());
+        g.process_ret(ret)
+    }
+}
+
+impl<'a, C, P> Regex<C> for Expect<C, P>
+where
+    P: Regex<C, Ret = Span>,
+    C: Context<'a> + Match<C>,
+{
+    type Ret = P::Ret;
+
+    #[inline(always)]
+    fn try_parse(&self, ctx: &mut C) -> Result<Self::Ret, Error> {
+        let mut g = CtxGuard::new(ctx);
+        let beg = g.beg();
+        let ret = trace!("expect", beg @ "pat", g.try_mat(&self.pat));
+        let ret = ret.map_err(|_| Error::Expected {
+            what: self.what,
+            at: beg,
+        });
+
+        trace!("expect", beg => g.end(), g.process_ret(ret))
+    }
+}
@@ -0,0 +1,250 @@
+use std::fmt::Debug;
+use std::marker::PhantomData;
+
+use crate::ctx::Context;
+use crate::ctx::CtxGuard;
+use crate::ctx::Match;
+use crate::ctx::Ret;
+use crate::ctx::Span;
+use crate::err::Error;
+use crate::neu::CRange;
+use crate::re::def_not;
+use crate::re::trace_v;
+use crate::re::Ctor;
+use crate::re::Extract;
+use crate::re::Handler;
+use crate::re::Regex;
+
+///
+/// Match regex `P` as many times as possible with S as the delimiter,
+/// fold the results into the accumulator created by `I` using `F`.
+///
+/// The separator semantics is same as [`Separate`](crate::re::ctor::Separate),
+/// but the results are not collected into a [`Vec`].
+///
+/// # Ctor
+///
+/// It will return the final accumulator.
+/// Return [`Error::Fold`] if `P` matched less than `min` times.
+///
+/// # Regex
+///
+/// It will return the merged [`Span`] of all the elements and delimiters.
+///
+/// # Example
+///
+/// ```
+/// # use neure::prelude::*;
+/// #
+/// # fn main() -> color_eyre::Result<()> {
+/// #     color_eyre::install()?;
+///     let num = neu::digit(10).repeat_one_more().map(map::from_str::<i64>());
+///     let max = num.fold(",", || i64::MIN, i64::max);
+///
+///     assert_eq!(CharsCtx::new("3,42,7").ctor(&max)?, 42);
+///     assert_eq!(CharsCtx::new("3,42,7").try_mat(&max)?, Span::new(0, 6));
+///     assert!(CharsCtx::new("").ctor(&max).is_err());
+///     Ok(())
+/// # }
+/// ```
+#[derive(Default, Copy)]
+pub struct Fold<C, P, S, I, F, O> {
+    pat: P,
+    sep: S,
+    init: I,
+    func: F,
+    skip: bool,
+    min: usize,
+    marker: PhantomData<(C, O)>,
+}
+
+def_not!(Fold<C, P, S, I, F, O>);
+
+impl<C, P, S, I, F, O> Debug for Fold<C, P, S, I, F, O>
+where
+    P: Debug,
+    S: Debug,
+{
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("Fold")
+            .field("pat", &self.pat)
+            .field("sep", &self.sep)
+            .field("skip", &self.skip)
+            .field("min", &self.min)
+            .finish()
+    }
+}
+
+impl<C, P, S, I, F, O> Clone for Fold<C, P, S, I, F, O>
+where
+    P: Clone,
+    S: Clone,
+    I: Clone,
+    F: Clone,
+{
+    fn clone(&self) -> Self {
+        Self {
+            pat: self.pat.clone(),
+            sep: self.sep.clone(),
+            init: self.init.clone(),
+            func: self.func.clone(),
+            skip: self.skip,
+            min: self.min,
+            marker: self.marker,
+        }
+    }
+}
+
+impl<C, P, S, I, F, O> Fold<C, P, S, I, F, O> {
+    pub fn new(pat: P, sep: S, init: I, func: F) -> Self {
+        Self {
+            pat,
+            sep,
+            init,
+            func,
+            skip: true,
+            min: 1,
+            marker: PhantomData,
+        }
+    }
+
+    pub fn pat(&self) -> &P {
+        &self.pat
+    }
+
+    pub fn pat_mut(&mut self) -> &mut P {
+        &mut self.pat
+    }
+
+    pub fn sep(&self) -> &S {
+        &self.sep
+    }
+
+    pub fn sep_mut(&mut self) -> &mut S {
+        &mut self.sep
+    }
+
+    pub fn skip(&self) -> bool {
+        self.skip
+    }
+
+    pub fn min(&self) -> usize {
+        self.min
+    }
+
+    pub fn set_pat(&mut self, pat: P) -> &mut Self {
+        self.pat = pat;
+        self
+    }
+
+    pub fn set_sep(&mut self, sep: S) -> &mut Self {
+        self.sep = sep;
+        self
+    }
+
+    pub fn set_skip(&mut self, skip: bool) -> &mut Self {
+        self.skip = skip;
+        self
+    }
+
+    pub fn set_min(&mut self, min: usize) -> &mut Self {
+        self.min = min;
+        self
+    }
+
+    pub fn with_skip(mut self, skip: bool) -> Self {
+        self.skip = skip;
+        self
+    }
+
+    pub fn at_least(mut self, min: usize) -> Self {
+        self.min = min;
+        self
+    }
+}
+
+impl<'a, C, S, P, I, F, M, O, B, H, A> Ctor<'a, C, M, B, H, A> for Fold<C, P, S, I, F, O>
+where
+    P: Ctor<'a, C, M, O, H, A>,
+    S: Regex<C, Ret = Span>,
+    I: Fn() -> B,
+    F: Fn(B, O) -> B,
+    C: Context<'a> + Match<C>,
+    H: Handler<A, Out = M, Error = Error>,
+    A: Extract<'a, C, Span, Out<'a> = A, Error = Error>,
+{
+    #[inline(always)]
+    fn construct(&self, ctx: &mut C, func: &mut H) -> Result<B, Error> {
+        let mut g = CtxGuard::new(ctx);
+        let mut acc = (self.init)();
+        let mut cnt = 0;
+        let beg = g.beg();
+        let range: CRange<usize> = (self.min..).into();
+
+        trace_v!("fold", range, beg, ());
+        let mut offset = g.end();
+
+        while let Some(ret) = g.non_fatal(|ctx| self.pat.construct(ctx, func))? {
+            let sep_ret = trace_v!("fold", range, beg @ "sep", g.ctx().try_mat(&self.sep));
+
+            if sep_ret.is_ok() || self.skip {
+                acc = (self.func)(acc, ret);
+                cnt += 1;
+            }
+            if sep_ret.is_err() || g.is_stuck(offset) {
+                break;
+            }
+            offset = g.end();
+        }
+        let ret = g.process_ret(if cnt >= self.min {
+            Ok(acc)
+        } else {
+            Err(Error::Fold)
+        });
+
+        trace_v!("fold", range, beg -> g.end(), ret.is_ok(), cnt);
+        ret
+    }
+}
+
+impl<'a, C, S, P, I, F, O> Regex<C> for Fold<C, P, S, I, F, O>
+where
+    S: Regex<C, Ret = Span>,
+    P: Regex<C, Ret = Span>,
+    C: Context<'a> + Match<C>,
+{
+    type Ret = Span;
+
+    #[inline(always)]
+    fn try_parse(&self, ctx: &mut C) -> Result<Self::Ret, Error> {
+        let mut g = CtxGuard::new(ctx);
+        let mut cnt = 0;
+        let mut span = <Span as Ret>::from_ctx(g.ctx(), (0, 0));
+        let mut ret = Err(Error::Fold);
+        let beg = g.beg();
+        let range: CRange<usize> = (self.min..).into();
+
+        trace_v!("fold", range, beg, ());
+        let mut offset = g.end();
+
+        while let Some(ret) = g.non_fatal(|ctx| ctx.try_mat(&self.pat))? {
+            let sep_ret = g.ctx().try_mat(&self.sep);
+
+            if sep_ret.is_ok() || self.skip {
+                cnt += 1;
+                span.add_assign(ret);
+                if let Ok(sep_ret) = sep_ret {
+                    span.add_assign(sep_ret);
+                }
+            }
+            if sep_ret.is_err() || g.is_stuck(offset) {
+                break;
+            }
+            offset = g.end();
+        }
+        if cnt >= self.min {
+            ret = Ok(span);
+        }
+        trace_v!("fold", range, beg => g.end(), g.process_ret(ret), cnt)
+    }
+}
@@ -0,0 +1,215 @@
+use std::fmt::Debug;
+use std::marker::PhantomData;
+
+use crate::ctx::Context;
+use crate::ctx::CtxGuard;
+use crate::ctx::Match;
+use crate::ctx::Span;
+use crate::err::Error;
+use crate::re::def_not;
+use crate::re::trace;
+use crate::re::Ctor;
+use crate::re::Extract;
+use crate::re::Handler;
+use crate::re::Regex;
+
+///
+/// Repeat `P` as many times as possible, then match the terminator `T`.
+/// If `T` does not match, give back the results of `P` one by one until `T` matched.
+///
+/// # Ctor
+///
+/// It will return a tuple of the results of `P` and `()`, the terminator is consumed.
+/// `P` must be matched at least `min` times, the default is 1.
+///
+/// # Example
+///
+/// ```
+/// # use neure::prelude::*;
+/// #
+/// # fn main() -> color_eyre::Result<()> {
+/// #     color_eyre::install()?;
+///     let any = neu::any().repeat_one();
+///     let name = any.greedy_until(".rs");
+///     let mut ctx = CharsCtx::new("main.rs.rs");
+///
+///     assert_eq!(ctx.ctor(&name)?.0, ["m", "a", "i", "n", ".", "r", "s"]);
+///     assert_eq!(ctx.offset(), 10);
+///     Ok(())
+/// # }
+/// ```
+#[derive(Default, Copy)]
+pub struct GreedyUntil<C, P, T> {
+    pat: P,
+    term: T,
+    min: usize,
+    marker: PhantomData<C>,
+}
+
+def_not!(GreedyUntil<C, P, T>);
+
+impl<C, P, T> Debug for GreedyUntil<C, P, T>
+where
+    P: Debug,
+    T: Debug,
+{
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("GreedyUntil")
+            .field("pat", &self.pat)
+            .field("term", &self.term)
+            .field("min", &self.min)
+            .finish()
+    }
+}
+
+impl<C, P, T> Clone for GreedyUntil<C, P, T>
+where
+    P: Clone,
+    T: Clone,
+{
+    fn clone(&self) -> Self {
+        Self {
+            pat: self.pat.clone(),
+            term: self.term.clone(),
+            min: self.min,
+            marker: self.marker,
+        }
+    }
+}
+
+impl<C, P, T> GreedyUntil<C, P, T> {
+    pub fn new(pat: P, term: T) -> Self {
+        Self {
+            pat,
+            term,
+            min: 1,
+            marker: PhantomData,
+        }
+    }
+
+    pub fn pat(&self) -> &P {
+        &self.pat
+    }
+
+    pub fn pat_mut(&mut self) -> &mut P {
+        &mut self.pat
+    }
+
+    pub fn term(&self) -> &T {
+        &self.term
+    }
+
+    pub fn term_mut(&mut self) -> &mut T {
+        &mut self.term
+    }
+
+    pub fn min(&self) -> usize {
+        self.min
+    }
+
+    pub fn set_pat(&mut self, pat: P) -> &mut Self {
+        self.pat = pat;
+        self
+    }
+
+    pub fn set_term(&mut self, term: T) -> &mut Self {
+        self.term = term;
+        self
+    }
+
+    pub fn set_min(&mut self, min: usize) -> &mut Self {
+        self.min = min;
+        self
+    }
+
+    pub fn at_least(mut self, min: usize) -> Self {
+        self.min = min;
+        self
+    }
+}
+
+impl<'a, C, P, T> GreedyUntil<C, P, T>
+where
+    T: Regex<C, Ret = Span>,
+    C: Context<'a> + Match<C>,
+{
+    /// Try the terminator from the last end offset, give back one element each time.
+    /// Return the count of elements kept if the terminator matched.
+    fn back_off(&self, ctx: &mut C, ends: &[usize]) -> Option<usize> {
+        ends.iter()
+            .enumerate()
+            .skip(self.min)
+            .rev()
+            .find_map(|(cnt, end)| {
+                ctx.set_offset(*end);
+                ctx.try_mat(&self.term).ok().map(|_| cnt)
+            })
+    }
+}
+
+impl<'a, C, P, T, M, O, H, A> Ctor<'a, C, M, (Vec<O>, ()), H, A> for GreedyUntil<C, P, T>
+where
+    P: Ctor<'a, C, M, O, H, A>,
+    T: Regex<C, Ret = Span>,
+    C: Context<'a> + Match<C>,
+    H: Handler<A, Out = M, Error = Error>,
+    A: Extract<'a, C, Span, Out<'a> = A, Error = Error>,
+{
+    #[inline(always)]
+    fn construct(&self, ctx: &mut C, func: &mut H) -> Result<(Vec<O>, ()), Error> {
+        let mut g = CtxGuard::new(ctx);
+        let beg = g.beg();
+        let mut ends = vec![beg];
+        let mut res = vec![];
+
+        trace!("greedy_until", beg, ());
+        while let Some(ret) = g.non_fatal(|ctx| self.pat.construct(ctx, func))? {
+            if g.is_stuck(ends[ends.len() - 1]) {
+                break;
+            }
+            res.push(ret);
+            ends.push(g.end());
+        }
+        let ret = trace!("greedy_until", beg @ "term", self.back_off(g.ctx(), &ends));
+        let ret = match ret {
+            Some(cnt) => {
+                res.truncate(cnt);
+                Ok((res, ()))
+            }
+            None => Err(Error::GreedyUntil),
+        };
+
+        trace!("greedy_until", beg -> g.end(), ret.is_ok());
+        g.process_ret(ret)
+    }
+}
+
+impl<'a, C, P, T> Regex<C> for GreedyUntil<C, P, T>
+where
+    P: Regex<C, Ret = Span>,
+    T: Regex<C, Ret = Span>,
+    C: Context<'a> + Match<C>,
+{
+    type Ret = Span;
+
+    #[inline(always)]
+    fn try_parse(&self, ctx: &mut C) -> Result<Self::Ret, Error> {
+        let mut g = CtxGuard::new(ctx);
+        let beg = g.beg();
+        let mut ends = vec![beg];
+
+        trace!("greedy_until", beg, ());
+        while g.non_fatal(|ctx| ctx.try_mat(&self.pat))?.is_some() {
+            if g.is_stuck(ends[ends.len() - 1]) {
+                break;
+            }
+            ends.push(g.end());
+        }
+        let ret = trace!("greedy_until", beg @ "term", self.back_off(g.ctx(), &ends));
+        let ret = ret
+            .map(|_| Span::new(beg, g.end() - beg))
+            .ok_or(Error::GreedyUntil);
+
+        trace!("greedy_until", beg => g.end(), g.process_ret(ret))
+    }
+}
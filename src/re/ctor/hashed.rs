@@ -0,0 +1,149 @@
+use std::fmt::Debug;
+use std::marker::PhantomData;
+
+use crate::ctx::Context;
+use crate::ctx::CtxGuard;
+use crate::ctx::Match;
+use crate::ctx::Span;
+use crate::err::Error;
+use crate::re::def_not;
+use crate::re::trace;
+use crate::re::Ctor;
+use crate::re::Extract;
+use crate::re::Handler;
+use crate::re::Regex;
+
+const SEED: u64 = 0x51_7c_c1_b7_27_22_0a_95;
+
+/// Compute the fxhash of the bytes.
+pub fn fxhash(bytes: &[u8]) -> u64 {
+    let mut hash = 0u64;
+    let mut chunks = bytes.chunks_exact(8);
+
+    for chunk in chunks.by_ref() {
+        let word = u64::from_le_bytes(chunk.try_into().unwrap());
+
+        hash = (hash.rotate_left(5) ^ word).wrapping_mul(SEED);
+    }
+    for byte in chunks.remainder() {
+        hash = (hash.rotate_left(5) ^ *byte as u64).wrapping_mul(SEED);
+    }
+    hash
+}
+
+///
+/// Match `P`, and compute the [`fxhash`] of the data covered by `P`.
+///
+/// # Ctor
+///
+/// It will return a tuple of the result of `P` and the hash value.
+///
+/// # Example
+///
+/// ```
+/// # use neure::prelude::*;
+/// #
+/// # fn main() -> color_eyre::Result<()> {
+/// #     color_eyre::install()?;
+///     let word = re::hashed(neu::ascii_alphabetic().repeat_one_more());
+///     let words = word.sep(" ");
+///     let ret = CharsCtx::new("foo bar foo").ctor(&words)?;
+///
+///     assert_eq!(ret[0].0, "foo");
+///     assert_eq!(ret[0].1, ret[2].1);
+///     assert_ne!(ret[0].1, ret[1].1);
+///     Ok(())
+/// # }
+/// ```
+#[derive(Default, Copy)]
+pub struct Hashed<C, P> {
+    pat: P,
+    marker: PhantomData<C>,
+}
+
+def_not!(Hashed<C, P>);
+
+impl<C, P> Debug for Hashed<C, P>
+where
+    P: Debug,
+{
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("Hashed").field("pat", &self.pat).finish()
+    }
+}
+
+impl<C, P> Clone for Hashed<C, P>
+where
+    P: Clone,
+{
+    fn clone(&self) -> Self {
+        Self {
+            pat: self.pat.clone(),
+            marker: self.marker,
+        }
+    }
+}
+
+impl<C, P> Hashed<C, P> {
+    pub fn new(pat: P) -> Self {
+        Self {
+            pat,
+            marker: PhantomData,
+        }
+    }
+
+    pub fn pat(&self) -> &P {
+        &self.pat
+    }
+
+    pub fn pat_mut(&mut self) -> &mut P {
+        &mut self.pat
+    }
+
+    pub fn set_pat(&mut self, pat: P) -> &mut Self {
+        self.pat = pat;
+        self
+    }
+}
+
+impl<'a, C, P, M, O, H, A> Ctor<'a, C, M, (O, u64), H, A> for Hashed<C, P>
+where
+    P: Ctor<'a, C, M, O, H, A>,
+    C: Context<'a> + Match<C> + 'a,
+    C::Orig: AsRef<[u8]>,
+    H: Handler<A, Out = M, Error = Error>,
+    A: Extract<'a, C, Span, Out<'a> = A, Error = Error>,
+{
+    #[inline(always)]
+    fn construct(&self, ctx: &mut C, func: &mut H) -> Result<(O, u64), Error> {
+        let mut g = CtxGuard::new(ctx);
+        let beg = g.beg();
+        let ret = trace!("hashed", beg @ "pat", self.pat.construct(g.ctx(), func));
+        let ret = ret.and_then(|ret| {
+            let len = g.end() - beg;
+            let data = g.ctx().orig_sub(beg, len)?;
+
+            Ok((ret, fxhash(data.as_ref())))
+        });
+
+        trace!("hashed", beg -> g.end(), ret.is_ok());
+        g.process_ret(ret)
+    }
+}
+
+impl<'a, C, P> Regex<C> for Hashed<C, P>
+where
+    P: Regex<C, Ret = Span>,
+    C: Context<'a> + Match<C>,
+{
+    type Ret = P::Ret;
+
+    #[inline(always)]
+    fn try_parse(&self, ctx: &mut C) -> Result<Self::Ret, Error> {
+        let mut g = CtxGuard::new(ctx);
+        let beg = g.beg();
+        let ret = trace!("hashed", beg @ "pat", g.try_mat(&self.pat));
+
+        trace!("hashed", beg => g.end(), g.process_ret(ret))
+    }
+}
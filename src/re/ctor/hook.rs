@@ -0,0 +1,155 @@
+use std::fmt::Debug;
+use std::marker::PhantomData;
+
+use crate::ctx::Context;
+use crate::ctx::CtxGuard;
+use crate::ctx::Match;
+use crate::ctx::Span;
+use crate::err::Error;
+use crate::re::def_not;
+use crate::re::trace;
+use crate::re::Ctor;
+use crate::re::Extract;
+use crate::re::Handler;
+use crate::re::Regex;
+
+///
+/// Match `P` and pass the result of `P` to the callback `F` if it succeeds.
+///
+/// Unlike [`tap_spans`](crate::re::ConstructOp::tap_spans), the callback only sees the value,
+/// it is intended for collecting statistics without changing the result.
+/// The callback is not undone when an outer combinator backtracks.
+/// Use [`Cell`](std::cell::Cell) or [`RefCell`](std::cell::RefCell) in `F` to record the state.
+///
+/// # Ctor
+///
+/// It will return the result of `P`.
+///
+/// # Regex
+///
+/// It will return the span of `P`, the callback will not be called.
+///
+/// # Example
+///
+/// ```
+/// # use std::cell::RefCell;
+/// # use std::collections::HashMap;
+/// #
+/// # use neure::prelude::*;
+/// #
+/// # fn main() -> color_eyre::Result<()> {
+/// #     color_eyre::install()?;
+///     let kinds = RefCell::new(HashMap::new());
+///     let count = |kind: &&'static str| *kinds.borrow_mut().entry(*kind).or_insert(0) += 1;
+///     let num = neu::digit(10).repeat_one_more().map(|_| Ok("num"));
+///     let id = neu::ascii_alphabetic().repeat_one_more().map(|_| Ok("id"));
+///     let node = num.or(id).on_success(count);
+///
+///     assert_eq!(CharsCtx::new("a 1 b 2 3").ctor(&node.sep(" "))?.len(), 5);
+///     assert_eq!(kinds.borrow()["num"], 3);
+///     assert_eq!(kinds.borrow()["id"], 2);
+///     Ok(())
+/// # }
+/// ```
+#[derive(Default, Copy)]
+pub struct OnSuccess<C, P, F> {
+    pat: P,
+    func: F,
+    marker: PhantomData<C>,
+}
+
+def_not!(OnSuccess<C, P, F>);
+
+impl<C, P, F> Debug for OnSuccess<C, P, F>
+where
+    P: Debug,
+{
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("OnSuccess").field("pat", &self.pat).finish()
+    }
+}
+
+impl<C, P, F> Clone for OnSuccess<C, P, F>
+where
+    P: Clone,
+    F: Clone,
+{
+    fn clone(&self) -> Self {
+        Self {
+            pat: self.pat.clone(),
+            func: self.func.clone(),
+            marker: self.marker,
+        }
+    }
+}
+
+impl<C, P, F> OnSuccess<C, P, F> {
+    pub fn new(pat: P, func: F) -> Self {
+        Self {
+            pat,
+            func,
+            marker: PhantomData,
+        }
+    }
+
+    pub fn pat(&self) -> &P {
+        &self.pat
+    }
+
+    pub fn pat_mut(&mut self) -> &mut P {
+        &mut self.pat
+    }
+
+    pub fn func(&self) -> &F {
+        &self.func
+    }
+
+    pub fn func_mut(&mut self) -> &mut F {
+        &mut self.func
+    }
+
+    pub fn set_pat(&mut self, pat: P) -> &mut Self {
+        self.pat = pat;
+        self
+    }
+
+    pub fn set_func(&mut self, func: F) -> &mut Self {
+        self.func = func;
+        self
+    }
+}
+
+impl<'a, C, P, F, M, O, H, A> Ctor<'a, C, M, O, H, A> for OnSuccess<C, P, F>
+where
+    P: Ctor<'a, C, M, O, H, A>,
+    F: Fn(&O),
+    C: Context<'a> + Match<C>,
+    H: Handler<A, Out = M, Error = Error>,
+    A: Extract<'a, C, Span, Out<'a> = A, Error = Error>,
+{
+    #[inline(always)]
+    fn construct(&self, ctx: &mut C, func: &mut H) -> Result<O, Error> {
+        let mut g = CtxGuard::new(ctx);
+        let beg = g.beg();
+        let ret = trace!("on_success", beg @ "pat", self.pat.construct(g.ctx(), func));
+
+        if let Ok(val) = &ret {
+            (self.func)(val);
+        }
+        trace!("on_success", beg -> g.end(), ret.is_ok());
+        g.process_ret(ret)
+    }
+}
+
+impl<'a, C, P, F> Regex<C> for OnSuccess<C, P, F>
+where
+    P: Regex<C, Ret = Span>,
+    C: Context<'a> + Match<C>,
+{
+    type Ret = P::Ret;
+
+    #[inline(always)]
+    fn try_parse(&self, ctx: &mut C) -> Result<Self::Ret, Error> {
+        ctx.try_mat(&self.pat)
+    }
+}
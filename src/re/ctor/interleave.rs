@@ -0,0 +1,213 @@
+use std::fmt::Debug;
+use std::marker::PhantomData;
+
+use crate::ctx::Context;
+use crate::ctx::CtxGuard;
+use crate::ctx::Match;
+use crate::ctx::Span;
+use crate::err::Error;
+use crate::re::def_not;
+use crate::re::trace;
+use crate::re::Ctor;
+use crate::re::Extract;
+use crate::re::Handler;
+use crate::re::Regex;
+
+///
+/// Match `P` and `T` alternately, `P`, `T`, `P`, `T` ... until one of them failed.
+///
+/// # Ctor
+///
+/// It will return a tuple of the results of `P` and the results of `T`.
+/// `P` must be matched at least once.
+///
+/// # Example
+///
+/// ```
+/// # use neure::prelude::*;
+/// #
+/// # fn main() -> color_eyre::Result<()> {
+/// #     color_eyre::install()?;
+///     let key = neu::ascii_alphabetic().repeat_one_more();
+///     let sep = "=".or(":");
+///     let path = key.interleave(sep);
+///
+///     assert_eq!(
+///         CharsCtx::new("a=b:c").ctor(&path)?,
+///         (vec!["a", "b", "c"], vec!["=", ":"])
+///     );
+///     Ok(())
+/// # }
+/// ```
+#[derive(Default, Copy)]
+pub struct Interleave<C, P, T> {
+    pat: P,
+    other: T,
+    marker: PhantomData<C>,
+}
+
+def_not!(Interleave<C, P, T>);
+
+impl<C, P, T> Debug for Interleave<C, P, T>
+where
+    P: Debug,
+    T: Debug,
+{
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("Interleave")
+            .field("pat", &self.pat)
+            .field("other", &self.other)
+            .finish()
+    }
+}
+
+impl<C, P, T> Clone for Interleave<C, P, T>
+where
+    P: Clone,
+    T: Clone,
+{
+    fn clone(&self) -> Self {
+        Self {
+            pat: self.pat.clone(),
+            other: self.other.clone(),
+            marker: self.marker,
+        }
+    }
+}
+
+impl<C, P, T> Interleave<C, P, T> {
+    pub fn new(pat: P, other: T) -> Self {
+        Self {
+            pat,
+            other,
+            marker: PhantomData,
+        }
+    }
+
+    pub fn pat(&self) -> &P {
+        &self.pat
+    }
+
+    pub fn pat_mut(&mut self) -> &mut P {
+        &mut self.pat
+    }
+
+    pub fn other(&self) -> &T {
+        &self.other
+    }
+
+    pub fn other_mut(&mut self) -> &mut T {
+        &mut self.other
+    }
+
+    pub fn set_pat(&mut self, pat: P) -> &mut Self {
+        self.pat = pat;
+        self
+    }
+
+    pub fn set_other(&mut self, other: T) -> &mut Self {
+        self.other = other;
+        self
+    }
+}
+
+impl<'a, C, P, T, M, O1, O2, H, A> Ctor<'a, C, M, (Vec<O1>, Vec<O2>), H, A> for Interleave<C, P, T>
+where
+    P: Ctor<'a, C, M, O1, H, A>,
+    T: Ctor<'a, C, M, O2, H, A>,
+    C: Context<'a> + Match<C>,
+    H: Handler<A, Out = M, Error = Error>,
+    A: Extract<'a, C, Span, Out<'a> = A, Error = Error>,
+{
+    #[inline(always)]
+    fn construct(&self, ctx: &mut C, func: &mut H) -> Result<(Vec<O1>, Vec<O2>), Error> {
+        let mut g = CtxGuard::new(ctx);
+        let mut fst = vec![];
+        let mut snd = vec![];
+        let beg = g.beg();
+
+        trace!("interleave", beg, ());
+        loop {
+            let start = g.end();
+
+            match g
+                .non_fatal(|ctx| trace!("interleave", beg @ "pat", self.pat.construct(ctx, func)))?
+            {
+                Some(ret) => fst.push(ret),
+                None => {
+                    g.ctx().set_offset(start);
+                    break;
+                }
+            }
+            let offset = g.end();
+
+            match g.non_fatal(
+                |ctx| trace!("interleave", beg @ "other", self.other.construct(ctx, func)),
+            )? {
+                Some(ret) => snd.push(ret),
+                None => {
+                    g.ctx().set_offset(offset);
+                    break;
+                }
+            }
+            if g.is_stuck(start) {
+                break;
+            }
+        }
+        let ret = if fst.is_empty() {
+            Err(Error::Interleave)
+        } else {
+            Ok((fst, snd))
+        };
+
+        trace!("interleave", beg -> g.end(), ret.is_ok());
+        g.process_ret(ret)
+    }
+}
+
+impl<'a, C, P, T> Regex<C> for Interleave<C, P, T>
+where
+    P: Regex<C, Ret = Span>,
+    T: Regex<C, Ret = Span>,
+    C: Context<'a> + Match<C>,
+{
+    type Ret = Span;
+
+    #[inline(always)]
+    fn try_parse(&self, ctx: &mut C) -> Result<Self::Ret, Error> {
+        let mut g = CtxGuard::new(ctx);
+        let mut cnt = 0;
+        let beg = g.beg();
+
+        trace!("interleave", beg, ());
+        loop {
+            let start = g.end();
+
+            if g.non_fatal(|ctx| trace!("interleave", beg @ "pat", ctx.try_mat(&self.pat)))?
+                .is_none()
+            {
+                g.ctx().set_offset(start);
+                break;
+            }
+            cnt += 1;
+            let offset = g.end();
+
+            if g.non_fatal(|ctx| trace!("interleave", beg @ "other", ctx.try_mat(&self.other)))?
+                .is_none()
+            {
+                g.ctx().set_offset(offset);
+                break;
+            }
+            if g.is_stuck(start) {
+                break;
+            }
+        }
+        let ret = if cnt == 0 {
+            Err(Error::Interleave)
+        } else {
+            Ok(Span::new(beg, g.end() - beg))
+        };
+
+        trace!("interleave", beg => g.end(), g.process_ret(ret))
+    }
+}
@@ -162,12 +162,6 @@ where
         let offset_l = g.end();
         let r_r = trace!("ltm", beg @ "right", g.reset().try_mat(&self.right));
         let offset_r = g.end();
-        let (off, ret) = if offset_l >= offset_r {
-            (offset_l, r_l)
-        } else {
-            (offset_r, r_r)
-        };
-
         trace_log!(
             "r`ltm`@{} -> {{l: offset = {}, ret = {:?}; r: offset = {}, ret = {:?}}}",
             beg,
@@ -176,6 +170,12 @@ where
             offset_r,
             r_r
         );
+        let (off, ret) = if offset_l >= offset_r {
+            (offset_l, r_l)
+        } else {
+            (offset_r, r_r)
+        };
+
         g.ctx().set_offset(off);
         trace!("ltm", beg => g.end(), g.process_ret(ret))
     }
@@ -2,11 +2,14 @@ use std::fmt::Debug;
 use std::marker::PhantomData;
 
 use crate::ctx::Context;
+use crate::ctx::CtxGuard;
 use crate::ctx::Match;
+use crate::ctx::Ret;
 use crate::ctx::Span;
 use crate::err::Error;
 use crate::map::MapSingle;
 use crate::re::def_not;
+use crate::re::trace;
 use crate::re::Ctor;
 use crate::re::Extract;
 use crate::re::Handler;
@@ -196,3 +199,277 @@ where
         ctx.try_mat(&self.pat)
     }
 }
+
+///
+/// Map each element of the result to another type, stop and return the error if any mapping failed.
+///
+/// # Ctor
+///
+/// Return a [`Vec`] contains the mapped values.
+/// The elements are mapped one by one after `P` matched, the first failed mapping fails the whole match
+/// and the remaining elements are not mapped.
+///
+/// # Example
+///
+/// ```
+/// # use neure::prelude::*;
+/// #
+/// # fn main() -> color_eyre::Result<()> {
+/// #     color_eyre::install()?;
+///     let hex = neu::ascii_hexdigit().repeat_one_more();
+///     let hex = hex.sep(",").map_each(map::from_str_radix::<u8>(16));
+///
+///     assert_eq!(CharsCtx::new("ff,1,0a").ctor(&hex)?, [0xff, 1, 0xa]);
+///     assert!(CharsCtx::new("ff,100").ctor(&hex).is_err());
+///     Ok(())
+/// # }
+/// ```
+#[derive(Default, Copy)]
+pub struct MapEach<C, P, F, O> {
+    pat: P,
+    mapper: F,
+    marker: PhantomData<(C, O)>,
+}
+
+def_not!(MapEach<C, P, F, O>);
+
+impl<C, P, F, O> Debug for MapEach<C, P, F, O>
+where
+    P: Debug,
+    F: Debug,
+{
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("MapEach")
+            .field("pat", &self.pat)
+            .field("mapper", &self.mapper)
+            .finish()
+    }
+}
+
+impl<C, P, F, O> Clone for MapEach<C, P, F, O>
+where
+    P: Clone,
+    F: Clone,
+{
+    fn clone(&self) -> Self {
+        Self {
+            pat: self.pat.clone(),
+            mapper: self.mapper.clone(),
+            marker: self.marker,
+        }
+    }
+}
+
+impl<C, P, F, O> MapEach<C, P, F, O> {
+    pub fn new(pat: P, func: F) -> Self {
+        Self {
+            pat,
+            mapper: func,
+            marker: PhantomData,
+        }
+    }
+
+    pub fn pat(&self) -> &P {
+        &self.pat
+    }
+
+    pub fn mapper(&self) -> &F {
+        &self.mapper
+    }
+
+    pub fn pat_mut(&mut self) -> &mut P {
+        &mut self.pat
+    }
+
+    pub fn mapper_mut(&mut self) -> &mut F {
+        &mut self.mapper
+    }
+
+    pub fn set_pat(&mut self, pat: P) -> &mut Self {
+        self.pat = pat;
+        self
+    }
+
+    pub fn set_mapper(&mut self, func: F) -> &mut Self {
+        self.mapper = func;
+        self
+    }
+}
+
+impl<'a, C, M, O, V, P, F, H, A> Ctor<'a, C, M, Vec<V>, H, A> for MapEach<C, P, F, O>
+where
+    O: IntoIterator,
+    P: Ctor<'a, C, M, O, H, A>,
+    F: MapSingle<O::Item, V>,
+    C: Context<'a> + Match<C>,
+    H: Handler<A, Out = M, Error = Error>,
+    A: Extract<'a, C, Span, Out<'a> = A, Error = Error>,
+{
+    #[inline(always)]
+    fn construct(&self, ctx: &mut C, func: &mut H) -> Result<Vec<V>, Error> {
+        self.pat
+            .construct(ctx, func)?
+            .into_iter()
+            .map(|v| self.mapper.map_to(v))
+            .collect()
+    }
+}
+
+impl<'a, C, P, F, O> Regex<C> for MapEach<C, P, F, O>
+where
+    P: Regex<C, Ret = Span>,
+    C: Context<'a> + Match<C>,
+{
+    type Ret = P::Ret;
+
+    #[inline(always)]
+    fn try_parse(&self, ctx: &mut C) -> Result<Self::Ret, Error> {
+        ctx.try_mat(&self.pat)
+    }
+}
+
+///
+/// Match `P`, map the result with `F` if it succeeds, otherwise map the error with `G`.
+///
+/// # Ctor
+///
+/// It will never fail, the offset is restored if `P` failed.
+///
+/// # Example
+///
+/// ```
+/// # use neure::prelude::*;
+/// #
+/// # fn main() -> color_eyre::Result<()> {
+/// #     color_eyre::install()?;
+///     let port = neu::digit(10).repeat_one_more().map(map::from_str::<u16>());
+///     let port = port.map_or_else(|v| v, |_| 8080);
+///
+///     assert_eq!(CharsCtx::new("443").ctor(&port)?, 443);
+///     assert_eq!(CharsCtx::new("http").ctor(&port)?, 8080);
+///     Ok(())
+/// # }
+/// ```
+#[derive(Default, Copy)]
+pub struct MapOrElse<C, P, F, G, O> {
+    pat: P,
+    on_ok: F,
+    on_err: G,
+    marker: PhantomData<(C, O)>,
+}
+
+def_not!(MapOrElse<C, P, F, G, O>);
+
+impl<C, P, F, G, O> Debug for MapOrElse<C, P, F, G, O>
+where
+    P: Debug,
+{
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("MapOrElse").field("pat", &self.pat).finish()
+    }
+}
+
+impl<C, P, F, G, O> Clone for MapOrElse<C, P, F, G, O>
+where
+    P: Clone,
+    F: Clone,
+    G: Clone,
+{
+    fn clone(&self) -> Self {
+        Self {
+            pat: self.pat.clone(),
+            on_ok: self.on_ok.clone(),
+            on_err: self.on_err.clone(),
+            marker: self.marker,
+        }
+    }
+}
+
+impl<C, P, F, G, O> MapOrElse<C, P, F, G, O> {
+    pub fn new(pat: P, on_ok: F, on_err: G) -> Self {
+        Self {
+            pat,
+            on_ok,
+            on_err,
+            marker: PhantomData,
+        }
+    }
+
+    pub fn pat(&self) -> &P {
+        &self.pat
+    }
+
+    pub fn on_ok(&self) -> &F {
+        &self.on_ok
+    }
+
+    pub fn on_err(&self) -> &G {
+        &self.on_err
+    }
+
+    pub fn pat_mut(&mut self) -> &mut P {
+        &mut self.pat
+    }
+
+    pub fn on_ok_mut(&mut self) -> &mut F {
+        &mut self.on_ok
+    }
+
+    pub fn on_err_mut(&mut self) -> &mut G {
+        &mut self.on_err
+    }
+
+    pub fn set_pat(&mut self, pat: P) -> &mut Self {
+        self.pat = pat;
+        self
+    }
+
+    pub fn set_on_ok(&mut self, on_ok: F) -> &mut Self {
+        self.on_ok = on_ok;
+        self
+    }
+
+    pub fn set_on_err(&mut self, on_err: G) -> &mut Self {
+        self.on_err = on_err;
+        self
+    }
+}
+
+impl<'a, C, M, O, V, P, F, G, H, A> Ctor<'a, C, M, V, H, A> for MapOrElse<C, P, F, G, O>
+where
+    P: Ctor<'a, C, M, O, H, A>,
+    F: Fn(O) -> V,
+    G: Fn(&Error) -> V,
+    C: Context<'a> + Match<C>,
+    H: Handler<A, Out = M, Error = Error>,
+    A: Extract<'a, C, Span, Out<'a> = A, Error = Error>,
+{
+    #[inline(always)]
+    fn construct(&self, ctx: &mut C, func: &mut H) -> Result<V, Error> {
+        let mut g = CtxGuard::new(ctx);
+        let beg = g.beg();
+        let ret = trace!("map_or_else", beg, self.pat.construct(g.ctx(), func));
+
+        trace!("map_or_else", beg -> g.end(), ret.is_ok());
+        Ok(match g.process_ret(ret) {
+            Ok(val) => (self.on_ok)(val),
+            Err(e) => (self.on_err)(&e),
+        })
+    }
+}
+
+impl<'a, C, P, F, G, O> Regex<C> for MapOrElse<C, P, F, G, O>
+where
+    P: Regex<C, Ret = Span>,
+    C: Context<'a> + Match<C>,
+{
+    type Ret = P::Ret;
+
+    #[inline(always)]
+    fn try_parse(&self, ctx: &mut C) -> Result<Self::Ret, Error> {
+        let beg = ctx.offset();
+        let ret = ctx.try_mat(&self.pat);
+
+        trace!("map_or_else", beg => ctx.offset(), Ok(ret.unwrap_or(<Span as Ret>::from_ctx(ctx, (0, 0)))))
+    }
+}
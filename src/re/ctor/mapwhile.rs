@@ -0,0 +1,156 @@
+use std::fmt::Debug;
+use std::marker::PhantomData;
+
+use crate::ctx::Context;
+use crate::ctx::CtxGuard;
+use crate::ctx::Match;
+use crate::ctx::Span;
+use crate::err::Error;
+use crate::re::trace;
+use crate::re::Ctor;
+use crate::re::Extract;
+use crate::re::Handler;
+
+///
+/// Repeatedly match the regex `P` and map the result using `F`, stop when `P` failed
+/// or `F` return `None`.
+///
+/// The element which mapped to `None` will be consumed, but nothing after it.
+///
+/// # Ctor
+///
+/// Return a type `V` that collects the mapped value of `F`.
+/// `MapWhile` will always succeed, the result may be empty.
+///
+/// # Example
+///
+/// ```
+/// # use neure::prelude::*;
+/// #
+/// # fn main() -> color_eyre::Result<()> {
+/// #     color_eyre::install()?;
+///     let byte = re::consume(1).map(|v: &[u8]| Ok(v[0]));
+///     let str = byte.map_while::<_, _, Vec<_>>(|v| (v != 0).then_some(v as char));
+///     let mut ctx = BytesCtx::new(b"abc\0def");
+///
+///     assert_eq!(ctx.ctor(&str)?, ['a', 'b', 'c']);
+///     assert_eq!(ctx.offset(), 4);
+///     Ok(())
+/// # }
+/// ```
+#[derive(Default, Copy)]
+pub struct MapWhile<C, P, F, O, V> {
+    pat: P,
+    func: F,
+    marker: PhantomData<(O, V, C)>,
+}
+
+impl<C, P, F, O, V> Debug for MapWhile<C, P, F, O, V>
+where
+    P: Debug,
+    F: Debug,
+{
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("MapWhile")
+            .field("pat", &self.pat)
+            .field("func", &self.func)
+            .finish()
+    }
+}
+
+impl<C, P, F, O, V> Clone for MapWhile<C, P, F, O, V>
+where
+    P: Clone,
+    F: Clone,
+{
+    fn clone(&self) -> Self {
+        Self {
+            pat: self.pat.clone(),
+            func: self.func.clone(),
+            marker: self.marker,
+        }
+    }
+}
+
+impl<C, P, F, O, V> MapWhile<C, P, F, O, V> {
+    pub fn new(pat: P, func: F) -> Self {
+        Self {
+            pat,
+            func,
+            marker: PhantomData,
+        }
+    }
+
+    pub fn pat(&self) -> &P {
+        &self.pat
+    }
+
+    pub fn pat_mut(&mut self) -> &mut P {
+        &mut self.pat
+    }
+
+    pub fn func(&self) -> &F {
+        &self.func
+    }
+
+    pub fn func_mut(&mut self) -> &mut F {
+        &mut self.func
+    }
+
+    pub fn set_pat(&mut self, pat: P) -> &mut Self {
+        self.pat = pat;
+        self
+    }
+
+    pub fn set_func(&mut self, func: F) -> &mut Self {
+        self.func = func;
+        self
+    }
+}
+
+impl<'a, C, P, F, M, O, T, V, H, A> Ctor<'a, C, M, V, H, A> for MapWhile<C, P, F, T, V>
+where
+    V: FromIterator<O>,
+    F: Fn(T) -> Option<O>,
+    P: Ctor<'a, C, M, T, H, A>,
+    C: Context<'a> + Match<C>,
+    H: Handler<A, Out = M, Error = Error>,
+    A: Extract<'a, C, Span, Out<'a> = A, Error = Error>,
+{
+    #[inline(always)]
+    fn construct(&self, ctx: &mut C, func: &mut H) -> Result<V, Error> {
+        let mut g = CtxGuard::new(ctx);
+        let mut end = false;
+        let mut fatal = None;
+        let beg = g.beg();
+        let val = trace!(
+            "map_while",
+            beg,
+            V::from_iter(std::iter::from_fn(|| {
+                let offset = g.end();
+
+                if end {
+                    return None;
+                }
+                match self.pat.construct(g.ctx(), func) {
+                    Ok(ret) => {
+                        end = g.is_stuck(offset);
+                        (self.func)(ret)
+                    }
+                    Err(e) => {
+                        g.ctx().set_offset(offset);
+                        fatal = e.is_fatal().then_some(e);
+                        None
+                    }
+                }
+            }))
+        );
+        let ret = match fatal {
+            Some(e) => Err(e),
+            None => Ok(val),
+        };
+
+        trace!("map_while", beg -> g.end(), ret.is_ok());
+        g.process_ret(ret)
+    }
+}
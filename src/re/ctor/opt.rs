@@ -103,11 +103,10 @@ where
         let beg = g.beg();
         let ret = trace!("or", beg @ "left", self.pat.construct(g.ctx(), func));
 
-        Ok(if let Ok(ret) = g.process_ret(ret) {
-            Some(ret)
-        } else {
-            None
-        })
+        match g.process_ret(ret) {
+            Err(e) if e.is_fatal() => Err(e),
+            ret => Ok(ret.ok()),
+        }
     }
 }
 
@@ -121,8 +120,131 @@ where
     #[inline(always)]
     fn try_parse(&self, ctx: &mut C) -> Result<Self::Ret, Error> {
         let beg = ctx.offset();
-        let ret = ctx.try_mat(&self.pat);
+        let ret = match ctx.try_mat(&self.pat) {
+            Err(e) if e.is_fatal() => Err(e),
+            ret => Ok(ret.unwrap_or(<Span as Ret>::from_ctx(ctx, (0, 0)))),
+        };
+
+        trace!("option", beg => ctx.offset(), ret)
+    }
+}
+
+///
+/// Match `P` and return the result, or return [`Default::default`] if the match failed.
+///
+/// # Ctor
+///
+/// If the regex `P` matches, return `T`; otherwise return `T::default()` and
+/// reset the offset of [`Context`].
+///
+/// # Example
+///
+/// ```
+/// # use neure::prelude::*;
+/// #
+/// # fn main() -> color_eyre::Result<()> {
+/// #     color_eyre::install()?;
+///     let name = neu::ascii_alphabetic().repeat_one_more();
+///     let count = neu::digit(10)
+///         .repeat_one_more()
+///         .map(map::from_str::<u32>())
+///         .or_default();
+///     let item = name.sep_once(":", count);
+///
+///     assert_eq!(CharsCtx::new("apple:12").ctor(&item)?, ("apple", 12));
+///     assert_eq!(CharsCtx::new("apple:").ctor(&item)?, ("apple", 0));
+///
+///     Ok(())
+/// # }
+/// ```
+#[derive(Default, Copy)]
+pub struct OrDefault<C, P> {
+    pat: P,
+    marker: PhantomData<C>,
+}
+
+def_not!(OrDefault<C, P>);
+
+impl<C, P> Debug for OrDefault<C, P>
+where
+    P: Debug,
+{
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("OrDefault").field("pat", &self.pat).finish()
+    }
+}
+
+impl<C, P> Clone for OrDefault<C, P>
+where
+    P: Clone,
+{
+    fn clone(&self) -> Self {
+        Self {
+            pat: self.pat.clone(),
+            marker: self.marker,
+        }
+    }
+}
+
+impl<C, P> OrDefault<C, P> {
+    pub fn new(pat: P) -> Self {
+        Self {
+            pat,
+            marker: PhantomData,
+        }
+    }
+
+    pub fn pat(&self) -> &P {
+        &self.pat
+    }
+
+    pub fn pat_mut(&mut self) -> &mut P {
+        &mut self.pat
+    }
+
+    pub fn set_pat(&mut self, pat: P) -> &mut Self {
+        self.pat = pat;
+        self
+    }
+}
+
+impl<'a, C, M, O, P, H, A> Ctor<'a, C, M, O, H, A> for OrDefault<C, P>
+where
+    O: Default,
+    P: Ctor<'a, C, M, O, H, A>,
+    C: Context<'a> + Match<C>,
+    H: Handler<A, Out = M, Error = Error>,
+    A: Extract<'a, C, Span, Out<'a> = A, Error = Error>,
+{
+    #[inline(always)]
+    fn construct(&self, ctx: &mut C, func: &mut H) -> Result<O, Error> {
+        let mut g = CtxGuard::new(ctx);
+        let beg = g.beg();
+        let ret = trace!("or_default", beg, self.pat.construct(g.ctx(), func));
+
+        trace!("or_default", beg -> g.end(), ret.is_ok());
+        match g.process_ret(ret) {
+            Err(e) if e.is_fatal() => Err(e),
+            ret => Ok(ret.unwrap_or_default()),
+        }
+    }
+}
+
+impl<'a, C, P> Regex<C> for OrDefault<C, P>
+where
+    P: Regex<C, Ret = Span>,
+    C: Context<'a> + Match<C>,
+{
+    type Ret = P::Ret;
+
+    #[inline(always)]
+    fn try_parse(&self, ctx: &mut C) -> Result<Self::Ret, Error> {
+        let beg = ctx.offset();
+        let ret = match ctx.try_mat(&self.pat) {
+            Err(e) if e.is_fatal() => Err(e),
+            ret => Ok(ret.unwrap_or(<Span as Ret>::from_ctx(ctx, (0, 0)))),
+        };
 
-        trace!("option", beg => ctx.offset(), Ok(ret.unwrap_or(<Span as Ret>::from_ctx(ctx, (0, 0)))))
+        trace!("or_default", beg => ctx.offset(), ret)
     }
 }
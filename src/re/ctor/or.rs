@@ -137,7 +137,7 @@ where
         let beg = g.beg();
         let mut ret = trace!("or", beg @ "left", self.left.construct(g.ctx(), func));
 
-        if ret.is_err() {
+        if ret.as_ref().is_err_and(|e| !e.is_fatal()) {
             ret = trace!("or", beg @ "right", self.right.construct(g.reset().ctx(), func));
         }
         trace!("or", beg -> g.end(), ret.is_ok());
@@ -157,10 +157,152 @@ where
     fn try_parse(&self, ctx: &mut C) -> Result<Self::Ret, Error> {
         let mut g = CtxGuard::new(ctx);
         let beg = g.beg();
-        let ret = trace!("or", beg @ "left", g.try_mat(&self.left).or_else(|_| {
+        let ret = trace!("or", beg @ "left", g.try_mat(&self.left).or_else(|e| {
+            if e.is_fatal() {
+                return Err(e);
+            }
             trace!("or", beg @ "right", g.reset().try_mat(&self.right))
         }));
 
         trace!("or", beg => g.end(), ret)
     }
 }
+
+///
+/// Try to match the alternatives `P` in order, return the result of the first one that matches.
+///
+/// # Ctor
+///
+/// Return the result of first matched alternative,
+/// or [`Error::NoAlternative`] with the count of alternatives tried if none of them matches.
+///
+/// # Example
+///
+/// ```
+/// # use neure::prelude::*;
+/// #
+/// # fn main() -> color_eyre::Result<()> {
+/// #     color_eyre::install()?;
+///     let kw = re::any_of(["let", "fn", "if"].map(|v| v.map(|v: &str| Ok(v.len()))));
+///     let mut ctx = CharsCtx::new("fn main");
+///
+///     assert_eq!(ctx.ctor(&kw)?, 2);
+///     assert!(matches!(
+///         ctx.ctor(&kw),
+///         Err(neure::err::Error::NoAlternative { tried: 3 })
+///     ));
+///     Ok(())
+/// # }
+/// ```
+#[derive(Default)]
+pub struct OrAny<C, P> {
+    pats: Vec<P>,
+    marker: PhantomData<C>,
+}
+
+def_not!(OrAny<C, P>);
+
+impl<C, P> Debug for OrAny<C, P>
+where
+    P: Debug,
+{
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("OrAny").field("pats", &self.pats).finish()
+    }
+}
+
+impl<C, P> Clone for OrAny<C, P>
+where
+    P: Clone,
+{
+    fn clone(&self) -> Self {
+        Self {
+            pats: self.pats.clone(),
+            marker: self.marker,
+        }
+    }
+}
+
+impl<C, P> OrAny<C, P> {
+    pub fn new(pats: Vec<P>) -> Self {
+        Self {
+            pats,
+            marker: PhantomData,
+        }
+    }
+
+    pub fn pats(&self) -> &[P] {
+        &self.pats
+    }
+
+    pub fn pats_mut(&mut self) -> &mut Vec<P> {
+        &mut self.pats
+    }
+
+    pub fn set_pats(&mut self, pats: Vec<P>) -> &mut Self {
+        self.pats = pats;
+        self
+    }
+}
+
+impl<'a, C, P, M, O, H, A> Ctor<'a, C, M, O, H, A> for OrAny<C, P>
+where
+    P: Ctor<'a, C, M, O, H, A>,
+    C: Context<'a> + Match<C>,
+    H: Handler<A, Out = M, Error = Error>,
+    A: Extract<'a, C, Span, Out<'a> = A, Error = Error>,
+{
+    #[inline(always)]
+    fn construct(&self, ctx: &mut C, func: &mut H) -> Result<O, Error> {
+        let mut g = CtxGuard::new(ctx);
+        let beg = g.beg();
+        let mut ret = Err(Error::NoAlternative {
+            tried: self.pats.len(),
+        });
+
+        for pat in self.pats.iter() {
+            match trace!("or_any", beg, pat.construct(g.reset().ctx(), func)) {
+                Ok(val) => ret = Ok(val),
+                Err(e) if e.is_fatal() => ret = Err(e),
+                Err(_) => continue,
+            }
+            break;
+        }
+        trace!("or_any", beg -> g.end(), ret.is_ok());
+        g.process_ret(ret)
+    }
+}
+
+impl<'a, C, P> Regex<C> for OrAny<C, P>
+where
+    P: Regex<C, Ret = Span>,
+    C: Context<'a> + Match<C>,
+{
+    type Ret = P::Ret;
+
+    #[inline(always)]
+    fn try_parse(&self, ctx: &mut C) -> Result<Self::Ret, Error> {
+        let mut g = CtxGuard::new(ctx);
+        let beg = g.beg();
+        let mut ret = Err(Error::NoAlternative {
+            tried: self.pats.len(),
+        });
+
+        for pat in self.pats.iter() {
+            match trace!("or_any", beg, g.reset().try_mat(pat)) {
+                Ok(span) => ret = Ok(span),
+                Err(e) if e.is_fatal() => ret = Err(e),
+                Err(_) => continue,
+            }
+            break;
+        }
+        trace!("or_any", beg => g.end(), g.process_ret(ret))
+    }
+}
+
+///
+/// Create an [`OrAny`] that tries the alternatives in order.
+///
+pub fn any_of<C, P>(pats: impl IntoIterator<Item = P>) -> OrAny<C, P> {
+    OrAny::new(pats.into_iter().collect())
+}
@@ -124,10 +124,9 @@ where
         let mut g = CtxGuard::new(ctx);
         let beg = g.beg();
         let ret = trace!("pad", beg @ "pat", self.pat.construct(g.ctx(), func));
+        let ret =
+            ret.and_then(|ret| trace!("pad", beg @ "tail", g.try_mat(&self.tail)).map(|_| ret));
 
-        if ret.is_ok() {
-            let _ = trace!("pad", beg @ "tail", g.try_mat(&self.tail)?);
-        }
         trace!("pad", beg -> g.end(), ret.is_ok());
         g.process_ret(ret)
     }
@@ -145,10 +144,11 @@ where
     fn try_parse(&self, ctx: &mut C) -> Result<Self::Ret, Error> {
         let mut g = CtxGuard::new(ctx);
         let beg = g.beg();
-        let mut ret = trace!("pad", beg @ "pat", g.try_mat(&self.pat)?);
+        let ret = trace!("pad", beg @ "pat", g.try_mat(&self.pat)).and_then(|mut ret| {
+            trace!("pad", beg @ "tail", g.try_mat(&self.tail)).map(|tail| *ret.add_assign(tail))
+        });
 
-        ret.add_assign(trace!("pad", beg @ "tail", g.try_mat(&self.tail)?));
-        trace!("pad", beg => g.end(), Ok(ret))
+        trace!("pad", beg => g.end(), g.process_ret(ret))
     }
 }
 
@@ -261,8 +261,8 @@ where
     fn construct(&self, ctx: &mut C, func: &mut H) -> Result<O, Error> {
         let mut g = CtxGuard::new(ctx);
         let beg = g.beg();
-        let _ = trace!("padded", beg @ "head", g.try_mat(&self.head)?);
-        let r = trace!("padded", beg @ "pat", self.pat.construct(g.ctx(), func));
+        let r = trace!("padded", beg @ "head", g.try_mat(&self.head));
+        let r = r.and_then(|_| trace!("padded", beg @ "pat", self.pat.construct(g.ctx(), func)));
 
         trace!("padded", beg -> g.end(), r.is_ok());
         g.process_ret(r)
@@ -281,9 +281,10 @@ where
     fn try_parse(&self, ctx: &mut C) -> Result<Self::Ret, Error> {
         let mut g = CtxGuard::new(ctx);
         let beg = g.beg();
-        let mut ret = trace!("padded", beg @ "head", g.try_mat(&self.head)?);
+        let ret = trace!("padded", beg @ "head", g.try_mat(&self.head)).and_then(|mut ret| {
+            trace!("padded", beg @ "pat", g.try_mat(&self.pat)).map(|pat| *ret.add_assign(pat))
+        });
 
-        ret.add_assign(trace!("padded", beg @ "pat", g.try_mat(&self.pat)?));
-        trace!("padded", beg => g.end(), Ok(ret))
+        trace!("padded", beg => g.end(), g.process_ret(ret))
     }
 }
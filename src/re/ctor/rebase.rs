@@ -0,0 +1,137 @@
+use std::fmt::Debug;
+use std::marker::PhantomData;
+
+use crate::ctx::Context;
+use crate::ctx::Match;
+use crate::ctx::Span;
+use crate::err::Error;
+use crate::re::def_not;
+use crate::re::Ctor;
+use crate::re::Extract;
+use crate::re::Handler;
+use crate::re::Regex;
+
+///
+/// Match `P` which returns a [`Span`], add `base` to the [`beg`](Span::beg) of it.
+///
+/// It is useful when parsing a sub-region of input with a new [`Context`],
+/// the spans can be rebased into the offsets of the original input.
+///
+/// # Ctor
+///
+/// It will return the rebased [`Span`].
+///
+/// # Regex
+///
+/// It will return the span of `P` as it is, the offsets of [`Context`] are not changed.
+///
+/// # Example
+///
+/// ```
+/// # use neure::prelude::*;
+/// #
+/// # fn main() -> color_eyre::Result<()> {
+/// #     color_eyre::install()?;
+///     let str = "key = value";
+///     let val = neu::ascii_alphabetic().repeat_one_more();
+///     let mut ctx = CharsCtx::new(&str[6..]);
+///     let span = ctx.ctor_span(&val.rebase(6))?;
+///
+///     assert_eq!(span, Span::new(6, 5));
+///     assert_eq!(&str[span.beg..span.beg + span.len], "value");
+///     Ok(())
+/// # }
+/// ```
+#[derive(Default, Copy)]
+pub struct Rebase<C, P> {
+    pat: P,
+    base: usize,
+    marker: PhantomData<C>,
+}
+
+def_not!(Rebase<C, P>);
+
+impl<C, P> Debug for Rebase<C, P>
+where
+    P: Debug,
+{
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("Rebase")
+            .field("pat", &self.pat)
+            .field("base", &self.base)
+            .finish()
+    }
+}
+
+impl<C, P> Clone for Rebase<C, P>
+where
+    P: Clone,
+{
+    fn clone(&self) -> Self {
+        Self {
+            pat: self.pat.clone(),
+            base: self.base,
+            marker: self.marker,
+        }
+    }
+}
+
+impl<C, P> Rebase<C, P> {
+    pub fn new(pat: P, base: usize) -> Self {
+        Self {
+            pat,
+            base,
+            marker: PhantomData,
+        }
+    }
+
+    pub fn pat(&self) -> &P {
+        &self.pat
+    }
+
+    pub fn pat_mut(&mut self) -> &mut P {
+        &mut self.pat
+    }
+
+    pub fn base(&self) -> usize {
+        self.base
+    }
+
+    pub fn set_pat(&mut self, pat: P) -> &mut Self {
+        self.pat = pat;
+        self
+    }
+
+    pub fn set_base(&mut self, base: usize) -> &mut Self {
+        self.base = base;
+        self
+    }
+}
+
+impl<'a, C, P, M, H, A> Ctor<'a, C, M, Span, H, A> for Rebase<C, P>
+where
+    P: Ctor<'a, C, M, Span, H, A>,
+    C: Context<'a> + Match<C>,
+    H: Handler<A, Out = M, Error = Error>,
+    A: Extract<'a, C, Span, Out<'a> = A, Error = Error>,
+{
+    #[inline(always)]
+    fn construct(&self, ctx: &mut C, func: &mut H) -> Result<Span, Error> {
+        let span = self.pat.construct(ctx, func)?;
+
+        Ok(Span::new(span.beg + self.base, span.len))
+    }
+}
+
+impl<'a, C, P> Regex<C> for Rebase<C, P>
+where
+    P: Regex<C, Ret = Span>,
+    C: Context<'a> + Match<C>,
+{
+    type Ret = P::Ret;
+
+    #[inline(always)]
+    fn try_parse(&self, ctx: &mut C) -> Result<Self::Ret, Error> {
+        ctx.try_mat(&self.pat)
+    }
+}
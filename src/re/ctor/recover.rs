@@ -0,0 +1,200 @@
+use std::fmt::Debug;
+use std::marker::PhantomData;
+
+use crate::ctx::Context;
+use crate::ctx::CtxGuard;
+use crate::ctx::ErrorSink;
+use crate::ctx::Match;
+use crate::ctx::Span;
+use crate::err::Error;
+use crate::neu::length_of;
+use crate::re::def_not;
+use crate::re::trace;
+use crate::re::Ctor;
+use crate::re::Extract;
+use crate::re::Handler;
+use crate::re::Regex;
+
+///
+/// Match `P`, if it failed, record the error into the [`ErrorSink`] of [`Context`],
+/// then skip the data until the regex `T` matched.
+///
+/// # Ctor
+///
+/// Return `Some(O)` if `P` matched, return `None` if `P` failed and recovered.
+/// It will return the error of `P` if `T` can not be found.
+///
+/// # Example
+///
+/// ```
+/// # use neure::prelude::*;
+/// #
+/// # fn main() -> color_eyre::Result<()> {
+/// #     color_eyre::install()?;
+///     let num = neu::digit(10).repeat_one_more().map(map::from_str::<i32>());
+///     let stmt = num.pad(";").recover_to(";");
+///     let stmts = stmt.repeat(1..);
+///     let mut ctx = CharsCtx::new("1;2;foo;4;").with_sink();
+///
+///     assert_eq!(ctx.ctor(&stmts)?, [Some(1), Some(2), None, Some(4)]);
+///     assert_eq!(ctx.errors().len(), 1);
+///     Ok(())
+/// # }
+/// ```
+#[derive(Default, Copy)]
+pub struct RecoverTo<C, P, T> {
+    pat: P,
+    sync: T,
+    marker: PhantomData<C>,
+}
+
+def_not!(RecoverTo<C, P, T>);
+
+impl<C, P, T> Debug for RecoverTo<C, P, T>
+where
+    P: Debug,
+    T: Debug,
+{
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("RecoverTo")
+            .field("pat", &self.pat)
+            .field("sync", &self.sync)
+            .finish()
+    }
+}
+
+impl<C, P, T> Clone for RecoverTo<C, P, T>
+where
+    P: Clone,
+    T: Clone,
+{
+    fn clone(&self) -> Self {
+        Self {
+            pat: self.pat.clone(),
+            sync: self.sync.clone(),
+            marker: self.marker,
+        }
+    }
+}
+
+impl<C, P, T> RecoverTo<C, P, T> {
+    pub fn new(pat: P, sync: T) -> Self {
+        Self {
+            pat,
+            sync,
+            marker: PhantomData,
+        }
+    }
+
+    pub fn pat(&self) -> &P {
+        &self.pat
+    }
+
+    pub fn pat_mut(&mut self) -> &mut P {
+        &mut self.pat
+    }
+
+    pub fn sync(&self) -> &T {
+        &self.sync
+    }
+
+    pub fn sync_mut(&mut self) -> &mut T {
+        &mut self.sync
+    }
+
+    pub fn set_pat(&mut self, pat: P) -> &mut Self {
+        self.pat = pat;
+        self
+    }
+
+    pub fn set_sync(&mut self, sync: T) -> &mut Self {
+        self.sync = sync;
+        self
+    }
+}
+
+/// Skip the items until `sync` matched, return the span of `sync`.
+fn skip_to<'a, C, T>(ctx: &mut C, sync: &T) -> Result<Span, Error>
+where
+    T: Regex<C, Ret = Span>,
+    C: Context<'a> + Match<C> + 'a,
+{
+    loop {
+        let offset = ctx.offset();
+
+        if let Ok(ret) = ctx.try_mat(sync) {
+            return Ok(ret);
+        }
+        ctx.set_offset(offset);
+
+        let mut iter = ctx.peek()?;
+
+        match iter.next() {
+            Some((beg, _)) => {
+                let len = length_of(beg, ctx, iter.next().map(|v| v.0));
+
+                ctx.inc(len);
+            }
+            None => return Err(Error::End),
+        }
+    }
+}
+
+impl<'a, C, P, T, M, O, H, A> Ctor<'a, C, M, Option<O>, H, A> for RecoverTo<C, P, T>
+where
+    P: Ctor<'a, C, M, O, H, A>,
+    T: Regex<C, Ret = Span>,
+    C: Context<'a> + Match<C> + ErrorSink + 'a,
+    H: Handler<A, Out = M, Error = Error>,
+    A: Extract<'a, C, Span, Out<'a> = A, Error = Error>,
+{
+    #[inline(always)]
+    fn construct(&self, ctx: &mut C, func: &mut H) -> Result<Option<O>, Error> {
+        let mut g = CtxGuard::new(ctx);
+        let beg = g.beg();
+        let ret = trace!("recover_to", beg @ "pat", self.pat.construct(g.ctx(), func));
+        let ret = match ret {
+            Ok(ret) => Ok(Some(ret)),
+            Err(e) => {
+                let sync = trace!("recover_to", beg @ "sync", skip_to(g.reset().ctx(), &self.sync));
+
+                match sync {
+                    Ok(_) => g.ctx().push_error(e).map(|_| None),
+                    Err(_) => Err(e),
+                }
+            }
+        };
+
+        trace!("recover_to", beg -> g.end(), ret.is_ok());
+        g.process_ret(ret)
+    }
+}
+
+impl<'a, C, P, T> Regex<C> for RecoverTo<C, P, T>
+where
+    P: Regex<C, Ret = Span>,
+    T: Regex<C, Ret = Span>,
+    C: Context<'a> + Match<C> + ErrorSink + 'a,
+{
+    type Ret = P::Ret;
+
+    #[inline(always)]
+    fn try_parse(&self, ctx: &mut C) -> Result<Self::Ret, Error> {
+        let mut g = CtxGuard::new(ctx);
+        let beg = g.beg();
+        let ret = trace!("recover_to", beg @ "pat", g.try_mat(&self.pat));
+        let ret = match ret {
+            Ok(ret) => Ok(ret),
+            Err(e) => {
+                let sync = trace!("recover_to", beg @ "sync", skip_to(g.reset().ctx(), &self.sync));
+
+                match sync {
+                    Ok(_) => g.ctx().push_error(e).map(|_| Span::new(beg, g.end() - beg)),
+                    Err(_) => Err(e),
+                }
+            }
+        };
+
+        trace!("recover_to", beg => g.end(), g.process_ret(ret))
+    }
+}
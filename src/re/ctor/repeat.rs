@@ -10,6 +10,7 @@ use crate::ctx::Span;
 use crate::err::Error;
 use crate::neu::CRange;
 use crate::re::def_not;
+use crate::re::trace;
 use crate::re::trace_v;
 use crate::re::Ctor;
 use crate::re::Extract;
@@ -22,6 +23,9 @@ use crate::re::Regex;
 /// # Ctor
 ///
 /// It will return a [`Vec`] of `P`'s match results.
+/// It will stop and return the error if `P` failed with a [`fatal`](Error::is_fatal) error.
+/// With the progress guard enabled, it will return [`Error::NoProgress`]
+/// if an iteration of `P` matched nothing.
 ///
 /// # Example
 ///
@@ -43,6 +47,7 @@ pub struct Repeat<C, P> {
     pat: P,
     range: CRange<usize>,
     capacity: usize,
+    guard: bool,
     marker: PhantomData<C>,
 }
 
@@ -57,6 +62,7 @@ where
             .field("pat", &self.pat)
             .field("range", &self.range)
             .field("capacity", &self.capacity)
+            .field("guard", &self.guard)
             .finish()
     }
 }
@@ -70,6 +76,7 @@ where
             pat: self.pat.clone(),
             range: self.range,
             capacity: self.capacity,
+            guard: self.guard,
             marker: self.marker,
         }
     }
@@ -84,6 +91,7 @@ impl<C, P> Repeat<C, P> {
             pat,
             range,
             capacity,
+            guard: false,
             marker: PhantomData,
         }
     }
@@ -109,6 +117,10 @@ impl<C, P> Repeat<C, P> {
         self.capacity
     }
 
+    pub fn guard(&self) -> bool {
+        self.guard
+    }
+
     pub fn pat_mut(&mut self) -> &mut P {
         &mut self.pat
     }
@@ -128,6 +140,11 @@ impl<C, P> Repeat<C, P> {
         self
     }
 
+    pub fn set_guard(&mut self, guard: bool) -> &mut Self {
+        self.guard = guard;
+        self
+    }
+
     pub fn with_pat(mut self, pat: P) -> Self {
         self.pat = pat;
         self
@@ -143,12 +160,23 @@ impl<C, P> Repeat<C, P> {
         self
     }
 
+    /// Return [`Error::NoProgress`] instead of looping if an iteration matched nothing.
+    pub fn with_guard(mut self, guard: bool) -> Self {
+        self.guard = guard;
+        self
+    }
+
     fn is_contain(&self, count: usize) -> bool {
-        match std::ops::RangeBounds::end_bound(&self.range) {
-            std::ops::Bound::Included(max) => count < *max,
-            std::ops::Bound::Excluded(max) => count < max.saturating_sub(1),
-            std::ops::Bound::Unbounded => true,
-        }
+        is_contain(&self.range, count)
+    }
+}
+
+/// Return true if one more match can be accepted after `count` matches.
+fn is_contain(range: &CRange<usize>, count: usize) -> bool {
+    match std::ops::RangeBounds::end_bound(range) {
+        std::ops::Bound::Included(max) => count < *max,
+        std::ops::Bound::Excluded(max) => count < max.saturating_sub(1),
+        std::ops::Bound::Unbounded => true,
     }
 }
 
@@ -165,23 +193,32 @@ where
         let mut cnt = 0;
         let mut res = Vec::with_capacity(self.capacity);
         let mut ret = Err(Error::RegexRepeat);
+        let mut fatal = None;
         let beg = g.beg();
 
         trace_v!("repeat", self.range, beg, ());
         while self.is_contain(cnt) {
+            let offset = g.end();
             let ret = self.pat.construct(g.ctx(), handler);
 
             match ret {
                 Ok(ret) => {
                     res.push(ret);
                     cnt += 1;
+                    if self.guard && g.is_stuck(offset) {
+                        fatal = Some(Error::NoProgress { at: offset });
+                        break;
+                    }
                 }
-                Err(_) => {
+                Err(e) => {
+                    fatal = e.is_fatal().then_some(e);
                     break;
                 }
             }
         }
-        if std::ops::RangeBounds::contains(&self.range, &cnt) {
+        if let Some(e) = fatal {
+            ret = Err(e);
+        } else if std::ops::RangeBounds::contains(&self.range, &cnt) {
             ret = Ok(res);
         }
         trace_v!("repeat", self.range, beg -> g.end(), ret.is_ok(), cnt);
@@ -202,23 +239,788 @@ where
         let mut cnt = 0;
         let mut span = <Span as Ret>::from_ctx(g.ctx(), (0, 0));
         let mut ret = Err(Error::RegexRepeat);
+        let mut fatal = None;
         let beg = g.beg();
 
         trace_v!("repeat", self.range, beg, ());
         while self.is_contain(cnt) {
+            let offset = g.end();
+
             match g.ctx().try_mat(&self.pat) {
                 Ok(ret) => {
                     span.add_assign(ret);
                     cnt += 1;
+                    if self.guard && g.is_stuck(offset) {
+                        fatal = Some(Error::NoProgress { at: offset });
+                        break;
+                    }
                 }
-                Err(_) => {
+                Err(e) => {
+                    fatal = e.is_fatal().then_some(e);
                     break;
                 }
             }
         }
-        if std::ops::RangeBounds::contains(&self.range, &cnt) {
+        if let Some(e) = fatal {
+            ret = Err(e);
+        } else if std::ops::RangeBounds::contains(&self.range, &cnt) {
             ret = Ok(span);
         }
         trace_v!("repeat", self.range, beg => g.end(), g.process_ret(ret), cnt)
     }
 }
+
+///
+/// Repeatedly match `P` which returns [`char`], and the number of matches must meet the given range.
+///
+/// # Ctor
+///
+/// It will push the chars into a [`String`] directly, without collecting a [`Vec`] first.
+/// It will stop and return the error if `P` failed with a [`fatal`](Error::is_fatal) error.
+///
+/// # Example
+///
+/// ```
+/// # use neure::prelude::*;
+/// #
+/// # fn main() -> color_eyre::Result<()> {
+/// #     color_eyre::install()?;
+///     let escape = "\\n".map(|_| Ok('\n')).or("\\t".map(|_| Ok('\t')));
+///     let char = neu::word().repeat_one().map(|v: &str| Ok(v.chars().next().unwrap()));
+///     let text = escape.or(char).repeat_string(1..);
+///     let mut ctx = CharsCtx::new("a\\tb\\n");
+///
+///     assert_eq!(ctx.ctor(&text)?, "a\tb\n");
+///     Ok(())
+/// # }
+/// ```
+#[derive(Copy)]
+pub struct RepeatString<C, P> {
+    pat: P,
+    range: CRange<usize>,
+    marker: PhantomData<C>,
+}
+
+def_not!(RepeatString<C, P>);
+
+impl<C, P> Debug for RepeatString<C, P>
+where
+    P: Debug,
+{
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("RepeatString")
+            .field("pat", &self.pat)
+            .field("range", &self.range)
+            .finish()
+    }
+}
+
+impl<C, P> Clone for RepeatString<C, P>
+where
+    P: Clone,
+{
+    fn clone(&self) -> Self {
+        Self {
+            pat: self.pat.clone(),
+            range: self.range,
+            marker: self.marker,
+        }
+    }
+}
+
+impl<C, P> RepeatString<C, P> {
+    pub fn new(pat: P, range: impl Into<CRange<usize>>) -> Self {
+        Self {
+            pat,
+            range: range.into(),
+            marker: PhantomData,
+        }
+    }
+
+    pub fn pat(&self) -> &P {
+        &self.pat
+    }
+
+    pub fn range(&self) -> &CRange<usize> {
+        &self.range
+    }
+
+    pub fn pat_mut(&mut self) -> &mut P {
+        &mut self.pat
+    }
+
+    pub fn set_pat(&mut self, pat: P) -> &mut Self {
+        self.pat = pat;
+        self
+    }
+
+    pub fn set_range(&mut self, range: impl Into<CRange<usize>>) -> &mut Self {
+        self.range = range.into();
+        self
+    }
+}
+
+impl<'a, C, P, M, H, A> Ctor<'a, C, M, String, H, A> for RepeatString<C, P>
+where
+    P: Ctor<'a, C, M, char, H, A>,
+    C: Context<'a> + Match<C>,
+    H: Handler<A, Out = M, Error = Error>,
+    A: Extract<'a, C, Span, Out<'a> = A, Error = Error>,
+{
+    #[inline(always)]
+    fn construct(&self, ctx: &mut C, handler: &mut H) -> Result<String, Error> {
+        let mut g = CtxGuard::new(ctx);
+        let mut cnt = 0;
+        let mut res = String::new();
+        let mut ret = Err(Error::RegexRepeat);
+        let mut fatal = None;
+        let beg = g.beg();
+
+        trace_v!("repeat_string", self.range, beg, ());
+        while is_contain(&self.range, cnt) {
+            let offset = g.end();
+
+            match self.pat.construct(g.ctx(), handler) {
+                Ok(ch) => {
+                    res.push(ch);
+                    cnt += 1;
+                    if g.is_stuck(offset) {
+                        break;
+                    }
+                }
+                Err(e) => {
+                    fatal = e.is_fatal().then_some(e);
+                    break;
+                }
+            }
+        }
+        if let Some(e) = fatal {
+            ret = Err(e);
+        } else if std::ops::RangeBounds::contains(&self.range, &cnt) {
+            ret = Ok(res);
+        }
+        trace_v!("repeat_string", self.range, beg -> g.end(), ret.is_ok(), cnt);
+        g.process_ret(ret)
+    }
+}
+
+impl<'a, C, P> Regex<C> for RepeatString<C, P>
+where
+    P: Regex<C, Ret = Span>,
+    C: Context<'a> + Match<C>,
+{
+    type Ret = Span;
+
+    #[inline(always)]
+    fn try_parse(&self, ctx: &mut C) -> Result<Self::Ret, Error> {
+        let mut g = CtxGuard::new(ctx);
+        let mut cnt = 0;
+        let mut span = <Span as Ret>::from_ctx(g.ctx(), (0, 0));
+        let mut ret = Err(Error::RegexRepeat);
+        let mut fatal = None;
+        let beg = g.beg();
+
+        trace_v!("repeat_string", self.range, beg, ());
+        while is_contain(&self.range, cnt) {
+            let offset = g.end();
+
+            match g.ctx().try_mat(&self.pat) {
+                Ok(ret) => {
+                    span.add_assign(ret);
+                    cnt += 1;
+                    if g.is_stuck(offset) {
+                        break;
+                    }
+                }
+                Err(e) => {
+                    fatal = e.is_fatal().then_some(e);
+                    break;
+                }
+            }
+        }
+        if let Some(e) = fatal {
+            ret = Err(e);
+        } else if std::ops::RangeBounds::contains(&self.range, &cnt) {
+            ret = Ok(span);
+        }
+        trace_v!("repeat_string", self.range, beg => g.end(), g.process_ret(ret), cnt)
+    }
+}
+
+///
+/// Repeatedly match `P` as few times as possible, stop as soon as the terminator `T` matched,
+/// like the non-greedy `*?` or `+?` of regex.
+///
+/// The terminator is tried before each match of `P`, only when the count meets the given range.
+///
+/// # Ctor
+///
+/// It will return a vector of the results of `P`, the terminator is consumed.
+/// It will return [`Error::RepeatLazy`] if `P` failed or the count exceeded the range
+/// before the terminator matched.
+///
+/// # Example
+///
+/// ```
+/// # use neure::prelude::*;
+/// #
+/// # fn main() -> color_eyre::Result<()> {
+/// #     color_eyre::install()?;
+///     let any = neu::any().repeat_one();
+///     let comment = "/*".then(any.repeat_lazy(0.., "*/"));
+///     let mut ctx = CharsCtx::new("/* a */ b /* c */");
+///
+///     assert_eq!(ctx.ctor(&comment)?.1, [" ", "a", " "]);
+///     assert_eq!(ctx.offset(), 7);
+///     Ok(())
+/// # }
+/// ```
+#[derive(Copy)]
+pub struct RepeatLazy<C, P, T> {
+    pat: P,
+    term: T,
+    range: CRange<usize>,
+    marker: PhantomData<C>,
+}
+
+def_not!(RepeatLazy<C, P, T>);
+
+impl<C, P, T> Debug for RepeatLazy<C, P, T>
+where
+    P: Debug,
+    T: Debug,
+{
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("RepeatLazy")
+            .field("pat", &self.pat)
+            .field("term", &self.term)
+            .field("range", &self.range)
+            .finish()
+    }
+}
+
+impl<C, P, T> Clone for RepeatLazy<C, P, T>
+where
+    P: Clone,
+    T: Clone,
+{
+    fn clone(&self) -> Self {
+        Self {
+            pat: self.pat.clone(),
+            term: self.term.clone(),
+            range: self.range,
+            marker: self.marker,
+        }
+    }
+}
+
+impl<C, P, T> RepeatLazy<C, P, T> {
+    pub fn new(pat: P, range: impl Into<CRange<usize>>, term: T) -> Self {
+        Self {
+            pat,
+            term,
+            range: range.into(),
+            marker: PhantomData,
+        }
+    }
+
+    pub fn pat(&self) -> &P {
+        &self.pat
+    }
+
+    pub fn pat_mut(&mut self) -> &mut P {
+        &mut self.pat
+    }
+
+    pub fn term(&self) -> &T {
+        &self.term
+    }
+
+    pub fn term_mut(&mut self) -> &mut T {
+        &mut self.term
+    }
+
+    pub fn range(&self) -> &CRange<usize> {
+        &self.range
+    }
+
+    pub fn set_pat(&mut self, pat: P) -> &mut Self {
+        self.pat = pat;
+        self
+    }
+
+    pub fn set_term(&mut self, term: T) -> &mut Self {
+        self.term = term;
+        self
+    }
+
+    pub fn set_range(&mut self, range: impl Into<CRange<usize>>) -> &mut Self {
+        self.range = range.into();
+        self
+    }
+}
+
+impl<'a, C, P, T> RepeatLazy<C, P, T>
+where
+    T: Regex<C, Ret = Span>,
+    C: Context<'a> + Match<C>,
+{
+    /// Return true if the count meets the range and the terminator matched.
+    fn is_term(&self, ctx: &mut C, cnt: usize) -> bool {
+        std::ops::RangeBounds::contains(&self.range, &cnt) && ctx.try_mat(&self.term).is_ok()
+    }
+}
+
+impl<'a, C, P, T, M, O, H, A> Ctor<'a, C, M, Vec<O>, H, A> for RepeatLazy<C, P, T>
+where
+    P: Ctor<'a, C, M, O, H, A>,
+    T: Regex<C, Ret = Span>,
+    C: Context<'a> + Match<C>,
+    H: Handler<A, Out = M, Error = Error>,
+    A: Extract<'a, C, Span, Out<'a> = A, Error = Error>,
+{
+    #[inline(always)]
+    fn construct(&self, ctx: &mut C, handler: &mut H) -> Result<Vec<O>, Error> {
+        let mut g = CtxGuard::new(ctx);
+        let mut res = vec![];
+        let mut ret = Err(Error::RepeatLazy);
+        let beg = g.beg();
+
+        trace_v!("repeat_lazy", self.range, beg, ());
+        loop {
+            let offset = g.end();
+
+            if self.is_term(g.ctx(), res.len()) {
+                ret = Ok(());
+                break;
+            }
+            if !is_contain(&self.range, res.len()) {
+                break;
+            }
+            match self.pat.construct(g.ctx(), handler) {
+                Ok(val) => {
+                    res.push(val);
+                    if g.is_stuck(offset) {
+                        break;
+                    }
+                }
+                Err(e) => {
+                    if e.is_fatal() {
+                        ret = Err(e);
+                    }
+                    break;
+                }
+            }
+        }
+        trace_v!("repeat_lazy", self.range, beg -> g.end(), ret.is_ok(), res.len());
+        g.process_ret(ret.map(|_| res))
+    }
+}
+
+impl<'a, C, P, T> Regex<C> for RepeatLazy<C, P, T>
+where
+    P: Regex<C, Ret = Span>,
+    T: Regex<C, Ret = Span>,
+    C: Context<'a> + Match<C>,
+{
+    type Ret = Span;
+
+    #[inline(always)]
+    fn try_parse(&self, ctx: &mut C) -> Result<Self::Ret, Error> {
+        let mut g = CtxGuard::new(ctx);
+        let mut cnt = 0;
+        let mut ret = Err(Error::RepeatLazy);
+        let beg = g.beg();
+
+        trace_v!("repeat_lazy", self.range, beg, ());
+        loop {
+            let offset = g.end();
+
+            if self.is_term(g.ctx(), cnt) {
+                ret = Ok(Span::new(beg, g.end() - beg));
+                break;
+            }
+            if !is_contain(&self.range, cnt) {
+                break;
+            }
+            match g.ctx().try_mat(&self.pat) {
+                Ok(_) => {
+                    cnt += 1;
+                    if g.is_stuck(offset) {
+                        break;
+                    }
+                }
+                Err(e) => {
+                    if e.is_fatal() {
+                        ret = Err(e);
+                    }
+                    break;
+                }
+            }
+        }
+        trace_v!("repeat_lazy", self.range, beg => g.end(), g.process_ret(ret), cnt)
+    }
+}
+
+///
+/// Repeatedly match regex `P` as many times as possible, only for advancing the offset.
+///
+/// # Ctor
+///
+/// It will return `()`, the handler will not be invoked and nothing will be allocated.
+/// `P` must be matched at least `min` times, the default is 0.
+/// It will stop and return the error if `P` failed with a [`fatal`](Error::is_fatal) error.
+///
+/// # Example
+///
+/// ```
+/// # use neure::prelude::*;
+/// #
+/// # fn main() -> color_eyre::Result<()> {
+/// #     color_eyre::install()?;
+///     let comment = "#".then(neu::not('\n').repeat_zero_more()).then("\n");
+///     let blank = neu::whitespace().repeat_one_more().or(comment);
+///     let key = neu::ascii_alphabetic().repeat_one_more();
+///     let mut ctx = CharsCtx::new("# comment\n  \n# more\nkey");
+///
+///     assert_eq!(ctx.ctor(&blank.skip_many())?, ());
+///     assert_eq!(ctx.ctor(&key)?, "key");
+///     Ok(())
+/// # }
+/// ```
+#[derive(Default, Copy)]
+pub struct SkipMany<C, P> {
+    pat: P,
+    min: usize,
+    marker: PhantomData<C>,
+}
+
+def_not!(SkipMany<C, P>);
+
+impl<C, P> Debug for SkipMany<C, P>
+where
+    P: Debug,
+{
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("SkipMany")
+            .field("pat", &self.pat)
+            .field("min", &self.min)
+            .finish()
+    }
+}
+
+impl<C, P> Clone for SkipMany<C, P>
+where
+    P: Clone,
+{
+    fn clone(&self) -> Self {
+        Self {
+            pat: self.pat.clone(),
+            min: self.min,
+            marker: self.marker,
+        }
+    }
+}
+
+impl<C, P> SkipMany<C, P> {
+    pub fn new(pat: P) -> Self {
+        Self {
+            pat,
+            min: 0,
+            marker: PhantomData,
+        }
+    }
+
+    pub fn pat(&self) -> &P {
+        &self.pat
+    }
+
+    pub fn pat_mut(&mut self) -> &mut P {
+        &mut self.pat
+    }
+
+    pub fn min(&self) -> usize {
+        self.min
+    }
+
+    pub fn set_pat(&mut self, pat: P) -> &mut Self {
+        self.pat = pat;
+        self
+    }
+
+    pub fn set_min(&mut self, min: usize) -> &mut Self {
+        self.min = min;
+        self
+    }
+
+    pub fn at_least(mut self, min: usize) -> Self {
+        self.min = min;
+        self
+    }
+}
+
+impl<'a, C, P, M, H, A> Ctor<'a, C, M, (), H, A> for SkipMany<C, P>
+where
+    P: Regex<C, Ret = Span>,
+    C: Context<'a> + Match<C>,
+    H: Handler<A, Out = M, Error = Error>,
+    A: Extract<'a, C, Span, Out<'a> = A, Error = Error>,
+{
+    #[inline(always)]
+    fn construct(&self, ctx: &mut C, _: &mut H) -> Result<(), Error> {
+        ctx.try_mat(self).map(|_| ())
+    }
+}
+
+impl<'a, C, P> Regex<C> for SkipMany<C, P>
+where
+    P: Regex<C, Ret = Span>,
+    C: Context<'a> + Match<C>,
+{
+    type Ret = Span;
+
+    #[inline(always)]
+    fn try_parse(&self, ctx: &mut C) -> Result<Self::Ret, Error> {
+        let mut g = CtxGuard::new(ctx);
+        let mut cnt = 0;
+        let mut ret = Err(Error::RegexRepeat);
+        let mut fatal = None;
+        let beg = g.beg();
+
+        trace!("skip_many", beg, ());
+        loop {
+            let offset = g.end();
+
+            match g.ctx().try_mat(&self.pat) {
+                Ok(_) => {
+                    cnt += 1;
+                    if g.is_stuck(offset) {
+                        break;
+                    }
+                }
+                Err(e) => {
+                    fatal = e.is_fatal().then_some(e);
+                    break;
+                }
+            }
+        }
+        if let Some(e) = fatal {
+            ret = Err(e);
+        } else if cnt >= self.min {
+            ret = Ok(Span::new(beg, g.end() - beg));
+        }
+        trace!("skip_many", beg => g.end(), g.process_ret(ret))
+    }
+}
+
+///
+/// Repeatedly match regex `P` while the condition `F` returns true,
+/// the condition is checked against the [`Context`] before each iteration.
+///
+/// # Ctor
+///
+/// It will return a [`Vec`] of `P`'s match results, stop if `F` returns false or `P` failed.
+/// `P` must be matched at least `min` times, the default is 0.
+///
+/// # Example
+///
+/// ```
+/// # use neure::prelude::*;
+/// #
+/// # fn main() -> color_eyre::Result<()> {
+/// #     color_eyre::install()?;
+///     let item = neu::ascii_alphabetic().repeat_one();
+///     // stop before the `_`
+///     let items = re::repeat_while(|ctx: &CharsCtx| Ok(ctx.current() != Some('_')), item.or("_"));
+///     let mut ctx = CharsCtx::new("ab_cd");
+///
+///     assert_eq!(ctx.ctor(&items)?, ["a", "b"]);
+///     assert_eq!(ctx.offset(), 2);
+///     Ok(())
+/// # }
+/// ```
+#[derive(Default, Copy)]
+pub struct RepeatWhile<C, P, F> {
+    pat: P,
+    pred: F,
+    min: usize,
+    marker: PhantomData<C>,
+}
+
+def_not!(RepeatWhile<C, P, F>);
+
+impl<C, P, F> Debug for RepeatWhile<C, P, F>
+where
+    P: Debug,
+{
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("RepeatWhile")
+            .field("pat", &self.pat)
+            .field("min", &self.min)
+            .finish()
+    }
+}
+
+impl<C, P, F> Clone for RepeatWhile<C, P, F>
+where
+    P: Clone,
+    F: Clone,
+{
+    fn clone(&self) -> Self {
+        Self {
+            pat: self.pat.clone(),
+            pred: self.pred.clone(),
+            min: self.min,
+            marker: self.marker,
+        }
+    }
+}
+
+impl<C, P, F> RepeatWhile<C, P, F> {
+    pub fn new(pat: P, pred: F) -> Self {
+        Self {
+            pat,
+            pred,
+            min: 0,
+            marker: PhantomData,
+        }
+    }
+
+    pub fn pat(&self) -> &P {
+        &self.pat
+    }
+
+    pub fn pat_mut(&mut self) -> &mut P {
+        &mut self.pat
+    }
+
+    pub fn pred(&self) -> &F {
+        &self.pred
+    }
+
+    pub fn pred_mut(&mut self) -> &mut F {
+        &mut self.pred
+    }
+
+    pub fn min(&self) -> usize {
+        self.min
+    }
+
+    pub fn set_pat(&mut self, pat: P) -> &mut Self {
+        self.pat = pat;
+        self
+    }
+
+    pub fn set_pred(&mut self, pred: F) -> &mut Self {
+        self.pred = pred;
+        self
+    }
+
+    pub fn set_min(&mut self, min: usize) -> &mut Self {
+        self.min = min;
+        self
+    }
+
+    pub fn at_least(mut self, min: usize) -> Self {
+        self.min = min;
+        self
+    }
+}
+
+impl<'a, C, P, F, M, O, H, A> Ctor<'a, C, M, Vec<O>, H, A> for RepeatWhile<C, P, F>
+where
+    P: Ctor<'a, C, M, O, H, A>,
+    F: Fn(&C) -> Result<bool, Error>,
+    C: Context<'a> + Match<C>,
+    H: Handler<A, Out = M, Error = Error>,
+    A: Extract<'a, C, Span, Out<'a> = A, Error = Error>,
+{
+    #[inline(always)]
+    fn construct(&self, ctx: &mut C, handler: &mut H) -> Result<Vec<O>, Error> {
+        let mut g = CtxGuard::new(ctx);
+        let mut res = Vec::with_capacity(self.min);
+        let mut ret = Err(Error::RepeatWhile);
+        let beg = g.beg();
+
+        trace!("repeat_while", beg, ());
+        loop {
+            let more = (self.pred)(g.ctx());
+            let offset = g.end();
+
+            // reset the context if the predicate failed
+            if !g.process_ret(more)? {
+                break;
+            }
+
+            match g.non_fatal(|ctx| self.pat.construct(ctx, handler))? {
+                Some(val) => {
+                    res.push(val);
+                    if g.is_stuck(offset) {
+                        break;
+                    }
+                }
+                None => break,
+            }
+        }
+        if res.len() >= self.min {
+            ret = Ok(res);
+        }
+        trace!("repeat_while", beg -> g.end(), ret.is_ok());
+        g.process_ret(ret)
+    }
+}
+
+impl<'a, C, P, F> Regex<C> for RepeatWhile<C, P, F>
+where
+    P: Regex<C, Ret = Span>,
+    F: Fn(&C) -> Result<bool, Error>,
+    C: Context<'a> + Match<C>,
+{
+    type Ret = Span;
+
+    #[inline(always)]
+    fn try_parse(&self, ctx: &mut C) -> Result<Self::Ret, Error> {
+        let mut g = CtxGuard::new(ctx);
+        let mut cnt = 0;
+        let mut span = <Span as Ret>::from_ctx(g.ctx(), (0, 0));
+        let mut ret = Err(Error::RepeatWhile);
+        let beg = g.beg();
+
+        trace!("repeat_while", beg, ());
+        loop {
+            let more = (self.pred)(g.ctx());
+            let offset = g.end();
+
+            // reset the context if the predicate failed
+            if !g.process_ret(more)? {
+                break;
+            }
+
+            match g.non_fatal(|ctx| ctx.try_mat(&self.pat))? {
+                Some(ret) => {
+                    span.add_assign(ret);
+                    cnt += 1;
+                    if g.is_stuck(offset) {
+                        break;
+                    }
+                }
+                None => break,
+            }
+        }
+        if cnt >= self.min {
+            ret = Ok(span);
+        }
+        trace!("repeat_while", beg => g.end(), g.process_ret(ret))
+    }
+}
+
+///
+/// Create a [`RepeatWhile`] that repeats `pat` while `pred` returns true.
+///
+pub fn repeat_while<'a, C, P, F>(pred: F, pat: P) -> RepeatWhile<C, P, F>
+where
+    C: Context<'a>,
+    F: Fn(&C) -> Result<bool, Error>,
+{
+    RepeatWhile::new(pat, pred)
+}
@@ -0,0 +1,279 @@
+use std::cell::Cell;
+use std::cell::OnceCell;
+use std::fmt::Debug;
+use std::marker::PhantomData;
+
+use crate::ctx::Context;
+use crate::ctx::CtxGuard;
+use crate::ctx::Match;
+use crate::ctx::Span;
+use crate::err::Error;
+use crate::re::trace;
+use crate::re::Ctor;
+use crate::re::Extract;
+use crate::re::Handler;
+use crate::re::Regex;
+
+/// The capacity of the first chunk of [`Scratch`], each chunk doubles the capacity of previous one.
+const SCRATCH_CHUNK: usize = 8;
+
+#[derive(Debug)]
+struct Chunk<T: ?Sized> {
+    vals: Box<[OnceCell<Box<T>>]>,
+    next: OnceCell<Box<Chunk<T>>>,
+}
+
+impl<T: ?Sized> Chunk<T> {
+    fn new(cap: usize) -> Self {
+        Self {
+            vals: (0..cap).map(|_| OnceCell::new()).collect(),
+            next: OnceCell::new(),
+        }
+    }
+}
+
+///
+/// An append-only buffer that keeps the repaired data of [`OrTransform`].
+///
+/// The data allocated will live as long as the `Scratch`,
+/// so the result borrowed from the repaired data can be returned safely.
+/// Create it before the [`Context`] so that it outlives the context.
+///
+/// The data of a failed retry is kept too, the handler may still hold a reference of it.
+#[derive(Debug)]
+pub struct Scratch<T: ?Sized> {
+    len: Cell<usize>,
+    head: OnceCell<Box<Chunk<T>>>,
+}
+
+impl<T: ?Sized> Default for Scratch<T> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl<T: ?Sized> Scratch<T> {
+    pub fn new() -> Self {
+        Self {
+            len: Cell::new(0),
+            head: OnceCell::new(),
+        }
+    }
+
+    /// Store the `val` into the scratch, return a reference of it.
+    pub fn alloc(&self, val: Box<T>) -> &T {
+        let len = self.len.get();
+        let mut idx = len;
+        let mut chunk = self
+            .head
+            .get_or_init(|| Box::new(Chunk::new(SCRATCH_CHUNK)));
+
+        while idx >= chunk.vals.len() {
+            let cap = chunk.vals.len() * 2;
+
+            idx -= chunk.vals.len();
+            chunk = chunk.next.get_or_init(|| Box::new(Chunk::new(cap)));
+        }
+        self.len.set(len + 1);
+        chunk.vals[idx].get_or_init(|| val)
+    }
+
+    /// Return the count of data allocated.
+    pub fn len(&self) -> usize {
+        self.len.get()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.len() == 0
+    }
+}
+
+///
+/// Match `P`, if it failed, repair the upcoming data with `F` and try `P` again.
+///
+/// The function `F` takes the remaining data, returns the length of the data should be replaced
+/// and the repaired data. The length is in the unit of [`offset`](Context::offset),
+/// which is bytes for both [`CharsCtx`](crate::ctx::CharsCtx) and [`BytesCtx`](crate::ctx::BytesCtx). The repaired data will be stored in the [`Scratch`],
+/// then `P` is retried on a scratch [`Context`] created by [`clone_with`](Context::clone_with).
+/// The retry succeeds only if `P` consumes all the repaired data and the length is
+/// a valid range of the remaining data, then the original context will skip the replaced items.
+///
+/// # Ctor
+///
+/// Return the result of `P`. The handler must take the slice of [`Orig`](Context::Orig),
+/// because the spans of retry are relative to the repaired data.
+///
+/// # Example
+///
+/// ```
+/// # use neure::prelude::*;
+/// # use neure::re::ctor::Scratch;
+/// #
+/// # fn main() -> color_eyre::Result<()> {
+/// #     color_eyre::install()?;
+///     let scratch = Scratch::new();
+///     let bool = "true".or("false");
+///     // accept the upper case
+///     let bool = bool.or_transform(&scratch, |s: &str| {
+///         let len = s.find(|c: char| !c.is_ascii_alphabetic()).unwrap_or(s.len());
+///
+///         Some((len, s[..len].to_lowercase().into()))
+///     });
+///     let bools = bool.sep(",");
+///
+///     assert_eq!(CharsCtx::new("true,FALSE,True").ctor(&bools)?, ["true", "false", "true"]);
+///     assert_eq!(scratch.len(), 2);
+///     Ok(())
+/// # }
+/// ```
+#[derive(Copy)]
+pub struct OrTransform<'s, C, P, F, T: ?Sized> {
+    pat: P,
+    func: F,
+    scratch: &'s Scratch<T>,
+    marker: PhantomData<C>,
+}
+
+impl<'s, C, P, F, T: ?Sized> std::ops::Not for OrTransform<'s, C, P, F, T> {
+    type Output = crate::re::RegexNot<Self>;
+
+    fn not(self) -> Self::Output {
+        crate::re::not(self)
+    }
+}
+
+impl<C, P, F, T: ?Sized> Debug for OrTransform<'_, C, P, F, T>
+where
+    P: Debug,
+{
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("OrTransform")
+            .field("pat", &self.pat)
+            .finish()
+    }
+}
+
+impl<C, P, F, T: ?Sized> Clone for OrTransform<'_, C, P, F, T>
+where
+    P: Clone,
+    F: Clone,
+{
+    fn clone(&self) -> Self {
+        Self {
+            pat: self.pat.clone(),
+            func: self.func.clone(),
+            scratch: self.scratch,
+            marker: self.marker,
+        }
+    }
+}
+
+impl<'s, C, P, F, T: ?Sized> OrTransform<'s, C, P, F, T> {
+    pub fn new(pat: P, scratch: &'s Scratch<T>, func: F) -> Self {
+        Self {
+            pat,
+            func,
+            scratch,
+            marker: PhantomData,
+        }
+    }
+
+    pub fn pat(&self) -> &P {
+        &self.pat
+    }
+
+    pub fn pat_mut(&mut self) -> &mut P {
+        &mut self.pat
+    }
+
+    pub fn func(&self) -> &F {
+        &self.func
+    }
+
+    pub fn func_mut(&mut self) -> &mut F {
+        &mut self.func
+    }
+
+    pub fn scratch(&self) -> &'s Scratch<T> {
+        self.scratch
+    }
+
+    pub fn set_pat(&mut self, pat: P) -> &mut Self {
+        self.pat = pat;
+        self
+    }
+
+    pub fn set_func(&mut self, func: F) -> &mut Self {
+        self.func = func;
+        self
+    }
+}
+
+// the length returned by user must be a valid range of the remaining data
+fn valid_len<'a, C: Context<'a> + 'a>(ctx: &C, len: usize) -> bool {
+    let beg = ctx.offset();
+
+    len <= ctx.len() - beg && ctx.orig_sub(beg, len).is_ok()
+}
+
+impl<'a, 's: 'a, C, P, F, T, M, O, H> Ctor<'a, C, M, O, H, &'a T> for OrTransform<'s, C, P, F, T>
+where
+    T: ?Sized,
+    P: Ctor<'a, C, M, O, H, &'a T>,
+    F: Fn(&T) -> Option<(usize, Box<T>)>,
+    C: Context<'a, Orig = T> + Match<C> + 'a,
+    H: Handler<&'a T, Out = M, Error = Error>,
+    &'a T: Extract<'a, C, Span, Out<'a> = &'a T, Error = Error>,
+{
+    #[inline(always)]
+    fn construct(&self, ctx: &mut C, func: &mut H) -> Result<O, Error> {
+        let mut g = CtxGuard::new(ctx);
+        let beg = g.beg();
+        let mut ret = trace!("or_transform", beg @ "pat", self.pat.construct(g.ctx(), func));
+
+        if ret.is_err() {
+            if let Some((len, repaired)) = (self.func)(g.reset().ctx().orig()?) {
+                let mut scratch = g.ctx().clone_with(self.scratch.alloc(repaired));
+                let retry =
+                    trace!("or_transform", beg @ "retry", self.pat.construct(&mut scratch, func));
+
+                if retry.is_ok() && scratch.offset() == scratch.len() && valid_len(g.ctx(), len) {
+                    g.ctx().inc(len);
+                    ret = retry;
+                }
+            }
+        }
+        trace!("or_transform", beg -> g.end(), ret.is_ok());
+        g.process_ret(ret)
+    }
+}
+
+impl<'a, 's: 'a, C, P, F, T> Regex<C> for OrTransform<'s, C, P, F, T>
+where
+    T: ?Sized,
+    P: Regex<C, Ret = Span>,
+    F: Fn(&T) -> Option<(usize, Box<T>)>,
+    C: Context<'a, Orig = T> + Match<C> + 'a,
+{
+    type Ret = Span;
+
+    #[inline(always)]
+    fn try_parse(&self, ctx: &mut C) -> Result<Self::Ret, Error> {
+        let mut g = CtxGuard::new(ctx);
+        let beg = g.beg();
+        let mut ret = trace!("or_transform", beg @ "pat", g.try_mat(&self.pat));
+
+        if ret.is_err() {
+            if let Some((len, repaired)) = (self.func)(g.reset().ctx().orig()?) {
+                let mut scratch = g.ctx().clone_with(self.scratch.alloc(repaired));
+                let retry = trace!("or_transform", beg @ "retry", scratch.try_mat(&self.pat));
+
+                if retry.is_ok() && scratch.offset() == scratch.len() && valid_len(g.ctx(), len) {
+                    g.ctx().inc(len);
+                    ret = Ok(Span::new(beg, len));
+                }
+            }
+        }
+        trace!("or_transform", beg => g.end(), g.process_ret(ret))
+    }
+}
@@ -7,10 +7,15 @@ use crate::ctx::Match;
 use crate::ctx::Ret;
 use crate::ctx::Span;
 use crate::err::Error;
+use crate::map;
+use crate::map::MapSingle;
 use crate::map::Select0;
 use crate::map::Select1;
 use crate::map::SelectEq;
+use crate::neu::AsciiWhiteSpace;
 use crate::neu::CRange;
+use crate::neu::NeureZeroMore;
+use crate::neu::NullCond;
 use crate::re::def_not;
 use crate::re::trace;
 use crate::re::trace_v;
@@ -20,6 +25,9 @@ use crate::re::Handler;
 use crate::re::Regex;
 
 use super::Map;
+use super::Pad;
+use super::Padded;
+use super::Quote;
 
 ///
 /// Match `L` and `R` separated by `S`.
@@ -195,12 +203,29 @@ where
     }
 }
 
+/// The separator `S` surrounded by the trivia `T`, see [`with_trivia`](Separate::with_trivia).
+pub type SepTrivia<C, S, T> = Pad<C, Padded<C, S, T>, T>;
+
+/// The whitespace skipped by [`ws_list`](crate::re::ConstructOp::ws_list).
+pub type WsTrivia<C> = NeureZeroMore<C, AsciiWhiteSpace, char, NullCond>;
+
+/// The list created by [`ws_list`](crate::re::ConstructOp::ws_list),
+/// every token of it is surrounded by the [`WsTrivia`].
+pub type WsList<C, P, S, L, R> = Quote<
+    C,
+    Separate<C, P, SepTrivia<C, S, WsTrivia<C>>>,
+    SepTrivia<C, L, WsTrivia<C>>,
+    SepTrivia<C, R, WsTrivia<C>>,
+>;
+
 ///
 /// Match regex `P` as many times as possible, with S as the delimiter.
 ///
 /// # Ctor
 ///
 /// It will return a [`Vec`] of `P`'s match results.
+/// With the progress guard enabled, it will return [`Error::NoProgress`]
+/// if an iteration of `P` and `S` matched nothing.
 ///
 /// # Example
 ///
@@ -230,6 +255,7 @@ pub struct Separate<C, P, S> {
     skip: bool,
     capacity: usize,
     min: usize,
+    guard: bool,
     marker: PhantomData<C>,
 }
 
@@ -247,6 +273,7 @@ where
             .field("skip", &self.skip)
             .field("capacity", &self.capacity)
             .field("min", &self.min)
+            .field("guard", &self.guard)
             .finish()
     }
 }
@@ -263,6 +290,7 @@ where
             skip: self.skip,
             capacity: self.capacity,
             min: self.min,
+            guard: self.guard,
             marker: self.marker,
         }
     }
@@ -276,6 +304,7 @@ impl<C, P, S> Separate<C, P, S> {
             skip: true,
             capacity: 0,
             min: 1,
+            guard: false,
             marker: PhantomData,
         }
     }
@@ -304,6 +333,10 @@ impl<C, P, S> Separate<C, P, S> {
         self.min
     }
 
+    pub fn guard(&self) -> bool {
+        self.guard
+    }
+
     pub fn capacity(&self) -> usize {
         self.capacity
     }
@@ -333,6 +366,11 @@ impl<C, P, S> Separate<C, P, S> {
         self
     }
 
+    pub fn set_guard(&mut self, guard: bool) -> &mut Self {
+        self.guard = guard;
+        self
+    }
+
     pub fn with_skip(mut self, skip: bool) -> Self {
         self.skip = skip;
         self
@@ -347,6 +385,51 @@ impl<C, P, S> Separate<C, P, S> {
         self.min = min;
         self
     }
+
+    /// Return [`Error::NoProgress`] instead of looping if an iteration matched nothing.
+    pub fn with_guard(mut self, guard: bool) -> Self {
+        self.guard = guard;
+        self
+    }
+
+    ///
+    /// Match the `trivia`, such as whitespace or comments, before and after the separator.
+    /// The `trivia` should be able to match the empty data.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use neure::prelude::*;
+    /// #
+    /// # fn main() -> color_eyre::Result<()> {
+    /// #     color_eyre::install()?;
+    ///     let num = neu::digit(10).repeat_one_more().map(map::from_str::<i32>());
+    ///     let comment = "#".then(neu::not('\n').repeat_zero_more()).then("\n");
+    ///     let trivia = comment.or(neu::whitespace().repeat_one_more()).repeat(0..);
+    ///     let nums = num.sep(",").with_trivia(trivia);
+    ///     let mut ctx = CharsCtx::new("1 # one\n, 2,\n3 ;");
+    ///
+    ///     assert_eq!(ctx.ctor(&nums)?, [1, 2, 3]);
+    ///     assert_eq!(ctx.offset(), 14);
+    ///     Ok(())
+    /// # }
+    /// ```
+    pub fn with_trivia<T>(self, trivia: T) -> Separate<C, P, SepTrivia<C, S, T>>
+    where
+        T: Clone,
+    {
+        let sep = Pad::new(Padded::new(self.sep, trivia.clone()), trivia);
+
+        Separate {
+            pat: self.pat,
+            sep,
+            skip: self.skip,
+            capacity: self.capacity,
+            min: self.min,
+            guard: self.guard,
+            marker: self.marker,
+        }
+    }
 }
 
 impl<'a, C, S, P, M, O, H, A> Ctor<'a, C, M, Vec<O>, H, A> for Separate<C, P, S>
@@ -365,7 +448,10 @@ where
         let range: CRange<usize> = (self.min..).into();
 
         trace_v!("separate", range, beg, ());
-        while let Ok(ret) = self.pat.construct(g.ctx(), func) {
+        let mut offset = g.end();
+        let mut fatal = None;
+
+        while let Some(ret) = g.non_fatal(|ctx| self.pat.construct(ctx, func))? {
             let sep_ret = trace_v!("separate", range, beg @ "sep", g.ctx().try_mat(&self.sep));
 
             if sep_ret.is_ok() || self.skip {
@@ -374,12 +460,17 @@ where
             if sep_ret.is_err() {
                 break;
             }
+            if self.guard && g.is_stuck(offset) {
+                fatal = Some(Error::NoProgress { at: offset });
+                break;
+            }
+            offset = g.end();
         }
         let len = res.len();
-        let ret = g.process_ret(if len >= self.min {
-            Ok(res)
-        } else {
-            Err(Error::Separate)
+        let ret = g.process_ret(match fatal {
+            Some(e) => Err(e),
+            None if len >= self.min => Ok(res),
+            None => Err(Error::Separate),
         });
 
         trace_v!("separate", range, beg -> g.end(), ret.is_ok(), len);
@@ -405,7 +496,10 @@ where
         let range: CRange<usize> = (self.min..).into();
 
         trace_v!("separate", range, beg, ());
-        while let Ok(ret) = g.ctx().try_mat(&self.pat) {
+        let mut offset = g.end();
+        let mut fatal = None;
+
+        while let Some(ret) = g.non_fatal(|ctx| ctx.try_mat(&self.pat))? {
             let sep_ret = g.ctx().try_mat(&self.sep);
 
             if sep_ret.is_ok() || self.skip {
@@ -418,14 +512,257 @@ where
             if sep_ret.is_err() {
                 break;
             }
+            if self.guard && g.is_stuck(offset) {
+                fatal = Some(Error::NoProgress { at: offset });
+                break;
+            }
+            offset = g.end();
         }
-        if cnt >= self.min {
+        if let Some(e) = fatal {
+            ret = Err(e);
+        } else if cnt >= self.min {
             ret = Ok(span);
         }
         trace_v!("separate", range, beg => g.end(), g.process_ret(ret), cnt )
     }
 }
 
+///
+/// Match regex `P` as many times as possible, with S as the delimiter.
+///
+/// # Ctor
+///
+/// It will return a [`Vec`] of `P`'s match results, every result is paired with
+/// the [`Span`] covered by `P`, the delimiter is not included.
+///
+/// # Example
+///
+/// ```
+/// # use neure::prelude::*;
+/// #
+/// # fn main() -> color_eyre::Result<()> {
+/// #     color_eyre::install()?;
+///     let num = neu::digit(10).repeat_one_more().map(map::from_str::<i32>());
+///     let nums = num.sep_spanned(",".ws());
+///     let mut ctx = CharsCtx::new("1, 22, 333");
+///
+///     assert_eq!(
+///         ctx.ctor(&nums)?,
+///         [(Span::new(0, 1), 1), (Span::new(3, 2), 22), (Span::new(7, 3), 333)]
+///     );
+///     Ok(())
+/// # }
+/// ```
+#[derive(Default, Copy)]
+pub struct SepSpanned<C, P, S> {
+    pat: P,
+    sep: S,
+    skip: bool,
+    capacity: usize,
+    min: usize,
+    marker: PhantomData<C>,
+}
+
+def_not!(SepSpanned<C, P, S>);
+
+impl<C, P, S> Debug for SepSpanned<C, P, S>
+where
+    P: Debug,
+    S: Debug,
+{
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("SepSpanned")
+            .field("pat", &self.pat)
+            .field("sep", &self.sep)
+            .field("skip", &self.skip)
+            .field("capacity", &self.capacity)
+            .field("min", &self.min)
+            .finish()
+    }
+}
+
+impl<C, P, S> Clone for SepSpanned<C, P, S>
+where
+    P: Clone,
+    S: Clone,
+{
+    fn clone(&self) -> Self {
+        Self {
+            pat: self.pat.clone(),
+            sep: self.sep.clone(),
+            skip: self.skip,
+            capacity: self.capacity,
+            min: self.min,
+            marker: self.marker,
+        }
+    }
+}
+
+impl<C, P, S> SepSpanned<C, P, S> {
+    pub fn new(pat: P, sep: S) -> Self {
+        Self {
+            pat,
+            sep,
+            skip: true,
+            capacity: 0,
+            min: 1,
+            marker: PhantomData,
+        }
+    }
+
+    pub fn pat(&self) -> &P {
+        &self.pat
+    }
+
+    pub fn pat_mut(&mut self) -> &mut P {
+        &mut self.pat
+    }
+
+    pub fn sep(&self) -> &S {
+        &self.sep
+    }
+
+    pub fn sep_mut(&mut self) -> &mut S {
+        &mut self.sep
+    }
+
+    pub fn skip(&self) -> bool {
+        self.skip
+    }
+
+    pub fn min(&self) -> usize {
+        self.min
+    }
+
+    pub fn capacity(&self) -> usize {
+        self.capacity
+    }
+
+    pub fn set_pat(&mut self, pat: P) -> &mut Self {
+        self.pat = pat;
+        self
+    }
+
+    pub fn set_sep(&mut self, sep: S) -> &mut Self {
+        self.sep = sep;
+        self
+    }
+
+    pub fn set_skip(&mut self, skip: bool) -> &mut Self {
+        self.skip = skip;
+        self
+    }
+
+    pub fn set_capacity(&mut self, capacity: usize) -> &mut Self {
+        self.capacity = capacity;
+        self
+    }
+
+    pub fn set_min(&mut self, min: usize) -> &mut Self {
+        self.min = min;
+        self
+    }
+
+    pub fn with_skip(mut self, skip: bool) -> Self {
+        self.skip = skip;
+        self
+    }
+
+    pub fn with_capacity(mut self, capacity: usize) -> Self {
+        self.capacity = capacity;
+        self
+    }
+
+    pub fn at_least(mut self, min: usize) -> Self {
+        self.min = min;
+        self
+    }
+}
+
+impl<'a, C, S, P, M, O, H, A> Ctor<'a, C, M, Vec<(Span, O)>, H, A> for SepSpanned<C, P, S>
+where
+    P: Ctor<'a, C, M, O, H, A>,
+    S: Regex<C, Ret = Span>,
+    C: Context<'a> + Match<C>,
+    H: Handler<A, Out = M, Error = Error>,
+    A: Extract<'a, C, Span, Out<'a> = A, Error = Error>,
+{
+    #[inline(always)]
+    fn construct(&self, ctx: &mut C, func: &mut H) -> Result<Vec<(Span, O)>, Error> {
+        let mut g = CtxGuard::new(ctx);
+        let mut res = Vec::with_capacity(self.capacity.max(self.min));
+        let beg = g.beg();
+        let range: CRange<usize> = (self.min..).into();
+
+        trace_v!("sep_spanned", range, beg, ());
+        let mut offset = g.end();
+
+        while let Some(ret) = g.non_fatal(|ctx| self.pat.construct(ctx, func))? {
+            let span = Span::new(offset, g.end() - offset);
+            let sep_ret = trace_v!("sep_spanned", range, beg @ "sep", g.ctx().try_mat(&self.sep));
+
+            if sep_ret.is_ok() || self.skip {
+                res.push((span, ret));
+            }
+            if sep_ret.is_err() || g.is_stuck(offset) {
+                break;
+            }
+            offset = g.end();
+        }
+        let len = res.len();
+        let ret = g.process_ret(if len >= self.min {
+            Ok(res)
+        } else {
+            Err(Error::Separate)
+        });
+
+        trace_v!("sep_spanned", range, beg -> g.end(), ret.is_ok(), len);
+        ret
+    }
+}
+
+impl<'a, C, S, P> Regex<C> for SepSpanned<C, P, S>
+where
+    S: Regex<C, Ret = Span>,
+    P: Regex<C, Ret = Span>,
+    C: Context<'a> + Match<C>,
+{
+    type Ret = Span;
+
+    #[inline(always)]
+    fn try_parse(&self, ctx: &mut C) -> Result<Self::Ret, Error> {
+        let mut g = CtxGuard::new(ctx);
+        let mut cnt = 0;
+        let mut span = <Span as Ret>::from_ctx(g.ctx(), (0, 0));
+        let mut ret = Err(Error::Separate);
+        let beg = g.beg();
+        let range: CRange<usize> = (self.min..).into();
+
+        trace_v!("sep_spanned", range, beg, ());
+        let mut offset = g.end();
+
+        while let Some(ret) = g.non_fatal(|ctx| ctx.try_mat(&self.pat))? {
+            let sep_ret = g.ctx().try_mat(&self.sep);
+
+            if sep_ret.is_ok() || self.skip {
+                cnt += 1;
+                span.add_assign(ret);
+                if let Ok(sep_ret) = sep_ret {
+                    span.add_assign(sep_ret);
+                }
+            }
+            if sep_ret.is_err() || g.is_stuck(offset) {
+                break;
+            }
+            offset = g.end();
+        }
+        if cnt >= self.min {
+            ret = Ok(span);
+        }
+        trace_v!("sep_spanned", range, beg => g.end(), g.process_ret(ret), cnt)
+    }
+}
+
 ///
 /// Match regex `P` as many times as possible, with S as the delimiter.
 ///
@@ -438,6 +775,8 @@ where
 ///
 /// `SepCollect` will always succeed if the minimum size is 0, be careful to use it with other `.sep` faimly APIs.
 /// The default size is 1.
+/// With the progress guard enabled, it will return [`Error::NoProgress`]
+/// if an iteration of `P` and `S` matched nothing.
 ///
 /// # Example
 ///
@@ -462,6 +801,7 @@ pub struct SepCollect<C, P, S, O, V> {
     sep: S,
     skip: bool,
     min: usize,
+    guard: bool,
     marker: PhantomData<(C, O, V)>,
 }
 
@@ -478,6 +818,7 @@ where
             .field("sep", &self.sep)
             .field("skip", &self.skip)
             .field("min", &self.min)
+            .field("guard", &self.guard)
             .finish()
     }
 }
@@ -493,6 +834,7 @@ where
             sep: self.sep.clone(),
             skip: self.skip,
             min: self.min,
+            guard: self.guard,
             marker: self.marker,
         }
     }
@@ -505,6 +847,7 @@ impl<C, P, S, O, V> SepCollect<C, P, S, O, V> {
             sep,
             skip: true,
             min: 1,
+            guard: false,
             marker: PhantomData,
         }
     }
@@ -533,6 +876,10 @@ impl<C, P, S, O, V> SepCollect<C, P, S, O, V> {
         self.min
     }
 
+    pub fn guard(&self) -> bool {
+        self.guard
+    }
+
     pub fn set_pat(&mut self, pat: P) -> &mut Self {
         self.pat = pat;
         self
@@ -553,6 +900,11 @@ impl<C, P, S, O, V> SepCollect<C, P, S, O, V> {
         self
     }
 
+    pub fn set_guard(&mut self, guard: bool) -> &mut Self {
+        self.guard = guard;
+        self
+    }
+
     pub fn with_skip(mut self, skip: bool) -> Self {
         self.skip = skip;
         self
@@ -562,6 +914,12 @@ impl<C, P, S, O, V> SepCollect<C, P, S, O, V> {
         self.min = min;
         self
     }
+
+    /// Return [`Error::NoProgress`] instead of looping if an iteration matched nothing.
+    pub fn with_guard(mut self, guard: bool) -> Self {
+        self.guard = guard;
+        self
+    }
 }
 
 impl<'a, C, S, P, M, O, V, H, A> Ctor<'a, C, M, V, H, A> for SepCollect<C, P, S, O, V>
@@ -578,32 +936,42 @@ where
         let mut g = CtxGuard::new(ctx);
         let mut cnt = 0;
         let mut end = false;
+        let mut fatal = None;
         let beg = g.beg();
         let range: CRange<usize> = (self.min..).into();
         let ret = {
             trace_v!("sep_collect", range, beg, ());
             V::from_iter(std::iter::from_fn(|| {
-                self.pat.construct(g.ctx(), func).ok().and_then(|ret| {
-                    let sep_ret =
-                        trace_v!("sep_collect", range, beg @ "sep", g.ctx().try_mat(&self.sep));
-
-                    if !end {
-                        if sep_ret.is_err() {
-                            end = true;
-                        }
-                        if sep_ret.is_ok() || self.skip {
-                            cnt += 1;
-                            return Some(ret);
-                        }
+                let offset = g.end();
+                let ret = match self.pat.construct(g.ctx(), func) {
+                    Ok(ret) => ret,
+                    Err(e) => {
+                        fatal = e.is_fatal().then_some(e);
+                        return None;
+                    }
+                };
+                let sep_ret =
+                    trace_v!("sep_collect", range, beg @ "sep", g.ctx().try_mat(&self.sep));
+
+                if !end {
+                    if sep_ret.is_err() {
+                        end = true;
+                    } else if self.guard && g.is_stuck(offset) {
+                        fatal = Some(Error::NoProgress { at: offset });
+                        return None;
+                    }
+                    if sep_ret.is_ok() || self.skip {
+                        cnt += 1;
+                        return Some(ret);
                     }
-                    None
-                })
+                }
+                None
             }))
         };
-        let ret = g.process_ret(if cnt >= self.min {
-            Ok(ret)
-        } else {
-            Err(Error::SepCollect)
+        let ret = g.process_ret(match fatal {
+            Some(e) => Err(e),
+            None if cnt >= self.min => Ok(ret),
+            None => Err(Error::SepCollect),
         });
 
         trace_v!("sep_collect", range, beg -> g.end(), ret.is_ok(), cnt);
@@ -629,7 +997,10 @@ where
         let range: CRange<usize> = (self.min..).into();
 
         trace_v!("sep_collect", range, beg, ());
-        while let Ok(ret) = g.ctx().try_mat(&self.pat) {
+        let mut offset = g.end();
+        let mut fatal = None;
+
+        while let Some(ret) = g.non_fatal(|ctx| ctx.try_mat(&self.pat))? {
             let sep_ret = g.ctx().try_mat(&self.sep);
 
             if sep_ret.is_ok() || self.skip {
@@ -642,10 +1013,1264 @@ where
             if sep_ret.is_err() {
                 break;
             }
+            if self.guard && g.is_stuck(offset) {
+                fatal = Some(Error::NoProgress { at: offset });
+                break;
+            }
+            offset = g.end();
         }
-        if cnt >= self.min {
+        if let Some(e) = fatal {
+            ret = Err(e);
+        } else if cnt >= self.min {
             ret = Ok(span);
         }
         trace_v!("sep_collect", range, beg => g.end(), g.process_ret(ret), cnt)
     }
 }
+
+///
+/// Match regex `P` as many times as possible, with S as the delimiter,
+/// join the results into a [`String`] with `join`.
+///
+/// # Ctor
+///
+/// It will return a [`String`] contains the results of `P` joined by `join`,
+/// no intermediate [`Vec`] is allocated.
+///
+/// # Notice
+///
+/// `SepString` will always succeed if the minimum size is 0, be careful to use it with other `.sep` faimly APIs.
+/// The default size is 1.
+///
+/// # Example
+///
+/// ```
+/// # use neure::prelude::*;
+/// #
+/// # fn main() -> color_eyre::Result<()> {
+/// #     color_eyre::install()?;
+///     let name = neu::ascii_alphabetic().repeat_one_more();
+///     let path = name.sep_to_string("::", "/");
+///     let mut ctx = CharsCtx::new("std::collections::HashMap");
+///
+///     assert_eq!(ctx.ctor(&path)?, "std/collections/HashMap");
+///     Ok(())
+/// # }
+/// ```
+#[derive(Default)]
+pub struct SepString<C, P, S, O> {
+    pat: P,
+    sep: S,
+    join: String,
+    skip: bool,
+    min: usize,
+    marker: PhantomData<(C, O)>,
+}
+
+def_not!(SepString<C, P, S, O>);
+
+impl<C, P, S, O> Debug for SepString<C, P, S, O>
+where
+    P: Debug,
+    S: Debug,
+{
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("SepString")
+            .field("pat", &self.pat)
+            .field("sep", &self.sep)
+            .field("join", &self.join)
+            .field("skip", &self.skip)
+            .field("min", &self.min)
+            .finish()
+    }
+}
+
+impl<C, P, S, O> Clone for SepString<C, P, S, O>
+where
+    P: Clone,
+    S: Clone,
+{
+    fn clone(&self) -> Self {
+        Self {
+            pat: self.pat.clone(),
+            sep: self.sep.clone(),
+            join: self.join.clone(),
+            skip: self.skip,
+            min: self.min,
+            marker: self.marker,
+        }
+    }
+}
+
+impl<C, P, S, O> SepString<C, P, S, O> {
+    pub fn new(pat: P, sep: S, join: impl Into<String>) -> Self {
+        Self {
+            pat,
+            sep,
+            join: join.into(),
+            skip: true,
+            min: 1,
+            marker: PhantomData,
+        }
+    }
+
+    pub fn pat(&self) -> &P {
+        &self.pat
+    }
+
+    pub fn pat_mut(&mut self) -> &mut P {
+        &mut self.pat
+    }
+
+    pub fn sep(&self) -> &S {
+        &self.sep
+    }
+
+    pub fn sep_mut(&mut self) -> &mut S {
+        &mut self.sep
+    }
+
+    pub fn join(&self) -> &str {
+        &self.join
+    }
+
+    pub fn skip(&self) -> bool {
+        self.skip
+    }
+
+    pub fn min(&self) -> usize {
+        self.min
+    }
+
+    pub fn set_pat(&mut self, pat: P) -> &mut Self {
+        self.pat = pat;
+        self
+    }
+
+    pub fn set_sep(&mut self, sep: S) -> &mut Self {
+        self.sep = sep;
+        self
+    }
+
+    pub fn set_join(&mut self, join: impl Into<String>) -> &mut Self {
+        self.join = join.into();
+        self
+    }
+
+    pub fn set_skip(&mut self, skip: bool) -> &mut Self {
+        self.skip = skip;
+        self
+    }
+
+    pub fn set_min(&mut self, min: usize) -> &mut Self {
+        self.min = min;
+        self
+    }
+
+    pub fn with_skip(mut self, skip: bool) -> Self {
+        self.skip = skip;
+        self
+    }
+
+    pub fn at_least(mut self, min: usize) -> Self {
+        self.min = min;
+        self
+    }
+}
+
+impl<'a, C, S, P, M, O, H, A> Ctor<'a, C, M, String, H, A> for SepString<C, P, S, O>
+where
+    O: AsRef<str>,
+    P: Ctor<'a, C, M, O, H, A>,
+    S: Regex<C, Ret = Span>,
+    C: Context<'a> + Match<C>,
+    H: Handler<A, Out = M, Error = Error>,
+    A: Extract<'a, C, Span, Out<'a> = A, Error = Error>,
+{
+    #[inline(always)]
+    fn construct(&self, ctx: &mut C, func: &mut H) -> Result<String, Error> {
+        let mut g = CtxGuard::new(ctx);
+        let mut cnt = 0;
+        let mut res = String::new();
+        let beg = g.beg();
+        let range: CRange<usize> = (self.min..).into();
+
+        trace_v!("sep_string", range, beg, ());
+        let mut offset = g.end();
+
+        while let Some(ret) = g.non_fatal(|ctx| self.pat.construct(ctx, func))? {
+            let sep_ret = trace_v!("sep_string", range, beg @ "sep", g.ctx().try_mat(&self.sep));
+
+            if sep_ret.is_ok() || self.skip {
+                if cnt > 0 {
+                    res.push_str(&self.join);
+                }
+                res.push_str(ret.as_ref());
+                cnt += 1;
+            }
+            if sep_ret.is_err() || g.is_stuck(offset) {
+                break;
+            }
+            offset = g.end();
+        }
+        let ret = g.process_ret(if cnt >= self.min {
+            Ok(res)
+        } else {
+            Err(Error::SepString)
+        });
+
+        trace_v!("sep_string", range, beg -> g.end(), ret.is_ok(), cnt);
+        ret
+    }
+}
+
+impl<'a, C, S, P, O> Regex<C> for SepString<C, P, S, O>
+where
+    S: Regex<C, Ret = Span>,
+    P: Regex<C, Ret = Span>,
+    C: Context<'a> + Match<C>,
+{
+    type Ret = Span;
+
+    #[inline(always)]
+    fn try_parse(&self, ctx: &mut C) -> Result<Self::Ret, Error> {
+        let mut g = CtxGuard::new(ctx);
+        let mut cnt = 0;
+        let mut span = <Span as Ret>::from_ctx(g.ctx(), (0, 0));
+        let mut ret = Err(Error::SepString);
+        let beg = g.beg();
+        let range: CRange<usize> = (self.min..).into();
+
+        trace_v!("sep_string", range, beg, ());
+        let mut offset = g.end();
+
+        while let Some(ret) = g.non_fatal(|ctx| ctx.try_mat(&self.pat))? {
+            let sep_ret = g.ctx().try_mat(&self.sep);
+
+            if sep_ret.is_ok() || self.skip {
+                cnt += 1;
+                span.add_assign(ret);
+                if let Ok(sep_ret) = sep_ret {
+                    span.add_assign(sep_ret);
+                }
+            }
+            if sep_ret.is_err() || g.is_stuck(offset) {
+                break;
+            }
+            offset = g.end();
+        }
+        if cnt >= self.min {
+            ret = Ok(span);
+        }
+        trace_v!("sep_string", range, beg => g.end(), g.process_ret(ret), cnt)
+    }
+}
+
+///
+/// Match regex `P` as many times as possible, with S as the delimiter,
+/// map each result using `F` and collect the values into `V`.
+///
+/// # Ctor
+///
+/// It will return a `V` that can constructed from the mapped values
+/// using [`from_iter`](std::iter::FromIterator::from_iter).
+/// The values are mapped lazily, it will return the error if any of the mapping failed.
+///
+/// # Notice
+///
+/// `SepMapCollect` will always succeed if the minimum size is 0, be careful to use it with other `.sep` faimly APIs.
+/// The default size is 1.
+///
+/// # Example
+///
+/// ```
+/// # use std::collections::BTreeSet;
+/// #
+/// # use neure::prelude::*;
+/// #
+/// # fn main() -> color_eyre::Result<()> {
+/// #     color_eyre::install()?;
+///     let digit = neu::digit(10).repeat_one_more();
+///     let vals = digit.sep_map_collect(",".ws(), map::from_str::<i64>());
+///     let array = vals.quote("[", "]");
+///     let mut ctx = CharsCtx::new("[42, 18, 42, 7]");
+///     let set: BTreeSet<i64> = ctx.ctor(&array)?;
+///
+///     assert_eq!(set.into_iter().collect::<Vec<_>>(), [7, 18, 42]);
+///     Ok(())
+/// # }
+/// ```
+#[derive(Default, Copy)]
+pub struct SepMapCollect<C, P, S, F, O, T, V> {
+    pat: P,
+    sep: S,
+    mapper: F,
+    skip: bool,
+    min: usize,
+    marker: PhantomData<(C, O, T, V)>,
+}
+
+def_not!(SepMapCollect<C, P, S, F, O, T, V>);
+
+impl<C, P, S, F, O, T, V> Debug for SepMapCollect<C, P, S, F, O, T, V>
+where
+    P: Debug,
+    S: Debug,
+    F: Debug,
+{
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("SepMapCollect")
+            .field("pat", &self.pat)
+            .field("sep", &self.sep)
+            .field("mapper", &self.mapper)
+            .field("skip", &self.skip)
+            .field("min", &self.min)
+            .finish()
+    }
+}
+
+impl<C, P, S, F, O, T, V> Clone for SepMapCollect<C, P, S, F, O, T, V>
+where
+    P: Clone,
+    S: Clone,
+    F: Clone,
+{
+    fn clone(&self) -> Self {
+        Self {
+            pat: self.pat.clone(),
+            sep: self.sep.clone(),
+            mapper: self.mapper.clone(),
+            skip: self.skip,
+            min: self.min,
+            marker: self.marker,
+        }
+    }
+}
+
+impl<C, P, S, F, O, T, V> SepMapCollect<C, P, S, F, O, T, V> {
+    pub fn new(pat: P, sep: S, mapper: F) -> Self {
+        Self {
+            pat,
+            sep,
+            mapper,
+            skip: true,
+            min: 1,
+            marker: PhantomData,
+        }
+    }
+
+    pub fn pat(&self) -> &P {
+        &self.pat
+    }
+
+    pub fn pat_mut(&mut self) -> &mut P {
+        &mut self.pat
+    }
+
+    pub fn sep(&self) -> &S {
+        &self.sep
+    }
+
+    pub fn sep_mut(&mut self) -> &mut S {
+        &mut self.sep
+    }
+
+    pub fn mapper(&self) -> &F {
+        &self.mapper
+    }
+
+    pub fn mapper_mut(&mut self) -> &mut F {
+        &mut self.mapper
+    }
+
+    pub fn skip(&self) -> bool {
+        self.skip
+    }
+
+    pub fn min(&self) -> usize {
+        self.min
+    }
+
+    pub fn set_pat(&mut self, pat: P) -> &mut Self {
+        self.pat = pat;
+        self
+    }
+
+    pub fn set_sep(&mut self, sep: S) -> &mut Self {
+        self.sep = sep;
+        self
+    }
+
+    pub fn set_mapper(&mut self, mapper: F) -> &mut Self {
+        self.mapper = mapper;
+        self
+    }
+
+    pub fn set_skip(&mut self, skip: bool) -> &mut Self {
+        self.skip = skip;
+        self
+    }
+
+    pub fn set_min(&mut self, min: usize) -> &mut Self {
+        self.min = min;
+        self
+    }
+
+    pub fn with_skip(mut self, skip: bool) -> Self {
+        self.skip = skip;
+        self
+    }
+
+    pub fn at_least(mut self, min: usize) -> Self {
+        self.min = min;
+        self
+    }
+}
+
+impl<'a, C, S, P, F, M, O, T, V, H, A> Ctor<'a, C, M, V, H, A>
+    for SepMapCollect<C, P, S, F, O, T, V>
+where
+    V: FromIterator<T>,
+    F: MapSingle<O, T>,
+    P: Ctor<'a, C, M, O, H, A>,
+    S: Regex<C, Ret = Span>,
+    C: Context<'a> + Match<C>,
+    H: Handler<A, Out = M, Error = Error>,
+    A: Extract<'a, C, Span, Out<'a> = A, Error = Error>,
+{
+    #[inline(always)]
+    fn construct(&self, ctx: &mut C, func: &mut H) -> Result<V, Error> {
+        let mut g = CtxGuard::new(ctx);
+        let mut cnt = 0;
+        let mut end = false;
+        let mut err = None;
+        let beg = g.beg();
+        let range: CRange<usize> = (self.min..).into();
+        let ret = {
+            trace_v!("sep_map_collect", range, beg, ());
+            V::from_iter(std::iter::from_fn(|| {
+                let offset = g.end();
+
+                if end {
+                    return None;
+                }
+                let ret = self.pat.construct(g.ctx(), func);
+
+                ret.map_err(|e| err = e.is_fatal().then_some(e))
+                    .ok()
+                    .and_then(|ret| {
+                        let sep_ret = trace_v!(
+                            "sep_map_collect",
+                            range,
+                            beg @ "sep",
+                            g.ctx().try_mat(&self.sep)
+                        );
+
+                        if sep_ret.is_err() || g.is_stuck(offset) {
+                            end = true;
+                        }
+                        if sep_ret.is_ok() || self.skip {
+                            match self.mapper.map_to(ret) {
+                                Ok(ret) => {
+                                    cnt += 1;
+                                    return Some(ret);
+                                }
+                                Err(e) => {
+                                    end = true;
+                                    err = Some(e);
+                                }
+                            }
+                        }
+                        None
+                    })
+            }))
+        };
+        let ret = g.process_ret(match err {
+            Some(e) => Err(e),
+            None if cnt >= self.min => Ok(ret),
+            None => Err(Error::SepCollect),
+        });
+
+        trace_v!("sep_map_collect", range, beg -> g.end(), ret.is_ok(), cnt);
+        ret
+    }
+}
+
+impl<'a, C, S, P, F, O, T, V> Regex<C> for SepMapCollect<C, P, S, F, O, T, V>
+where
+    S: Regex<C, Ret = Span>,
+    P: Regex<C, Ret = Span>,
+    C: Context<'a> + Match<C>,
+{
+    type Ret = Span;
+
+    #[inline(always)]
+    fn try_parse(&self, ctx: &mut C) -> Result<Self::Ret, Error> {
+        let mut g = CtxGuard::new(ctx);
+        let mut cnt = 0;
+        let mut span = <Span as Ret>::from_ctx(g.ctx(), (0, 0));
+        let mut ret = Err(Error::SepCollect);
+        let beg = g.beg();
+        let range: CRange<usize> = (self.min..).into();
+
+        trace_v!("sep_map_collect", range, beg, ());
+        let mut offset = g.end();
+
+        while let Some(ret) = g.non_fatal(|ctx| ctx.try_mat(&self.pat))? {
+            let sep_ret = g.ctx().try_mat(&self.sep);
+
+            if sep_ret.is_ok() || self.skip {
+                cnt += 1;
+                span.add_assign(ret);
+                if let Ok(sep_ret) = sep_ret {
+                    span.add_assign(sep_ret);
+                }
+            }
+            if sep_ret.is_err() || g.is_stuck(offset) {
+                break;
+            }
+            offset = g.end();
+        }
+        if cnt >= self.min {
+            ret = Ok(span);
+        }
+        trace_v!("sep_map_collect", range, beg => g.end(), g.process_ret(ret), cnt)
+    }
+}
+
+///
+/// Match regex `P` at least twice, with S as the delimiter.
+///
+/// # Ctor
+///
+/// It will return a tuple of the first result and a [`Vec`] of the rest results.
+/// Return [`Error::TooFew`] if `P` matched less than two times.
+///
+/// # Example
+///
+/// ```
+/// # use neure::prelude::*;
+/// #
+/// # fn main() -> color_eyre::Result<()> {
+/// #     color_eyre::install()?;
+///     let ty = neu::ascii_alphabetic().repeat_one_more();
+///     let union = ty.sep_two_plus("|");
+///     let mut ctx = CharsCtx::new("Int|Str|Bool");
+///
+///     assert_eq!(ctx.ctor(&union)?, ("Int", vec!["Str", "Bool"]));
+///     assert!(CharsCtx::new("Int").ctor(&union).is_err());
+///     Ok(())
+/// # }
+/// ```
+#[derive(Default, Copy)]
+pub struct SepTwoPlus<C, P, S> {
+    inner: Separate<C, P, S>,
+}
+
+def_not!(SepTwoPlus<C, P, S>);
+
+impl<C, P, S> Debug for SepTwoPlus<C, P, S>
+where
+    P: Debug,
+    S: Debug,
+{
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("SepTwoPlus")
+            .field("inner", &self.inner)
+            .finish()
+    }
+}
+
+impl<C, P, S> Clone for SepTwoPlus<C, P, S>
+where
+    P: Clone,
+    S: Clone,
+{
+    fn clone(&self) -> Self {
+        Self {
+            inner: self.inner.clone(),
+        }
+    }
+}
+
+impl<C, P, S> SepTwoPlus<C, P, S> {
+    pub fn new(pat: P, sep: S) -> Self {
+        Self {
+            inner: Separate::new(pat, sep).at_least(2),
+        }
+    }
+
+    pub fn pat(&self) -> &P {
+        self.inner.pat()
+    }
+
+    pub fn pat_mut(&mut self) -> &mut P {
+        self.inner.pat_mut()
+    }
+
+    pub fn sep(&self) -> &S {
+        self.inner.sep()
+    }
+
+    pub fn sep_mut(&mut self) -> &mut S {
+        self.inner.sep_mut()
+    }
+
+    pub fn skip(&self) -> bool {
+        self.inner.skip()
+    }
+
+    pub fn set_pat(&mut self, pat: P) -> &mut Self {
+        self.inner.set_pat(pat);
+        self
+    }
+
+    pub fn set_sep(&mut self, sep: S) -> &mut Self {
+        self.inner.set_sep(sep);
+        self
+    }
+
+    pub fn set_skip(&mut self, skip: bool) -> &mut Self {
+        self.inner.set_skip(skip);
+        self
+    }
+
+    pub fn with_skip(mut self, skip: bool) -> Self {
+        self.inner.set_skip(skip);
+        self
+    }
+}
+
+impl<'a, C, S, P, M, O, H, A> Ctor<'a, C, M, (O, Vec<O>), H, A> for SepTwoPlus<C, P, S>
+where
+    P: Ctor<'a, C, M, O, H, A>,
+    S: Regex<C, Ret = Span>,
+    C: Context<'a> + Match<C>,
+    H: Handler<A, Out = M, Error = Error>,
+    A: Extract<'a, C, Span, Out<'a> = A, Error = Error>,
+{
+    #[inline(always)]
+    fn construct(&self, ctx: &mut C, func: &mut H) -> Result<(O, Vec<O>), Error> {
+        // the `inner` matched at least two elements
+        match self.inner.construct(ctx, func) {
+            Ok(mut rest) => Ok((rest.remove(0), rest)),
+            Err(Error::Separate) => Err(Error::TooFew),
+            Err(e) => Err(e),
+        }
+    }
+}
+
+impl<'a, C, S, P> Regex<C> for SepTwoPlus<C, P, S>
+where
+    S: Regex<C, Ret = Span>,
+    P: Regex<C, Ret = Span>,
+    C: Context<'a> + Match<C>,
+{
+    type Ret = Span;
+
+    #[inline(always)]
+    fn try_parse(&self, ctx: &mut C) -> Result<Self::Ret, Error> {
+        match self.inner.try_parse(ctx) {
+            Err(Error::Separate) => Err(Error::TooFew),
+            ret => ret,
+        }
+    }
+}
+
+///
+/// Match regex `P` exactly `N` times, with S as the delimiter.
+///
+/// It will fail if `S` still matches after the `N`th `P`,
+/// so neither more elements nor a trailing delimiter is accepted.
+///
+/// # Ctor
+///
+/// It will return an array of the results.
+/// Return [`Error::SepExactly`] if `P` matched less or more than `N` times.
+///
+/// # Example
+///
+/// ```
+/// # use neure::prelude::*;
+/// #
+/// # fn main() -> color_eyre::Result<()> {
+/// #     color_eyre::install()?;
+///     let num = neu::digit(10).repeat_one_more().map(map::from_str::<u8>());
+///     let rgb = num.sep_exactly::<3, _>(",".ws());
+///
+///     assert_eq!(CharsCtx::new("255, 128, 0").ctor(&rgb)?, [255, 128, 0]);
+///     assert!(CharsCtx::new("255, 128").ctor(&rgb).is_err());
+///     Ok(())
+/// # }
+/// ```
+pub struct SepExactly<C, P, S, const N: usize> {
+    inner: SepSpanned<C, P, S>,
+}
+
+impl<C, P, S, const N: usize> std::ops::Not for SepExactly<C, P, S, N> {
+    type Output = crate::re::RegexNot<Self>;
+
+    fn not(self) -> Self::Output {
+        crate::re::not(self)
+    }
+}
+
+impl<C, P, S, const N: usize> Debug for SepExactly<C, P, S, N>
+where
+    P: Debug,
+    S: Debug,
+{
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("SepExactly")
+            .field("inner", &self.inner)
+            .field("count", &N)
+            .finish()
+    }
+}
+
+impl<C, P, S, const N: usize> Clone for SepExactly<C, P, S, N>
+where
+    P: Clone,
+    S: Clone,
+{
+    fn clone(&self) -> Self {
+        Self {
+            inner: self.inner.clone(),
+        }
+    }
+}
+
+impl<C, P, S, const N: usize> SepExactly<C, P, S, N> {
+    pub fn new(pat: P, sep: S) -> Self {
+        Self {
+            inner: SepSpanned::new(pat, sep).with_capacity(N).at_least(N),
+        }
+    }
+
+    pub fn pat(&self) -> &P {
+        self.inner.pat()
+    }
+
+    pub fn pat_mut(&mut self) -> &mut P {
+        self.inner.pat_mut()
+    }
+
+    pub fn sep(&self) -> &S {
+        self.inner.sep()
+    }
+
+    pub fn sep_mut(&mut self) -> &mut S {
+        self.inner.sep_mut()
+    }
+
+    pub fn set_pat(&mut self, pat: P) -> &mut Self {
+        self.inner.set_pat(pat);
+        self
+    }
+
+    pub fn set_sep(&mut self, sep: S) -> &mut Self {
+        self.inner.set_sep(sep);
+        self
+    }
+}
+
+impl<'a, C, S, P, const N: usize> SepExactly<C, P, S, N>
+where
+    S: Regex<C, Ret = Span>,
+    C: Context<'a> + Match<C>,
+{
+    /// Return true if `S` matches at current offset, the offset will not be changed.
+    fn has_more(&self, ctx: &mut C) -> bool {
+        let offset = ctx.offset();
+        let ret = ctx.is_mat(self.sep());
+
+        ctx.set_offset(offset);
+        ret
+    }
+}
+
+impl<'a, C, S, P, M, O, H, A, const N: usize> Ctor<'a, C, M, [O; N], H, A>
+    for SepExactly<C, P, S, N>
+where
+    P: Ctor<'a, C, M, O, H, A>,
+    S: Regex<C, Ret = Span>,
+    C: Context<'a> + Match<C>,
+    H: Handler<A, Out = M, Error = Error>,
+    A: Extract<'a, C, Span, Out<'a> = A, Error = Error>,
+{
+    #[inline(always)]
+    fn construct(&self, ctx: &mut C, func: &mut H) -> Result<[O; N], Error> {
+        let mut g = CtxGuard::new(ctx);
+        let beg = g.beg();
+        let range: CRange<usize> = (N..N + 1).into();
+
+        trace_v!("sep_exactly", range, beg, ());
+        let ret = match self.inner.construct(g.ctx(), func) {
+            Ok(vals) => {
+                // a trailing delimiter is consumed by `sep`, check it after the last element
+                let end = vals.last().map_or(beg, |(span, _)| span.beg + span.len);
+
+                g.ctx().set_offset(end);
+                if self.has_more(g.ctx()) {
+                    Err(Error::SepExactly)
+                } else {
+                    let vals = vals.into_iter().map(|(_, val)| val).collect();
+
+                    map::to_array().map_to(vals).map_err(|_| Error::SepExactly)
+                }
+            }
+            Err(Error::Separate) => Err(Error::SepExactly),
+            Err(e) => Err(e),
+        };
+        let ret = g.process_ret(ret);
+
+        trace_v!("sep_exactly", range, beg -> g.end(), ret.is_ok(), ret.as_ref().map_or(0, |v| v.len()));
+        ret
+    }
+}
+
+impl<'a, C, S, P, const N: usize> Regex<C> for SepExactly<C, P, S, N>
+where
+    S: Regex<C, Ret = Span>,
+    P: Regex<C, Ret = Span>,
+    C: Context<'a> + Match<C>,
+{
+    type Ret = Span;
+
+    #[inline(always)]
+    fn try_parse(&self, ctx: &mut C) -> Result<Self::Ret, Error> {
+        let mut g = CtxGuard::new(ctx);
+        let mut cnt = 0;
+        let mut span = <Span as Ret>::from_ctx(g.ctx(), (0, 0));
+        let mut ret = Err(Error::SepExactly);
+        let beg = g.beg();
+        let range: CRange<usize> = (N..N + 1).into();
+
+        trace_v!("sep_exactly", range, beg, ());
+        while cnt < N {
+            if cnt > 0 {
+                match g.ctx().try_mat(self.sep()) {
+                    Ok(sep_ret) => span.add_assign(sep_ret),
+                    Err(_) => break,
+                };
+            }
+            match g.ctx().try_mat(self.pat()) {
+                Ok(pat_ret) => span.add_assign(pat_ret),
+                Err(_) => break,
+            };
+            cnt += 1;
+        }
+        if cnt == N && !self.has_more(g.ctx()) {
+            ret = Ok(span);
+        }
+        trace_v!("sep_exactly", range, beg => g.end(), g.process_ret(ret), cnt)
+    }
+}
+
+/// The reason why [`ManyTillBounded`] stopped.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub enum EndReason {
+    /// The terminator matched, it is consumed.
+    /// It is preferred if the terminator matched when the maximum count reached.
+    Terminator,
+
+    /// The maximum count of elements reached, the terminator is not consumed.
+    MaxReached,
+}
+
+///
+/// Match regex `P` until the terminator `T` matched or the count reached `max`.
+///
+/// # Ctor
+///
+/// It will return a `V` that can constructed from `P`'s match results
+/// using [`from_iter`](std::iter::FromIterator::from_iter), and the [`EndReason`].
+/// Return [`Error::ManyTillBounded`] if `P` failed before either condition met.
+///
+/// # Example
+///
+/// ```
+/// # use neure::prelude::*;
+/// # use neure::re::EndReason;
+/// #
+/// # fn main() -> color_eyre::Result<()> {
+/// #     color_eyre::install()?;
+///     let arg = neu::ascii_alphabetic().repeat_one_more().pad(" ");
+///     let args = arg.many_till_bounded::<_, _, Vec<_>>(";", 2);
+///
+///     assert_eq!(
+///         CharsCtx::new("a b ;").ctor(&args)?,
+///         (vec!["a", "b"], EndReason::Terminator)
+///     );
+///     assert_eq!(
+///         CharsCtx::new("a b c ;").ctor(&args)?,
+///         (vec!["a", "b"], EndReason::MaxReached)
+///     );
+///     Ok(())
+/// # }
+/// ```
+#[derive(Default, Copy)]
+pub struct ManyTillBounded<C, P, T, O, V> {
+    pat: P,
+    term: T,
+    max: usize,
+    marker: PhantomData<(C, O, V)>,
+}
+
+def_not!(ManyTillBounded<C, P, T, O, V>);
+
+impl<C, P, T, O, V> Debug for ManyTillBounded<C, P, T, O, V>
+where
+    P: Debug,
+    T: Debug,
+{
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("ManyTillBounded")
+            .field("pat", &self.pat)
+            .field("term", &self.term)
+            .field("max", &self.max)
+            .finish()
+    }
+}
+
+impl<C, P, T, O, V> Clone for ManyTillBounded<C, P, T, O, V>
+where
+    P: Clone,
+    T: Clone,
+{
+    fn clone(&self) -> Self {
+        Self {
+            pat: self.pat.clone(),
+            term: self.term.clone(),
+            max: self.max,
+            marker: self.marker,
+        }
+    }
+}
+
+impl<C, P, T, O, V> ManyTillBounded<C, P, T, O, V> {
+    pub fn new(pat: P, term: T, max: usize) -> Self {
+        Self {
+            pat,
+            term,
+            max,
+            marker: PhantomData,
+        }
+    }
+
+    pub fn pat(&self) -> &P {
+        &self.pat
+    }
+
+    pub fn pat_mut(&mut self) -> &mut P {
+        &mut self.pat
+    }
+
+    pub fn term(&self) -> &T {
+        &self.term
+    }
+
+    pub fn term_mut(&mut self) -> &mut T {
+        &mut self.term
+    }
+
+    pub fn max(&self) -> usize {
+        self.max
+    }
+
+    pub fn set_pat(&mut self, pat: P) -> &mut Self {
+        self.pat = pat;
+        self
+    }
+
+    pub fn set_term(&mut self, term: T) -> &mut Self {
+        self.term = term;
+        self
+    }
+
+    pub fn set_max(&mut self, max: usize) -> &mut Self {
+        self.max = max;
+        self
+    }
+}
+
+impl<'a, C, P, T, M, O, V, H, A> Ctor<'a, C, M, (V, EndReason), H, A>
+    for ManyTillBounded<C, P, T, O, V>
+where
+    V: FromIterator<O>,
+    P: Ctor<'a, C, M, O, H, A>,
+    T: Regex<C, Ret = Span>,
+    C: Context<'a> + Match<C>,
+    H: Handler<A, Out = M, Error = Error>,
+    A: Extract<'a, C, Span, Out<'a> = A, Error = Error>,
+{
+    #[inline(always)]
+    fn construct(&self, ctx: &mut C, func: &mut H) -> Result<(V, EndReason), Error> {
+        let mut g = CtxGuard::new(ctx);
+        let mut res = Vec::with_capacity(self.max.min(16));
+        let beg = g.beg();
+        let range: CRange<usize> = (..=self.max).into();
+
+        trace_v!("many_till_bounded", range, beg, ());
+        let ret = loop {
+            if trace_v!("many_till_bounded", range, beg @ "term", g.ctx().try_mat(&self.term))
+                .is_ok()
+            {
+                break Ok(EndReason::Terminator);
+            }
+            if res.len() >= self.max {
+                break Ok(EndReason::MaxReached);
+            }
+            match self.pat.construct(g.ctx(), func) {
+                Ok(ret) => res.push(ret),
+                Err(e) if e.is_fatal() => break Err(e),
+                Err(_) => break Err(Error::ManyTillBounded),
+            }
+        };
+        trace_v!("many_till_bounded", range, beg -> g.end(), ret.is_ok(), res.len());
+        g.process_ret(ret.map(|reason| (V::from_iter(res), reason)))
+    }
+}
+
+impl<'a, C, P, T, O, V> Regex<C> for ManyTillBounded<C, P, T, O, V>
+where
+    P: Regex<C, Ret = Span>,
+    T: Regex<C, Ret = Span>,
+    C: Context<'a> + Match<C>,
+{
+    type Ret = Span;
+
+    #[inline(always)]
+    fn try_parse(&self, ctx: &mut C) -> Result<Self::Ret, Error> {
+        let mut g = CtxGuard::new(ctx);
+        let mut cnt = 0;
+        let mut span = <Span as Ret>::from_ctx(g.ctx(), (0, 0));
+        let beg = g.beg();
+        let range: CRange<usize> = (..=self.max).into();
+
+        trace_v!("many_till_bounded", range, beg, ());
+        let ret = loop {
+            if let Ok(ret) = g.try_mat(&self.term) {
+                span.add_assign(ret);
+                break Ok(span);
+            }
+            if cnt >= self.max {
+                break Ok(span);
+            }
+            match g.try_mat(&self.pat) {
+                Ok(ret) => {
+                    cnt += 1;
+                    span.add_assign(ret);
+                }
+                Err(e) if e.is_fatal() => break Err(e),
+                Err(_) => break Err(Error::ManyTillBounded),
+            }
+        };
+
+        trace_v!("many_till_bounded", range, beg => g.end(), g.process_ret(ret), cnt)
+    }
+}
+
+///
+/// Match regex `P` as many times as possible, every `P` must be followed by the delimiter `S`.
+///
+/// # Ctor
+///
+/// It will return a [`Vec`] of `P`'s match results.
+/// Return [`Error::SepEndBy`] if any `P` is not followed by `S`, or `P` matched less than `min` times.
+/// The default `min` is 1.
+///
+/// # Example
+///
+/// ```
+/// # use neure::prelude::*;
+/// #
+/// # fn main() -> color_eyre::Result<()> {
+/// #     color_eyre::install()?;
+///     let stmt = neu::ascii_alphabetic().repeat_one_more();
+///     let stmts = stmt.sep_end_by(";".ws()).quote("{".ws(), "}");
+///
+///     assert_eq!(CharsCtx::new("{ a; b; c; }").ctor(&stmts)?, ["a", "b", "c"]);
+///     assert!(CharsCtx::new("{ a; b; c }").ctor(&stmts).is_err());
+///     Ok(())
+/// # }
+/// ```
+#[derive(Default, Copy)]
+pub struct SepEndBy<C, P, S> {
+    pat: P,
+    sep: S,
+    min: usize,
+    marker: PhantomData<C>,
+}
+
+def_not!(SepEndBy<C, P, S>);
+
+impl<C, P, S> Debug for SepEndBy<C, P, S>
+where
+    P: Debug,
+    S: Debug,
+{
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("SepEndBy")
+            .field("pat", &self.pat)
+            .field("sep", &self.sep)
+            .field("min", &self.min)
+            .finish()
+    }
+}
+
+impl<C, P, S> Clone for SepEndBy<C, P, S>
+where
+    P: Clone,
+    S: Clone,
+{
+    fn clone(&self) -> Self {
+        Self {
+            pat: self.pat.clone(),
+            sep: self.sep.clone(),
+            min: self.min,
+            marker: self.marker,
+        }
+    }
+}
+
+impl<C, P, S> SepEndBy<C, P, S> {
+    pub fn new(pat: P, sep: S) -> Self {
+        Self {
+            pat,
+            sep,
+            min: 1,
+            marker: PhantomData,
+        }
+    }
+
+    pub fn pat(&self) -> &P {
+        &self.pat
+    }
+
+    pub fn pat_mut(&mut self) -> &mut P {
+        &mut self.pat
+    }
+
+    pub fn sep(&self) -> &S {
+        &self.sep
+    }
+
+    pub fn sep_mut(&mut self) -> &mut S {
+        &mut self.sep
+    }
+
+    pub fn min(&self) -> usize {
+        self.min
+    }
+
+    pub fn set_pat(&mut self, pat: P) -> &mut Self {
+        self.pat = pat;
+        self
+    }
+
+    pub fn set_sep(&mut self, sep: S) -> &mut Self {
+        self.sep = sep;
+        self
+    }
+
+    pub fn set_min(&mut self, min: usize) -> &mut Self {
+        self.min = min;
+        self
+    }
+
+    pub fn at_least(mut self, min: usize) -> Self {
+        self.min = min;
+        self
+    }
+}
+
+impl<'a, C, S, P, M, O, H, A> Ctor<'a, C, M, Vec<O>, H, A> for SepEndBy<C, P, S>
+where
+    P: Ctor<'a, C, M, O, H, A>,
+    S: Regex<C, Ret = Span>,
+    C: Context<'a> + Match<C>,
+    H: Handler<A, Out = M, Error = Error>,
+    A: Extract<'a, C, Span, Out<'a> = A, Error = Error>,
+{
+    #[inline(always)]
+    fn construct(&self, ctx: &mut C, func: &mut H) -> Result<Vec<O>, Error> {
+        let mut g = CtxGuard::new(ctx);
+        let mut res = Vec::with_capacity(self.min);
+        let mut ret = Ok(());
+        let beg = g.beg();
+        let range: CRange<usize> = (self.min..).into();
+
+        trace_v!("sep_end_by", range, beg, ());
+        let mut offset = g.end();
+
+        while let Some(val) = g.non_fatal(|ctx| self.pat.construct(ctx, func))? {
+            let sep_ret = trace_v!("sep_end_by", range, beg @ "sep", g.ctx().try_mat(&self.sep));
+
+            if sep_ret.is_err() {
+                ret = Err(Error::SepEndBy);
+                break;
+            }
+            res.push(val);
+            if g.is_stuck(offset) {
+                break;
+            }
+            offset = g.end();
+        }
+        let len = res.len();
+        let ret = g.process_ret(match ret {
+            Ok(_) if len >= self.min => Ok(res),
+            _ => Err(Error::SepEndBy),
+        });
+
+        trace_v!("sep_end_by", range, beg -> g.end(), ret.is_ok(), len);
+        ret
+    }
+}
+
+impl<'a, C, S, P> Regex<C> for SepEndBy<C, P, S>
+where
+    S: Regex<C, Ret = Span>,
+    P: Regex<C, Ret = Span>,
+    C: Context<'a> + Match<C>,
+{
+    type Ret = Span;
+
+    #[inline(always)]
+    fn try_parse(&self, ctx: &mut C) -> Result<Self::Ret, Error> {
+        let mut g = CtxGuard::new(ctx);
+        let mut cnt = 0;
+        let mut span = <Span as Ret>::from_ctx(g.ctx(), (0, 0));
+        let mut ret = Ok(());
+        let beg = g.beg();
+        let range: CRange<usize> = (self.min..).into();
+
+        trace_v!("sep_end_by", range, beg, ());
+        let mut offset = g.end();
+
+        while let Some(val) = g.non_fatal(|ctx| ctx.try_mat(&self.pat))? {
+            match g.ctx().try_mat(&self.sep) {
+                Ok(sep_ret) => {
+                    cnt += 1;
+                    span.add_assign(val);
+                    span.add_assign(sep_ret);
+                }
+                Err(_) => {
+                    ret = Err(Error::SepEndBy);
+                    break;
+                }
+            }
+            if g.is_stuck(offset) {
+                break;
+            }
+            offset = g.end();
+        }
+        let ret = match ret {
+            Ok(_) if cnt >= self.min => Ok(span),
+            _ => Err(Error::SepEndBy),
+        };
+
+        trace_v!("sep_end_by", range, beg => g.end(), g.process_ret(ret), cnt)
+    }
+}
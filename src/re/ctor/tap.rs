@@ -0,0 +1,158 @@
+use std::fmt::Debug;
+use std::marker::PhantomData;
+
+use crate::ctx::Context;
+use crate::ctx::CtxGuard;
+use crate::ctx::Match;
+use crate::ctx::Span;
+use crate::err::Error;
+use crate::re::def_not;
+use crate::re::trace;
+use crate::re::Ctor;
+use crate::re::Extract;
+use crate::re::Handler;
+use crate::re::Regex;
+
+///
+/// Match `P` and pass the [`Span`] of `P` to the callback `F` if it succeeds.
+///
+/// The callback is called once for every successful match of `P`, so it is called once
+/// per element when used inside a repeated combinator such as [`sep`](crate::re::ConstructOp::sep).
+/// Note the callback is not undone when an outer combinator backtracks.
+/// Use [`Cell`](std::cell::Cell) or [`RefCell`](std::cell::RefCell) in `F` to record the spans.
+///
+/// # Ctor
+///
+/// It will return the result of `P`.
+///
+/// # Example
+///
+/// ```
+/// # use std::cell::RefCell;
+/// #
+/// # use neure::prelude::*;
+/// #
+/// # fn main() -> color_eyre::Result<()> {
+/// #     color_eyre::install()?;
+///     let spans = RefCell::new(vec![]);
+///     let word = neu::ascii_alphabetic().repeat_one_more();
+///     let words = word.tap_spans(|span: &Span| spans.borrow_mut().push(*span)).sep(" ");
+///
+///     assert_eq!(CharsCtx::new("let it be").ctor(&words)?, ["let", "it", "be"]);
+///     assert_eq!(
+///         spans.take(),
+///         [Span::new(0, 3), Span::new(4, 2), Span::new(7, 2)]
+///     );
+///     Ok(())
+/// # }
+/// ```
+#[derive(Default, Copy)]
+pub struct TapSpans<C, P, F> {
+    pat: P,
+    func: F,
+    marker: PhantomData<C>,
+}
+
+def_not!(TapSpans<C, P, F>);
+
+impl<C, P, F> Debug for TapSpans<C, P, F>
+where
+    P: Debug,
+{
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("TapSpans").field("pat", &self.pat).finish()
+    }
+}
+
+impl<C, P, F> Clone for TapSpans<C, P, F>
+where
+    P: Clone,
+    F: Clone,
+{
+    fn clone(&self) -> Self {
+        Self {
+            pat: self.pat.clone(),
+            func: self.func.clone(),
+            marker: self.marker,
+        }
+    }
+}
+
+impl<C, P, F> TapSpans<C, P, F> {
+    pub fn new(pat: P, func: F) -> Self {
+        Self {
+            pat,
+            func,
+            marker: PhantomData,
+        }
+    }
+
+    pub fn pat(&self) -> &P {
+        &self.pat
+    }
+
+    pub fn pat_mut(&mut self) -> &mut P {
+        &mut self.pat
+    }
+
+    pub fn func(&self) -> &F {
+        &self.func
+    }
+
+    pub fn func_mut(&mut self) -> &mut F {
+        &mut self.func
+    }
+
+    pub fn set_pat(&mut self, pat: P) -> &mut Self {
+        self.pat = pat;
+        self
+    }
+
+    pub fn set_func(&mut self, func: F) -> &mut Self {
+        self.func = func;
+        self
+    }
+}
+
+impl<'a, C, P, F, M, O, H, A> Ctor<'a, C, M, O, H, A> for TapSpans<C, P, F>
+where
+    P: Ctor<'a, C, M, O, H, A>,
+    F: Fn(&Span),
+    C: Context<'a> + Match<C>,
+    H: Handler<A, Out = M, Error = Error>,
+    A: Extract<'a, C, Span, Out<'a> = A, Error = Error>,
+{
+    #[inline(always)]
+    fn construct(&self, ctx: &mut C, func: &mut H) -> Result<O, Error> {
+        let mut g = CtxGuard::new(ctx);
+        let beg = g.beg();
+        let ret = trace!("tap_spans", beg @ "pat", self.pat.construct(g.ctx(), func));
+
+        if ret.is_ok() {
+            (self.func)(&Span::new(beg, g.end() - beg));
+        }
+        trace!("tap_spans", beg -> g.end(), ret.is_ok());
+        g.process_ret(ret)
+    }
+}
+
+impl<'a, C, P, F> Regex<C> for TapSpans<C, P, F>
+where
+    P: Regex<C, Ret = Span>,
+    F: Fn(&Span),
+    C: Context<'a> + Match<C>,
+{
+    type Ret = P::Ret;
+
+    #[inline(always)]
+    fn try_parse(&self, ctx: &mut C) -> Result<Self::Ret, Error> {
+        let mut g = CtxGuard::new(ctx);
+        let beg = g.beg();
+        let ret = trace!("tap_spans", beg @ "pat", g.try_mat(&self.pat));
+
+        if let Ok(span) = &ret {
+            (self.func)(span);
+        }
+        trace!("tap_spans", beg => g.end(), g.process_ret(ret))
+    }
+}
@@ -77,6 +77,8 @@ where
             if ret.is_ok() {
                 trace!("vector", beg -> g.end(), true);
                 return ret;
+            } else if ret.as_ref().is_err_and(Error::is_fatal) {
+                return g.process_ret(ret);
             } else {
                 g.reset();
             }
@@ -103,6 +105,8 @@ where
             if ret.is_ok() {
                 trace!("vector", beg => g.end(), true);
                 return ret;
+            } else if ret.as_ref().is_err_and(Error::is_fatal) {
+                return g.process_ret(ret);
             } else {
                 g.reset();
             }
@@ -183,6 +187,8 @@ where {
             if ret.is_ok() {
                 trace!("pair_vec", beg -> g.end(), true);
                 return Ok((ret?, value.clone()));
+            } else if ret.as_ref().is_err_and(Error::is_fatal) {
+                return g.process_ret(ret).map(|ret| (ret, value.clone()));
             } else {
                 g.reset();
             }
@@ -209,6 +215,8 @@ where
             if ret.is_ok() {
                 trace!("pair_vec", beg => g.end(), true);
                 return ret;
+            } else if ret.as_ref().is_err_and(Error::is_fatal) {
+                return g.process_ret(ret);
             } else {
                 g.reset();
             }
@@ -216,3 +224,104 @@ where
         Err(Error::PairVec)
     }
 }
+
+///
+/// Iterate over the vector and match the regex against the [`Context`].
+///
+/// # Ctor
+///
+/// Return a pair of the index and the result of first regex that matches.
+///
+/// # Example
+///
+/// ```
+/// # use neure::prelude::*;
+/// #
+/// # fn main() -> color_eyre::Result<()> {
+/// #     color_eyre::install()?;
+///     let vec = re::vector_indexed(["a", "b", "c"]);
+///
+///     assert_eq!(CharsCtx::new("cab").ctor(&vec)?, (2, "c"));
+///     Ok(())
+/// # }
+/// ```
+#[derive(Debug, Clone)]
+pub struct IndexedVector<T>(Vec<T>);
+
+def_not!(IndexedVector<T>);
+
+impl<T> IndexedVector<T> {
+    pub fn new(val: Vec<T>) -> Self {
+        Self(val)
+    }
+}
+
+impl<T> Deref for IndexedVector<T> {
+    type Target = Vec<T>;
+
+    fn deref(&self) -> &Self::Target {
+        &self.0
+    }
+}
+
+impl<T> DerefMut for IndexedVector<T> {
+    fn deref_mut(&mut self) -> &mut Self::Target {
+        &mut self.0
+    }
+}
+
+impl<'a, C, T, M, O, H, A> Ctor<'a, C, M, (usize, O), H, A> for IndexedVector<T>
+where
+    T: Ctor<'a, C, M, O, H, A>,
+    C: Context<'a> + Match<C>,
+    H: Handler<A, Out = M, Error = Error>,
+    A: Extract<'a, C, Span, Out<'a> = A, Error = Error>,
+{
+    #[inline(always)]
+    fn construct(&self, ctx: &mut C, func: &mut H) -> Result<(usize, O), Error> {
+        let mut g = CtxGuard::new(ctx);
+        let beg = g.beg();
+
+        for (idx, regex) in self.0.iter().enumerate() {
+            let ret = trace!("indexed_vec", beg, regex.construct(g.ctx(), func));
+
+            if ret.is_ok() {
+                trace!("indexed_vec", beg -> g.end(), true);
+                return Ok((idx, ret?));
+            } else if ret.as_ref().is_err_and(Error::is_fatal) {
+                return g.process_ret(ret).map(|ret| (idx, ret));
+            } else {
+                g.reset();
+            }
+        }
+        Err(Error::Vec)
+    }
+}
+
+impl<'a, C, T> Regex<C> for IndexedVector<T>
+where
+    T: Regex<C>,
+    C: Context<'a> + Match<C>,
+{
+    type Ret = T::Ret;
+
+    #[inline(always)]
+    fn try_parse(&self, ctx: &mut C) -> Result<Self::Ret, Error> {
+        let mut g = CtxGuard::new(ctx);
+        let beg = g.beg();
+
+        for regex in self.0.iter() {
+            let ret = trace!("indexed_vec", beg, g.try_mat(regex));
+
+            if ret.is_ok() {
+                trace!("indexed_vec", beg => g.end(), true);
+                return ret;
+            } else if ret.as_ref().is_err_and(Error::is_fatal) {
+                return g.process_ret(ret);
+            } else {
+                g.reset();
+            }
+        }
+        Err(Error::Vec)
+    }
+}
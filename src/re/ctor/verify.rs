@@ -0,0 +1,150 @@
+use std::fmt::Debug;
+use std::marker::PhantomData;
+
+use crate::ctx::Context;
+use crate::ctx::CtxGuard;
+use crate::ctx::Match;
+use crate::ctx::Span;
+use crate::err::Error;
+use crate::re::def_not;
+use crate::re::trace;
+use crate::re::Ctor;
+use crate::re::Extract;
+use crate::re::Handler;
+use crate::re::Regex;
+
+///
+/// Construct the value of `P` and check it with the predicate `F`,
+/// return [`Error::Verify`] and restore the offset if the predicate returns false.
+///
+/// # Ctor
+///
+/// It will return the result of `P` if the predicate passed.
+///
+/// # Regex
+///
+/// It will return the span of `P`, the predicate will not be called.
+///
+/// # Example
+///
+/// ```
+/// # use neure::prelude::*;
+/// #
+/// # fn main() -> color_eyre::Result<()> {
+/// #     color_eyre::install()?;
+///     let name = neu::ascii_alphabetic().repeat_one_more();
+///     let name = name.verify(|v: &&str| !["if", "else"].contains(v));
+///     let mut ctx = CharsCtx::new("else");
+///
+///     assert_eq!(CharsCtx::new("foo").ctor(&name)?, "foo");
+///     assert!(ctx.ctor(&name).is_err());
+///     assert_eq!(ctx.offset(), 0);
+///     Ok(())
+/// # }
+/// ```
+#[derive(Default, Copy)]
+pub struct Verify<C, P, F> {
+    pat: P,
+    func: F,
+    marker: PhantomData<C>,
+}
+
+def_not!(Verify<C, P, F>);
+
+impl<C, P, F> Debug for Verify<C, P, F>
+where
+    P: Debug,
+{
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("Verify").field("pat", &self.pat).finish()
+    }
+}
+
+impl<C, P, F> Clone for Verify<C, P, F>
+where
+    P: Clone,
+    F: Clone,
+{
+    fn clone(&self) -> Self {
+        Self {
+            pat: self.pat.clone(),
+            func: self.func.clone(),
+            marker: self.marker,
+        }
+    }
+}
+
+impl<C, P, F> Verify<C, P, F> {
+    pub fn new(pat: P, func: F) -> Self {
+        Self {
+            pat,
+            func,
+            marker: PhantomData,
+        }
+    }
+
+    pub fn pat(&self) -> &P {
+        &self.pat
+    }
+
+    pub fn pat_mut(&mut self) -> &mut P {
+        &mut self.pat
+    }
+
+    pub fn func(&self) -> &F {
+        &self.func
+    }
+
+    pub fn func_mut(&mut self) -> &mut F {
+        &mut self.func
+    }
+
+    pub fn set_pat(&mut self, pat: P) -> &mut Self {
+        self.pat = pat;
+        self
+    }
+
+    pub fn set_func(&mut self, func: F) -> &mut Self {
+        self.func = func;
+        self
+    }
+}
+
+impl<'a, C, P, F, M, O, H, A> Ctor<'a, C, M, O, H, A> for Verify<C, P, F>
+where
+    P: Ctor<'a, C, M, O, H, A>,
+    F: Fn(&O) -> bool,
+    C: Context<'a> + Match<C>,
+    H: Handler<A, Out = M, Error = Error>,
+    A: Extract<'a, C, Span, Out<'a> = A, Error = Error>,
+{
+    #[inline(always)]
+    fn construct(&self, ctx: &mut C, func: &mut H) -> Result<O, Error> {
+        let mut g = CtxGuard::new(ctx);
+        let beg = g.beg();
+        let ret = trace!("verify", beg @ "pat", self.pat.construct(g.ctx(), func));
+        let ret = ret.and_then(|val| {
+            if (self.func)(&val) {
+                Ok(val)
+            } else {
+                Err(Error::Verify)
+            }
+        });
+
+        trace!("verify", beg -> g.end(), ret.is_ok());
+        g.process_ret(ret)
+    }
+}
+
+impl<'a, C, P, F> Regex<C> for Verify<C, P, F>
+where
+    P: Regex<C, Ret = Span>,
+    C: Context<'a> + Match<C>,
+{
+    type Ret = P::Ret;
+
+    #[inline(always)]
+    fn try_parse(&self, ctx: &mut C) -> Result<Self::Ret, Error> {
+        ctx.try_mat(&self.pat)
+    }
+}
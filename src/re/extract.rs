@@ -100,8 +100,8 @@ impl<'a, C: Context<'a, Orig = str>, R: Ret> Extract<'a, C, R> for &'a str {
     }
 }
 
-impl<'a, C: Context<'a, Orig = [u8]>, R: Ret> Extract<'a, C, R> for &'a [u8] {
-    type Out<'b> = &'b [u8];
+impl<'a, T: 'a, C: Context<'a, Orig = [T]>, R: Ret> Extract<'a, C, R> for &'a [T] {
+    type Out<'b> = &'a [T];
 
     type Error = Error;
 
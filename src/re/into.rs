@@ -161,6 +161,11 @@ where
     where
         C: Context<'a> + Match<C>,
         Self: Ctor<'a, C, M, O, H, A> + 'b;
+
+    fn boxed_local<'a, 'b, C, M, O, H, A>(self) -> Box<dyn Ctor<'a, C, M, O, H, A> + 'b>
+    where
+        C: Context<'a> + Match<C>,
+        Self: Ctor<'a, C, M, O, H, A> + 'b;
 }
 
 impl<T> ConstructIntoOp for T
@@ -303,4 +308,38 @@ where
             value: DynamicRcCtor::new(self),
         }
     }
+
+    ///
+    /// Return a boxed trait object of `Ctor`, which keeps the lifetime `'b` of `Self`.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use neure::prelude::*;
+    /// #
+    /// # fn main() -> color_eyre::Result<()> {
+    /// #     color_eyre::install()?;
+    ///     let keywords = vec![String::from("let"), String::from("fn")];
+    ///     let keyword = |ctx: &mut CharsCtx| {
+    ///         for (idx, keyword) in keywords.iter().enumerate() {
+    ///             if ctx.try_mat(&keyword.as_str()).is_ok() {
+    ///                 return Ok(idx);
+    ///             }
+    ///         }
+    ///         Err(neure::err::Error::Vec)
+    ///     };
+    ///     let keyword = keyword.boxed_local();
+    ///
+    ///     assert_eq!(CharsCtx::new("fn main").ctor(&keyword)?, 1);
+    ///     assert!(CharsCtx::new("struct").ctor(&keyword).is_err());
+    ///     Ok(())
+    /// # }
+    /// ```
+    fn boxed_local<'a, 'b, C, M, O, H, A>(self) -> Box<dyn Ctor<'a, C, M, O, H, A> + 'b>
+    where
+        C: Context<'a> + Match<C>,
+        Self: Ctor<'a, C, M, O, H, A> + 'b,
+    {
+        Box::new(self)
+    }
 }
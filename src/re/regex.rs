@@ -1,18 +1,45 @@
+mod anchor;
+mod ascii;
+mod balanced;
+mod binary;
 mod boxed;
 mod dthen;
+mod duration;
 mod dynamic;
+mod float;
+mod int;
+mod lex;
 mod literal;
+mod lookahead;
 mod not;
-
+mod take;
+mod trivia;
+
+pub use self::anchor::AtColumn;
+pub use self::anchor::WordBoundary;
+pub use self::ascii::RequireAscii;
+pub use self::balanced::Balanced;
+pub use self::binary::ReadArrayLe;
 pub use self::boxed::BoxedRegex;
 pub use self::dthen::DynamicCreateRegexThen;
 pub use self::dthen::DynamicCreateRegexThenHelper;
+pub use self::duration::HumanDuration;
 pub use self::dynamic::DynamicArcRegex;
 pub use self::dynamic::DynamicBoxedRegex;
 pub use self::dynamic::DynamicRcRegex;
+pub use self::float::HexFloat;
+pub use self::int::IntWithSeparators;
+pub use self::lex::LexStep;
+pub use self::literal::Keyword;
 pub use self::literal::LitSlice;
+pub use self::literal::LitSliceNoCase;
 pub use self::literal::LitString;
+pub use self::literal::LitStringNoCase;
+pub use self::lookahead::FollowedBy;
 pub use self::not::RegexNot;
+pub use self::take::TakeTill;
+pub use self::take::TakeUntil;
+pub use self::trivia::SkipTrivia;
 
 use crate::ctx::Context;
 use crate::ctx::Match;
@@ -114,6 +141,52 @@ where
     }
 }
 
+/// Success if no [`Item`](crate::ctx::Context::Item) remains, otherwise return [`Error::TrailingInput`].
+///
+/// Unlike [`AnchorEnd`], the error tells the caller that the input is not fully consumed.
+#[derive(Debug, Clone, Default, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct Eof;
+
+def_not!(Eof);
+
+impl Eof {
+    pub fn new() -> Self {
+        Self {}
+    }
+}
+
+impl<'a, C, O, H, A> Ctor<'a, C, O, O, H, A> for Eof
+where
+    C: Context<'a> + Match<C>,
+    H: Handler<A, Out = O, Error = Error>,
+    A: Extract<'a, C, Span, Out<'a> = A, Error = Error>,
+{
+    #[inline(always)]
+    fn construct(&self, ctx: &mut C, func: &mut H) -> Result<O, Error> {
+        let ret = ctx.try_mat(self)?;
+
+        func.invoke(A::extract(ctx, &ret)?)
+    }
+}
+
+impl<'a, C> Regex<C> for Eof
+where
+    C: Context<'a>,
+{
+    type Ret = Span;
+
+    #[inline(always)]
+    fn try_parse(&self, ctx: &mut C) -> Result<Self::Ret, crate::err::Error> {
+        let mut ret = Err(Error::TrailingInput);
+        let beg = ctx.offset();
+
+        if ctx.len() == ctx.offset() {
+            ret = Ok(<Span as Ret>::from_ctx(ctx, (0, 0)));
+        }
+        trace!("eof", beg => ctx.offset(), ret)
+    }
+}
+
 /// Consume the specified number [`Item`](crate::ctx::Context::Item)s.
 #[derive(Debug, Clone, Default, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
 pub struct Consume(usize);
@@ -159,6 +232,56 @@ where
     }
 }
 
+/// Consume exactly the specified number of bytes, the width is counted in the
+/// offset unit of [`Context`], and the end of the field must be a valid boundary.
+#[derive(Debug, Clone, Default, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct Field(usize);
+
+def_not!(Field);
+
+impl Field {
+    pub fn new(width: usize) -> Self {
+        Self(width)
+    }
+
+    pub fn width(&self) -> usize {
+        self.0
+    }
+}
+
+impl<'a, C, O, H, A> Ctor<'a, C, O, O, H, A> for Field
+where
+    C: Context<'a> + Match<C> + 'a,
+    H: Handler<A, Out = O, Error = Error>,
+    A: Extract<'a, C, Span, Out<'a> = A, Error = Error>,
+{
+    #[inline(always)]
+    fn construct(&self, ctx: &mut C, func: &mut H) -> Result<O, Error> {
+        let ret = ctx.try_mat(self)?;
+
+        func.invoke(A::extract(ctx, &ret)?)
+    }
+}
+
+impl<'a, C> Regex<C> for Field
+where
+    C: Context<'a> + 'a,
+{
+    type Ret = Span;
+
+    #[inline(always)]
+    fn try_parse(&self, ctx: &mut C) -> Result<Self::Ret, crate::err::Error> {
+        let mut ret = Err(Error::Field);
+        let beg = ctx.offset();
+
+        if ctx.len() - beg >= self.0 && ctx.orig_sub(beg, self.0).is_ok() {
+            ctx.inc(self.0);
+            ret = Ok(Span::new(beg, self.0));
+        }
+        trace!("field", beg => ctx.offset(), ret)
+    }
+}
+
 /// Consume all remaining [`Item`](crate::ctx::Context::Item)s of the [`Context`].
 #[derive(Debug, Clone, Default, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
 pub struct ConsumeAll;
@@ -0,0 +1,185 @@
+use crate::ctx::Context;
+use crate::ctx::Match;
+use crate::ctx::Span;
+use crate::err::Error;
+use crate::neu::Neu;
+use crate::neu::Word;
+use crate::re::def_not;
+use crate::re::trace;
+use crate::re::Ctor;
+use crate::re::Extract;
+use crate::re::Handler;
+use crate::re::Regex;
+
+/// Success if the column of current offset is equal to `col`.
+///
+/// The column is 1-based and counted in the items of [`Context`] after the last `\n`,
+/// which means chars for [`CharsCtx`](crate::ctx::CharsCtx) and bytes for [`BytesCtx`](crate::ctx::BytesCtx).
+/// It is the same as the column of [`line_col`](crate::ctx::RegexCtx::line_col).
+/// It will not consume any data.
+///
+/// # Regex
+///
+/// Return a zero-length [`Span`] at current offset, or [`Error::AtColumn`] if the column not match.
+#[derive(Debug, Clone, Default, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct AtColumn(usize);
+
+def_not!(AtColumn);
+
+impl AtColumn {
+    pub fn new(col: usize) -> Self {
+        Self(col)
+    }
+
+    pub fn col(&self) -> usize {
+        self.0
+    }
+
+    /// Return the 1-based column of `offset`.
+    pub fn column_of<'a, C>(ctx: &C, offset: usize) -> Result<usize, Error>
+    where
+        C::Orig: AsRef<[u8]>,
+        C: Context<'a> + 'a,
+    {
+        let head = ctx.orig_sub(0, offset)?.as_ref();
+        let line_beg = head
+            .iter()
+            .rposition(|v| *v == b'\n')
+            .map_or(0, |pos| pos + 1);
+
+        if line_beg == offset {
+            return Ok(1);
+        }
+        // the index of peek_at is relative to line_beg
+        let cnt = ctx
+            .peek_at(line_beg)?
+            .take_while(|(idx, _)| line_beg + idx < offset)
+            .count();
+
+        Ok(cnt + 1)
+    }
+}
+
+impl<'a, C, O, H, A> Ctor<'a, C, O, O, H, A> for AtColumn
+where
+    C::Orig: AsRef<[u8]>,
+    C: Context<'a> + Match<C> + 'a,
+    H: Handler<A, Out = O, Error = Error>,
+    A: Extract<'a, C, Span, Out<'a> = A, Error = Error>,
+{
+    #[inline(always)]
+    fn construct(&self, ctx: &mut C, func: &mut H) -> Result<O, Error> {
+        let ret = ctx.try_mat(self)?;
+
+        func.invoke(A::extract(ctx, &ret)?)
+    }
+}
+
+impl<'a, C> Regex<C> for AtColumn
+where
+    C::Orig: AsRef<[u8]>,
+    C: Context<'a> + 'a,
+{
+    type Ret = Span;
+
+    #[inline(always)]
+    fn try_parse(&self, ctx: &mut C) -> Result<Self::Ret, crate::err::Error> {
+        let beg = ctx.offset();
+        let found = Self::column_of(ctx, beg)?;
+        let ret = if found == self.0 {
+            Ok(Span::new(beg, 0))
+        } else {
+            Err(Error::AtColumn {
+                expect: self.0,
+                found,
+            })
+        };
+
+        trace!("at_column", beg => ctx.offset(), ret)
+    }
+}
+
+/// Success if the current offset is on a word boundary, like `\b` in regex.
+///
+/// It is a boundary if the previous item and the current item differ in
+/// [`word`](crate::neu::word) membership, the beginning and the end of data are treated
+/// as non-word. The previous item is decoded backward, so multi-byte chars of
+/// [`CharsCtx`](crate::ctx::CharsCtx) are handled correctly.
+/// If created by [`non_word_boundary`](crate::re::non_word_boundary), the result is inverted.
+///
+/// # Regex
+///
+/// Return a zero-length [`Span`] at current offset, or [`Error::WordBoundary`] if not match.
+#[derive(Debug, Clone, Default, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct WordBoundary {
+    negated: bool,
+}
+
+def_not!(WordBoundary);
+
+impl WordBoundary {
+    pub fn new() -> Self {
+        Self { negated: false }
+    }
+
+    pub fn negated(&self) -> bool {
+        self.negated
+    }
+
+    pub fn set_negated(&mut self, negated: bool) -> &mut Self {
+        self.negated = negated;
+        self
+    }
+
+    pub fn with_negated(mut self, negated: bool) -> Self {
+        self.negated = negated;
+        self
+    }
+}
+
+impl<'a, C, O, H, A> Ctor<'a, C, O, O, H, A> for WordBoundary
+where
+    Word: Neu<C::Item>,
+    C: Context<'a> + Match<C> + 'a,
+    H: Handler<A, Out = O, Error = Error>,
+    A: Extract<'a, C, Span, Out<'a> = A, Error = Error>,
+{
+    #[inline(always)]
+    fn construct(&self, ctx: &mut C, func: &mut H) -> Result<O, Error> {
+        let ret = ctx.try_mat(self)?;
+
+        func.invoke(A::extract(ctx, &ret)?)
+    }
+}
+
+impl<'a, C> Regex<C> for WordBoundary
+where
+    Word: Neu<C::Item>,
+    C: Context<'a> + 'a,
+{
+    type Ret = Span;
+
+    #[inline(always)]
+    fn try_parse(&self, ctx: &mut C) -> Result<Self::Ret, crate::err::Error> {
+        let beg = ctx.offset();
+        let is_word = |offset: usize| {
+            ctx.peek_at(offset)
+                .ok()
+                .and_then(|mut iter| iter.next())
+                .is_some_and(|(_, item)| Word.is_match(&item))
+        };
+        // the start of previous item is the nearest offset can be peeked
+        let prev = (0..beg)
+            .rev()
+            .find(|offset| ctx.peek_at(*offset).is_ok())
+            .is_some_and(is_word);
+        let next = is_word(beg);
+        let ret = if (prev != next) != self.negated {
+            Ok(Span::new(beg, 0))
+        } else {
+            Err(Error::WordBoundary)
+        };
+
+        trace!("word_boundary", beg => ctx.offset(), ret)
+    }
+}
@@ -0,0 +1,66 @@
+use crate::ctx::Context;
+use crate::ctx::Match;
+use crate::ctx::Span;
+use crate::err::Error;
+use crate::re::def_not;
+use crate::re::trace;
+use crate::re::Ctor;
+use crate::re::Extract;
+use crate::re::Handler;
+use crate::re::Regex;
+
+///
+/// Check all the remaining data are ASCII, the offset will not be changed.
+///
+/// The check scans the [`Orig`](crate::ctx::Context::Orig) as bytes at once,
+/// it is faster than matching the data item by item.
+///
+/// # Ctor
+///
+/// Return [`Orig`](crate::ctx::Context::Orig) with the zero-length [`Span`] as the index,
+/// or [`Error::NonAscii`] with the offset of first non-ASCII byte.
+#[derive(Debug, Clone, Default, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct RequireAscii;
+
+def_not!(RequireAscii);
+
+impl RequireAscii {
+    pub fn new() -> Self {
+        Self {}
+    }
+}
+
+impl<'a, C, O, H, A> Ctor<'a, C, O, O, H, A> for RequireAscii
+where
+    C::Orig: AsRef<[u8]>,
+    C: Context<'a> + Match<C> + 'a,
+    H: Handler<A, Out = O, Error = Error>,
+    A: Extract<'a, C, Span, Out<'a> = A, Error = Error>,
+{
+    #[inline(always)]
+    fn construct(&self, ctx: &mut C, func: &mut H) -> Result<O, Error> {
+        let ret = ctx.try_mat(self)?;
+
+        func.invoke(A::extract(ctx, &ret)?)
+    }
+}
+
+impl<'a, C> Regex<C> for RequireAscii
+where
+    C::Orig: AsRef<[u8]>,
+    C: Context<'a> + 'a,
+{
+    type Ret = Span;
+
+    #[inline(always)]
+    fn try_parse(&self, ctx: &mut C) -> Result<Self::Ret, crate::err::Error> {
+        let beg = ctx.offset();
+        let bytes = ctx.orig()?.as_ref();
+        let ret = match bytes.iter().position(|v| !v.is_ascii()) {
+            Some(pos) => Err(Error::NonAscii { at: beg + pos }),
+            None => Ok(Span::new(beg, 0)),
+        };
+
+        trace!("require_ascii", beg => ctx.offset(), ret)
+    }
+}
@@ -0,0 +1,135 @@
+use crate::ctx::Context;
+use crate::ctx::CtxGuard;
+use crate::ctx::Match;
+use crate::ctx::Ret;
+use crate::ctx::Span;
+use crate::err::Error;
+use crate::re::def_not;
+use crate::re::trace;
+use crate::re::Ctor;
+use crate::re::Extract;
+use crate::re::Handler;
+use crate::re::Regex;
+
+///
+/// Match a group start with `L` and end with `R`, the nested groups are skipped.
+///
+/// # Ctor
+///
+/// Return [`Orig`](crate::ctx::Context::Orig) with the [`Span`] as the index.
+/// The span contains the outermost delimiters unless it created by
+/// [`inside_balanced`](crate::re::inside_balanced), but the delimiters are always consumed.
+/// It will return [`Error::Balanced`] if the group is not closed.
+#[derive(Debug, Clone, Default, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct Balanced<L, R> {
+    open: L,
+    close: R,
+    inside: bool,
+}
+
+def_not!(Balanced<L, R>);
+
+impl<L, R> Balanced<L, R> {
+    pub fn new(open: L, close: R) -> Self {
+        Self {
+            open,
+            close,
+            inside: false,
+        }
+    }
+
+    pub fn open(&self) -> &L {
+        &self.open
+    }
+
+    pub fn close(&self) -> &R {
+        &self.close
+    }
+
+    pub fn inside(&self) -> bool {
+        self.inside
+    }
+
+    pub fn set_open(&mut self, open: L) -> &mut Self {
+        self.open = open;
+        self
+    }
+
+    pub fn set_close(&mut self, close: R) -> &mut Self {
+        self.close = close;
+        self
+    }
+
+    pub fn set_inside(&mut self, inside: bool) -> &mut Self {
+        self.inside = inside;
+        self
+    }
+
+    pub fn with_inside(mut self, inside: bool) -> Self {
+        self.inside = inside;
+        self
+    }
+}
+
+impl<'a, C, L, R, O, H, A> Ctor<'a, C, O, O, H, A> for Balanced<L, R>
+where
+    L: Regex<C, Ret = Span>,
+    R: Regex<C, Ret = Span>,
+    C: Context<'a> + Match<C> + 'a,
+    H: Handler<A, Out = O, Error = Error>,
+    A: Extract<'a, C, Span, Out<'a> = A, Error = Error>,
+{
+    #[inline(always)]
+    fn construct(&self, ctx: &mut C, func: &mut H) -> Result<O, Error> {
+        let ret = ctx.try_mat(self)?;
+
+        func.invoke(A::extract(ctx, &ret)?)
+    }
+}
+
+impl<'a, C, L, R> Regex<C> for Balanced<L, R>
+where
+    L: Regex<C, Ret = Span>,
+    R: Regex<C, Ret = Span>,
+    C: Context<'a> + Match<C> + 'a,
+{
+    type Ret = Span;
+
+    #[inline(always)]
+    fn try_parse(&self, ctx: &mut C) -> Result<Self::Ret, crate::err::Error> {
+        let mut g = CtxGuard::new(ctx);
+        let beg = g.beg();
+        let mut ret = Err(Error::Balanced);
+
+        trace!("balanced", beg @ "open", g.try_mat(&self.open)?);
+        let inner = g.end();
+        let len = g.ctx().len();
+        let mut depth = 1;
+
+        while g.end() < len {
+            let offset = g.end();
+
+            if let Ok(span) = g.ctx().try_mat(&self.open) {
+                if span.is_zero() {
+                    break;
+                }
+                depth += 1;
+            } else if let Ok(span) = g.ctx().try_mat(&self.close) {
+                depth -= 1;
+                if depth == 0 {
+                    ret = Ok(if self.inside {
+                        Span::new(inner, span.beg - inner)
+                    } else {
+                        Span::new(beg, span.beg + span.len - beg)
+                    });
+                    break;
+                }
+            } else {
+                let next = g.ctx().peek()?.nth(1).map(|(idx, _)| offset + idx);
+
+                g.ctx().set_offset(next.unwrap_or(len));
+            }
+        }
+        trace!("balanced", beg => g.end(), g.process_ret(ret))
+    }
+}
@@ -0,0 +1,113 @@
+use std::marker::PhantomData;
+
+use crate::ctx::Context;
+use crate::ctx::Match;
+use crate::ctx::Span;
+use crate::err::Error;
+use crate::map::FromLeBytes;
+use crate::map::MapSingle;
+use crate::re::trace;
+use crate::re::Ctor;
+use crate::re::Extract;
+use crate::re::Handler;
+use crate::re::Regex;
+
+///
+/// Read `N` values of `T` from the bytes in little endianness.
+///
+/// # Ctor
+///
+/// Return an array `[T; N]` decoded by [`FromLeBytes`], it consumes `N * size_of::<T>()` bytes.
+/// It will return [`Error::ReadArray`] if there are not enough bytes.
+///
+/// # Example
+///
+/// ```
+/// # use neure::prelude::*;
+/// #
+/// # fn main() -> color_eyre::Result<()> {
+/// #     color_eyre::install()?;
+///     let header = re::read_array_le::<u32, 2>();
+///     let mut ctx = BytesCtx::new(&[1, 0, 0, 0, 0xff, 0, 0, 0, 0xaa]);
+///
+///     assert_eq!(ctx.ctor(&header)?, [1, 0xff]);
+///     assert_eq!(ctx.offset(), 8);
+///     assert!(ctx.ctor(&header).is_err());
+///     Ok(())
+/// # }
+/// ```
+#[derive(Debug, Copy, PartialEq, Eq, PartialOrd, Ord)]
+pub struct ReadArrayLe<T, const N: usize>(PhantomData<T>);
+
+impl<T, const N: usize> std::ops::Not for ReadArrayLe<T, N> {
+    type Output = crate::re::RegexNot<Self>;
+
+    fn not(self) -> Self::Output {
+        crate::re::not(self)
+    }
+}
+
+impl<T, const N: usize> Clone for ReadArrayLe<T, N> {
+    fn clone(&self) -> Self {
+        Self(self.0)
+    }
+}
+
+impl<T, const N: usize> Default for ReadArrayLe<T, N> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl<T, const N: usize> ReadArrayLe<T, N> {
+    pub fn new() -> Self {
+        Self(PhantomData)
+    }
+
+    /// Return the count of bytes will be consumed.
+    pub const fn size(&self) -> usize {
+        size_of::<T>() * N
+    }
+}
+
+impl<'a, C, T, M, H, A, const N: usize> Ctor<'a, C, M, [T; N], H, A> for ReadArrayLe<T, N>
+where
+    T: Default + Copy,
+    C: Context<'a, Orig = [u8]> + Match<C>,
+    H: Handler<A, Out = M, Error = Error>,
+    A: Extract<'a, C, Span, Out<'a> = A, Error = Error>,
+    FromLeBytes<T>: MapSingle<&'a [u8], T>,
+{
+    #[inline(always)]
+    fn construct(&self, ctx: &mut C, _: &mut H) -> Result<[T; N], Error> {
+        let ret = ctx.try_mat(self)?;
+        let bytes = ctx.orig_sub(ret.beg, ret.len)?;
+        let mapper = FromLeBytes::<T>::new();
+        let mut vals = [T::default(); N];
+
+        for (val, chunk) in vals.iter_mut().zip(bytes.chunks_exact(size_of::<T>())) {
+            *val = mapper.map_to(chunk)?;
+        }
+        Ok(vals)
+    }
+}
+
+impl<'a, C, T, const N: usize> Regex<C> for ReadArrayLe<T, N>
+where
+    C: Context<'a, Orig = [u8]>,
+{
+    type Ret = Span;
+
+    #[inline(always)]
+    fn try_parse(&self, ctx: &mut C) -> Result<Self::Ret, crate::err::Error> {
+        let mut ret = Err(Error::ReadArray);
+        let len = self.size();
+        let beg = ctx.offset();
+
+        if ctx.len() - beg >= len {
+            ctx.inc(len);
+            ret = Ok(Span::new(beg, len));
+        }
+        trace!("read_array_le", beg => ctx.offset(), ret)
+    }
+}
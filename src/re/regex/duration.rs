@@ -0,0 +1,104 @@
+use crate::ctx::Context;
+use crate::ctx::Match;
+use crate::ctx::Span;
+use crate::err::Error;
+use crate::re::def_not;
+use crate::re::trace;
+use crate::re::Ctor;
+use crate::re::Extract;
+use crate::re::Handler;
+use crate::re::Regex;
+
+///
+/// Match the human readable duration such as `500ms`, `2h` or `1h30m`.
+///
+/// The duration is one or more components, each component is a decimal number follow by
+/// one of the unit `ns`, `us`, `ms`, `s`, `m`, `h` or `d`.
+/// The match will stop at the end of last complete component.
+///
+/// # Ctor
+///
+/// Return [`Orig`](crate::ctx::Context::Orig) with the [`Span`] as the index if the match is found.
+#[derive(Debug, Clone, Default, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct HumanDuration;
+
+def_not!(HumanDuration);
+
+impl HumanDuration {
+    pub fn new() -> Self {
+        Self {}
+    }
+}
+
+impl<'a, C, O, H, A> Ctor<'a, C, O, O, H, A> for HumanDuration
+where
+    C::Item: Into<char>,
+    C: Context<'a> + Match<C> + 'a,
+    H: Handler<A, Out = O, Error = Error>,
+    A: Extract<'a, C, Span, Out<'a> = A, Error = Error>,
+{
+    #[inline(always)]
+    fn construct(&self, ctx: &mut C, func: &mut H) -> Result<O, Error> {
+        let ret = ctx.try_mat(self)?;
+
+        func.invoke(A::extract(ctx, &ret)?)
+    }
+}
+
+impl<'a, C> Regex<C> for HumanDuration
+where
+    C::Item: Into<char>,
+    C: Context<'a> + 'a,
+{
+    type Ret = Span;
+
+    #[inline(always)]
+    fn try_parse(&self, ctx: &mut C) -> Result<Self::Ret, crate::err::Error> {
+        let beg = ctx.offset();
+        let remain = ctx.len() - beg;
+        let mut iter = ctx.peek()?.map(|(offset, item)| (offset, item.into()));
+        let mut next = iter.next();
+        let mut len = 0;
+
+        loop {
+            let mut digits = 0;
+
+            while let Some((_, '0'..='9')) = next {
+                digits += 1;
+                next = iter.next();
+            }
+            if digits == 0 {
+                break;
+            }
+            match next {
+                Some((_, 'n' | 'u')) => {
+                    next = iter.next();
+                    if !matches!(next, Some((_, 's'))) {
+                        break;
+                    }
+                    next = iter.next();
+                }
+                Some((_, 'm')) => {
+                    next = iter.next();
+                    if matches!(next, Some((_, 's'))) {
+                        next = iter.next();
+                    }
+                }
+                Some((_, 's' | 'h' | 'd')) => {
+                    next = iter.next();
+                }
+                _ => break,
+            }
+            len = next.map(|v| v.0).unwrap_or(remain);
+        }
+
+        let ret = if len > 0 {
+            ctx.inc(len);
+            Ok(Span::new(beg, len))
+        } else {
+            Err(Error::Duration)
+        };
+
+        trace!("humantime_duration", beg => ctx.offset(), ret)
+    }
+}
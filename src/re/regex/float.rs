@@ -0,0 +1,98 @@
+use crate::ctx::Context;
+use crate::ctx::Match;
+use crate::ctx::Span;
+use crate::err::Error;
+use crate::re::def_not;
+use crate::re::trace;
+use crate::re::Ctor;
+use crate::re::Extract;
+use crate::re::Handler;
+use crate::re::Regex;
+
+///
+/// Match the C99 hexadecimal floating-point literal such as `0x1.8p3` or `0X.Fp-2`.
+///
+/// The literal starts with `0x` or `0X`, follow by the hexadecimal digits with an
+/// optional `.`, at least one digit is required. The binary exponent `p` or `P`
+/// with an optional sign and decimal digits is mandatory.
+///
+/// # Ctor
+///
+/// Return [`Orig`](crate::ctx::Context::Orig) with the [`Span`] as the index if the match is found.
+#[derive(Debug, Clone, Default, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct HexFloat;
+
+def_not!(HexFloat);
+
+impl HexFloat {
+    pub fn new() -> Self {
+        Self {}
+    }
+}
+
+impl<'a, C, O, H, A> Ctor<'a, C, O, O, H, A> for HexFloat
+where
+    C::Item: Into<char>,
+    C: Context<'a> + Match<C> + 'a,
+    H: Handler<A, Out = O, Error = Error>,
+    A: Extract<'a, C, Span, Out<'a> = A, Error = Error>,
+{
+    #[inline(always)]
+    fn construct(&self, ctx: &mut C, func: &mut H) -> Result<O, Error> {
+        let ret = ctx.try_mat(self)?;
+
+        func.invoke(A::extract(ctx, &ret)?)
+    }
+}
+
+impl<'a, C> Regex<C> for HexFloat
+where
+    C::Item: Into<char>,
+    C: Context<'a> + 'a,
+{
+    type Ret = Span;
+
+    #[inline(always)]
+    fn try_parse(&self, ctx: &mut C) -> Result<Self::Ret, crate::err::Error> {
+        let beg = ctx.offset();
+        let remain = ctx.len() - beg;
+        let mut iter = ctx.peek()?.map(|(offset, item)| (offset, item.into()));
+        let mut ret = Err(Error::HexFloat);
+
+        if matches!(iter.next(), Some((_, '0'))) && matches!(iter.next(), Some((_, 'x' | 'X'))) {
+            let mut digits = 0;
+            let mut next = iter.next();
+
+            while matches!(next, Some((_, ch)) if ch.is_ascii_hexdigit()) {
+                digits += 1;
+                next = iter.next();
+            }
+            if matches!(next, Some((_, '.'))) {
+                next = iter.next();
+                while matches!(next, Some((_, ch)) if ch.is_ascii_hexdigit()) {
+                    digits += 1;
+                    next = iter.next();
+                }
+            }
+            if digits > 0 && matches!(next, Some((_, 'p' | 'P'))) {
+                let mut exp = 0;
+
+                next = iter.next();
+                if matches!(next, Some((_, '+' | '-'))) {
+                    next = iter.next();
+                }
+                while matches!(next, Some((_, '0'..='9'))) {
+                    exp += 1;
+                    next = iter.next();
+                }
+                if exp > 0 {
+                    let len = next.map(|v| v.0).unwrap_or(remain);
+
+                    ctx.inc(len);
+                    ret = Ok(Span::new(beg, len));
+                }
+            }
+        }
+        trace!("hex_float", beg => ctx.offset(), ret)
+    }
+}
@@ -0,0 +1,97 @@
+use crate::ctx::Context;
+use crate::ctx::Match;
+use crate::ctx::Span;
+use crate::err::Error;
+use crate::neu::DigitOrUnderscore;
+use crate::neu::Neu;
+use crate::re::def_not;
+use crate::re::trace;
+use crate::re::Ctor;
+use crate::re::Extract;
+use crate::re::Handler;
+use crate::re::Regex;
+
+///
+/// Match the integer literal of given radix, the digits can be separated by `_`.
+///
+/// The separator must be between two digits, the leading, trailing or consecutive
+/// separators are not allowed.
+///
+/// # Ctor
+///
+/// Return [`Orig`](crate::ctx::Context::Orig) with the [`Span`] as the index if the match is found.
+#[derive(Debug, Clone, Default, Copy)]
+pub struct IntWithSeparators {
+    unit: DigitOrUnderscore,
+    radix: u32,
+}
+
+def_not!(IntWithSeparators);
+
+impl IntWithSeparators {
+    pub fn new(radix: u32) -> Self {
+        Self {
+            unit: DigitOrUnderscore::new(radix),
+            radix,
+        }
+    }
+
+    pub fn radix(&self) -> u32 {
+        self.radix
+    }
+}
+
+impl<'a, C, O, H, A> Ctor<'a, C, O, O, H, A> for IntWithSeparators
+where
+    C::Item: Into<char>,
+    C: Context<'a> + Match<C> + 'a,
+    H: Handler<A, Out = O, Error = Error>,
+    A: Extract<'a, C, Span, Out<'a> = A, Error = Error>,
+{
+    #[inline(always)]
+    fn construct(&self, ctx: &mut C, func: &mut H) -> Result<O, Error> {
+        let ret = ctx.try_mat(self)?;
+
+        func.invoke(A::extract(ctx, &ret)?)
+    }
+}
+
+impl<'a, C> Regex<C> for IntWithSeparators
+where
+    C::Item: Into<char>,
+    C: Context<'a> + 'a,
+{
+    type Ret = Span;
+
+    #[inline(always)]
+    fn try_parse(&self, ctx: &mut C) -> Result<Self::Ret, crate::err::Error> {
+        let beg = ctx.offset();
+        let remain = ctx.len() - beg;
+        let mut iter = ctx.peek()?.map(|(offset, item)| (offset, item.into()));
+        let mut last = None;
+        let mut ret = Err(Error::IntWithSeparators);
+
+        loop {
+            match iter.next() {
+                Some((_, ch)) if self.unit.is_match(&ch) => {
+                    // the separator must follow a digit
+                    if ch == '_' && matches!(last, None | Some('_')) {
+                        break;
+                    }
+                    last = Some(ch);
+                }
+                next => {
+                    // the last one must be a digit
+                    if matches!(last, Some(ch) if ch != '_') {
+                        let len = next.map(|v| v.0).unwrap_or(remain);
+
+                        ctx.inc(len);
+                        ret = Ok(Span::new(beg, len));
+                    }
+                    break;
+                }
+            }
+        }
+        trace!("int_with_separators", beg => ctx.offset(), ret)
+    }
+}
@@ -0,0 +1,114 @@
+use std::ops::Deref;
+use std::ops::DerefMut;
+
+use crate::ctx::Context;
+use crate::ctx::CtxGuard;
+use crate::ctx::Match;
+use crate::ctx::Span;
+use crate::err::Error;
+use crate::re::def_not;
+use crate::re::trace;
+use crate::re::Ctor;
+use crate::re::Extract;
+use crate::re::Handler;
+use crate::re::Regex;
+
+///
+/// Try all the rules at the current offset, match the longest one.
+/// If more than one rule matched the same length, the first one wins.
+///
+/// It is the step of a maximal munch lexer.
+///
+/// # Ctor
+///
+/// Return the index of rule and the handler result of the longest match.
+/// It will return [`Error::LexStep`] if no rule matched.
+#[derive(Debug, Clone, Default)]
+pub struct LexStep<T>(Vec<T>);
+
+def_not!(LexStep<T>);
+
+impl<T> LexStep<T> {
+    pub fn new(rules: Vec<T>) -> Self {
+        Self(rules)
+    }
+}
+
+impl<T> Deref for LexStep<T> {
+    type Target = Vec<T>;
+
+    fn deref(&self) -> &Self::Target {
+        &self.0
+    }
+}
+
+impl<T> DerefMut for LexStep<T> {
+    fn deref_mut(&mut self) -> &mut Self::Target {
+        &mut self.0
+    }
+}
+
+impl<T> LexStep<T> {
+    /// Return the index and span of the longest match, the context is left at the end of it.
+    fn longest<'a, C>(&self, ctx: &mut C) -> Result<(usize, Span), Error>
+    where
+        T: Regex<C, Ret = Span>,
+        C: Context<'a> + Match<C>,
+    {
+        let beg = ctx.offset();
+        let mut longest: Option<(usize, Span)> = None;
+
+        for (idx, rule) in self.0.iter().enumerate() {
+            ctx.set_offset(beg);
+            if let Ok(span) = ctx.try_mat(rule) {
+                if longest.is_none_or(|(_, v)| span.len > v.len) {
+                    longest = Some((idx, span));
+                }
+            }
+        }
+        match longest {
+            Some((idx, span)) => {
+                ctx.set_offset(span.beg + span.len);
+                Ok((idx, span))
+            }
+            None => Err(Error::LexStep),
+        }
+    }
+}
+
+impl<'a, C, T, M, H, A> Ctor<'a, C, M, (usize, M), H, A> for LexStep<T>
+where
+    T: Regex<C, Ret = Span>,
+    C: Context<'a> + Match<C>,
+    H: Handler<A, Out = M, Error = Error>,
+    A: Extract<'a, C, Span, Out<'a> = A, Error = Error>,
+{
+    #[inline(always)]
+    fn construct(&self, ctx: &mut C, func: &mut H) -> Result<(usize, M), Error> {
+        let mut g = CtxGuard::new(ctx);
+        let beg = g.beg();
+        let ret = trace!("lex_step", beg @ "rules", self.longest(g.ctx()));
+        let ret = ret.and_then(|(idx, span)| Ok((idx, func.invoke(A::extract(g.ctx(), &span)?)?)));
+
+        trace!("lex_step", beg -> g.end(), ret.is_ok());
+        g.process_ret(ret)
+    }
+}
+
+impl<'a, C, T> Regex<C> for LexStep<T>
+where
+    T: Regex<C, Ret = Span>,
+    C: Context<'a> + Match<C>,
+{
+    type Ret = Span;
+
+    #[inline(always)]
+    fn try_parse(&self, ctx: &mut C) -> Result<Self::Ret, Error> {
+        let mut g = CtxGuard::new(ctx);
+        let beg = g.beg();
+        let ret = trace!("lex_step", beg @ "rules", self.longest(g.ctx()));
+        let ret = ret.map(|(_, span)| span);
+
+        trace!("lex_step", beg => g.end(), g.process_ret(ret))
+    }
+}
@@ -2,6 +2,8 @@ use crate::ctx::Context;
 use crate::ctx::Match;
 use crate::ctx::Span;
 use crate::err::Error;
+use crate::neu::Neu;
+use crate::neu::Word;
 use crate::re::def_not;
 use crate::re::trace;
 use crate::re::Ctor;
@@ -51,9 +53,9 @@ where
 
     #[inline(always)]
     fn try_parse(&self, ctx: &mut C) -> Result<Self::Ret, crate::err::Error> {
-        let mut ret = Err(Error::Slice);
         let len = self.val.len();
         let beg = ctx.offset();
+        let mut ret = Err(Error::Slice { at: beg });
 
         if ctx.orig()?.starts_with(self.val) {
             ctx.inc(len);
@@ -65,19 +67,37 @@ where
 
 /// Match given string in the [`Context`].
 ///
+/// If created by [`string_bom`](crate::re::string_bom), a leading UTF-8 BOM (`U+FEFF`)
+/// is skipped before the string, this only applies at the very start of data.
+///
 /// # Regex
 ///
-/// Return a [`Span`] as match result.
+/// Return a [`Span`] as match result, the skipped BOM is not included.
 #[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord)]
 pub struct LitString<'a> {
     val: &'a str,
+    bom: bool,
 }
 
 def_not!(LitString<'a>);
 
 impl<'a> LitString<'a> {
     pub fn new(val: &'a str) -> Self {
-        Self { val }
+        Self { val, bom: false }
+    }
+
+    pub fn bom(&self) -> bool {
+        self.bom
+    }
+
+    pub fn set_bom(&mut self, bom: bool) -> &mut Self {
+        self.bom = bom;
+        self
+    }
+
+    pub fn with_bom(mut self, bom: bool) -> Self {
+        self.bom = bom;
+        self
     }
 }
 
@@ -103,14 +123,192 @@ where
 
     #[inline(always)]
     fn try_parse(&self, ctx: &mut C) -> Result<Self::Ret, crate::err::Error> {
-        let mut ret = Err(Error::String);
         let len = self.val.len();
         let beg = ctx.offset();
+        let mut ret = Err(Error::String { at: beg });
+        let orig = ctx.orig()?;
+        let bom = if self.bom && beg == 0 && orig.starts_with('\u{FEFF}') {
+            '\u{FEFF}'.len_utf8()
+        } else {
+            0
+        };
 
-        if ctx.orig()?.starts_with(self.val) {
+        if orig[bom..].starts_with(self.val) {
+            ctx.inc(bom + len);
+            ret = Ok(Span::new(beg + bom, len));
+        }
+        trace!("string", beg => ctx.offset(), ret)
+    }
+}
+
+/// Match given string in the [`Context`], ignoring the ASCII case.
+///
+/// The non-ASCII chars must be equal exactly, so a multi-byte char in the data
+/// will never match an ASCII char of the string.
+///
+/// # Regex
+///
+/// Return a [`Span`] as match result, the length is equal to the length of string.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord)]
+pub struct LitStringNoCase<'a> {
+    val: &'a str,
+}
+
+def_not!(LitStringNoCase<'a>);
+
+impl<'a> LitStringNoCase<'a> {
+    pub fn new(val: &'a str) -> Self {
+        Self { val }
+    }
+}
+
+impl<'a, C, O, H, A> Ctor<'a, C, O, O, H, A> for LitStringNoCase<'_>
+where
+    C: Context<'a, Orig = str> + Match<C>,
+    H: Handler<A, Out = O, Error = Error>,
+    A: Extract<'a, C, Span, Out<'a> = A, Error = Error>,
+{
+    #[inline(always)]
+    fn construct(&self, ctx: &mut C, func: &mut H) -> Result<O, Error> {
+        let ret = ctx.try_mat(self)?;
+
+        func.invoke(A::extract(ctx, &ret)?)
+    }
+}
+
+impl<'a, C> Regex<C> for LitStringNoCase<'_>
+where
+    C: Context<'a, Orig = str>,
+{
+    type Ret = Span;
+
+    #[inline(always)]
+    fn try_parse(&self, ctx: &mut C) -> Result<Self::Ret, crate::err::Error> {
+        let len = self.val.len();
+        let beg = ctx.offset();
+        let mut ret = Err(Error::String { at: beg });
+        let orig = ctx.orig()?.as_bytes();
+
+        // ASCII case conversion never changes the length of char
+        if orig
+            .get(..len)
+            .is_some_and(|head| head.eq_ignore_ascii_case(self.val.as_bytes()))
+        {
             ctx.inc(len);
             ret = Ok(Span::new(beg, len));
         }
-        trace!("string", beg => ctx.offset(), ret)
+        trace!("string_ignore_case", beg => ctx.offset(), ret)
+    }
+}
+
+/// Match given bytes in the [`Context`], ignoring the ASCII case.
+///
+/// # Regex
+///
+/// Return a [`Span`] as match result.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord)]
+pub struct LitSliceNoCase<'a> {
+    val: &'a [u8],
+}
+
+def_not!(LitSliceNoCase<'a>);
+
+impl<'a> LitSliceNoCase<'a> {
+    pub fn new(val: &'a [u8]) -> Self {
+        Self { val }
+    }
+}
+
+impl<'a, C, O, H, A> Ctor<'a, C, O, O, H, A> for LitSliceNoCase<'_>
+where
+    C: Context<'a, Orig = [u8]> + Match<C>,
+    H: Handler<A, Out = O, Error = Error>,
+    A: Extract<'a, C, Span, Out<'a> = A, Error = Error>,
+{
+    #[inline(always)]
+    fn construct(&self, ctx: &mut C, func: &mut H) -> Result<O, Error> {
+        let ret = ctx.try_mat(self)?;
+
+        func.invoke(A::extract(ctx, &ret)?)
+    }
+}
+
+impl<'a, C> Regex<C> for LitSliceNoCase<'_>
+where
+    C: Context<'a, Orig = [u8]>,
+{
+    type Ret = Span;
+
+    #[inline(always)]
+    fn try_parse(&self, ctx: &mut C) -> Result<Self::Ret, crate::err::Error> {
+        let len = self.val.len();
+        let beg = ctx.offset();
+        let mut ret = Err(Error::Slice { at: beg });
+
+        if ctx
+            .orig()?
+            .get(..len)
+            .is_some_and(|head| head.eq_ignore_ascii_case(self.val))
+        {
+            ctx.inc(len);
+            ret = Ok(Span::new(beg, len));
+        }
+        trace!("slice_ignore_case", beg => ctx.offset(), ret)
+    }
+}
+
+/// Match given string in the [`Context`], the string must not followed by a
+/// [`word`](crate::neu::word) character.
+///
+/// # Regex
+///
+/// Return a [`Span`] as match result.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord)]
+pub struct Keyword<'a> {
+    val: &'a str,
+}
+
+def_not!(Keyword<'a>);
+
+impl<'a> Keyword<'a> {
+    pub fn new(val: &'a str) -> Self {
+        Self { val }
+    }
+}
+
+impl<'a, C, O, H, A> Ctor<'a, C, O, O, H, A> for Keyword<'_>
+where
+    C: Context<'a, Orig = str> + Match<C>,
+    H: Handler<A, Out = O, Error = Error>,
+    A: Extract<'a, C, Span, Out<'a> = A, Error = Error>,
+{
+    #[inline(always)]
+    fn construct(&self, ctx: &mut C, func: &mut H) -> Result<O, Error> {
+        let ret = ctx.try_mat(self)?;
+
+        func.invoke(A::extract(ctx, &ret)?)
+    }
+}
+
+impl<'a, C> Regex<C> for Keyword<'_>
+where
+    C: Context<'a, Orig = str>,
+{
+    type Ret = Span;
+
+    #[inline(always)]
+    fn try_parse(&self, ctx: &mut C) -> Result<Self::Ret, crate::err::Error> {
+        let len = self.val.len();
+        let beg = ctx.offset();
+        let mut ret = Err(Error::Keyword { at: beg });
+        let orig = ctx.orig()?;
+
+        if let Some(next) = orig.strip_prefix(self.val) {
+            if !next.chars().next().is_some_and(|ch| Word.is_match(&ch)) {
+                ctx.inc(len);
+                ret = Ok(Span::new(beg, len));
+            }
+        }
+        trace!("keyword", beg => ctx.offset(), ret)
     }
 }
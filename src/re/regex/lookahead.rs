@@ -0,0 +1,103 @@
+use crate::ctx::Context;
+use crate::ctx::CtxGuard;
+use crate::ctx::Match;
+use crate::ctx::Span;
+use crate::err::Error;
+use crate::re::def_not;
+use crate::re::trace;
+use crate::re::Ctor;
+use crate::re::Extract;
+use crate::re::Handler;
+use crate::re::Regex;
+
+/// Success if `T` matches at current offset, the offset will not be changed.
+///
+/// It can be chained with other regexes, such as `a.then(re::followed_by(b))`
+/// only match the `a` followed by `b`.
+/// If created by [`not_followed_by`](crate::re::not_followed_by), the result is inverted.
+///
+/// # Regex
+///
+/// Return a zero-length [`Span`] at current offset, or [`Error::FollowedBy`]
+/// ([`Error::NotFollowedBy`] if inverted) if not match.
+#[derive(Debug, Clone, Default, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct FollowedBy<T> {
+    val: T,
+    negated: bool,
+}
+
+def_not!(FollowedBy<T>);
+
+impl<T> FollowedBy<T> {
+    pub fn new(val: T) -> Self {
+        Self {
+            val,
+            negated: false,
+        }
+    }
+
+    pub fn val(&self) -> &T {
+        &self.val
+    }
+
+    pub fn val_mut(&mut self) -> &mut T {
+        &mut self.val
+    }
+
+    pub fn negated(&self) -> bool {
+        self.negated
+    }
+
+    pub fn set_val(&mut self, val: T) -> &mut Self {
+        self.val = val;
+        self
+    }
+
+    pub fn set_negated(&mut self, negated: bool) -> &mut Self {
+        self.negated = negated;
+        self
+    }
+
+    pub fn with_negated(mut self, negated: bool) -> Self {
+        self.negated = negated;
+        self
+    }
+}
+
+impl<'a, C, O, T, H, A> Ctor<'a, C, O, O, H, A> for FollowedBy<T>
+where
+    T: Regex<C>,
+    C: Context<'a> + Match<C>,
+    H: Handler<A, Out = O, Error = Error>,
+    A: Extract<'a, C, Span, Out<'a> = A, Error = Error>,
+{
+    #[inline(always)]
+    fn construct(&self, ctx: &mut C, func: &mut H) -> Result<O, Error> {
+        let ret = ctx.try_mat(self)?;
+
+        func.invoke(A::extract(ctx, &ret)?)
+    }
+}
+
+impl<'a, C, T> Regex<C> for FollowedBy<T>
+where
+    T: Regex<C>,
+    C: Context<'a> + Match<C>,
+{
+    type Ret = Span;
+
+    #[inline(always)]
+    fn try_parse(&self, ctx: &mut C) -> Result<Self::Ret, crate::err::Error> {
+        let mut g = CtxGuard::new(ctx);
+        let beg = g.beg();
+        let r = trace!("followed_by", beg, g.try_mat(&self.val));
+        let ret = match (r.is_ok(), self.negated) {
+            (true, false) | (false, true) => Ok(Span::new(beg, 0)),
+            (false, false) => Err(Error::FollowedBy),
+            (true, true) => Err(Error::NotFollowedBy),
+        };
+
+        g.reset();
+        trace!("followed_by", beg => g.end(), ret)
+    }
+}
@@ -62,6 +62,7 @@ where
         if r.is_err() {
             ret = Ok(<T::Ret as Ret>::from_ctx(g.ctx(), (0, 0)));
         }
-        trace!("not", beg => g.reset().end(), ret)
+        g.reset();
+        trace!("not", beg => g.end(), ret)
     }
 }
@@ -0,0 +1,205 @@
+use std::iter::once;
+
+use crate::ctx::Context;
+use crate::ctx::CtxGuard;
+use crate::ctx::Match;
+use crate::ctx::Span;
+use crate::err::Error;
+use crate::re::def_not;
+use crate::re::trace;
+use crate::re::Ctor;
+use crate::re::Extract;
+use crate::re::Handler;
+use crate::re::Regex;
+
+///
+/// Match the data before the first occurrence of `byte`, the `byte` is not consumed.
+///
+/// The scan searches the [`Orig`](crate::ctx::Context::Orig) as bytes at once,
+/// it is faster than matching the data item by item.
+/// The `byte` should be ASCII if the context is [`CharsCtx`](crate::ctx::CharsCtx),
+/// otherwise the span may not on the char boundary.
+///
+/// # Ctor
+///
+/// Return [`Orig`](crate::ctx::Context::Orig) with the [`Span`] as the index.
+/// It will return [`Error::TakeTill`] if the `byte` not found,
+/// unless it created by [`take_till_or_eof`](crate::re::take_till_or_eof),
+/// which matches the remaining data at the end.
+#[derive(Debug, Clone, Default, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct TakeTill {
+    byte: u8,
+    or_eof: bool,
+}
+
+def_not!(TakeTill);
+
+impl TakeTill {
+    pub fn new(byte: u8) -> Self {
+        Self {
+            byte,
+            or_eof: false,
+        }
+    }
+
+    pub fn byte(&self) -> u8 {
+        self.byte
+    }
+
+    pub fn or_eof(&self) -> bool {
+        self.or_eof
+    }
+
+    pub fn set_byte(&mut self, byte: u8) -> &mut Self {
+        self.byte = byte;
+        self
+    }
+
+    pub fn set_or_eof(&mut self, or_eof: bool) -> &mut Self {
+        self.or_eof = or_eof;
+        self
+    }
+
+    pub fn with_or_eof(mut self, or_eof: bool) -> Self {
+        self.or_eof = or_eof;
+        self
+    }
+}
+
+impl<'a, C, O, H, A> Ctor<'a, C, O, O, H, A> for TakeTill
+where
+    C::Orig: AsRef<[u8]>,
+    C: Context<'a> + Match<C> + 'a,
+    H: Handler<A, Out = O, Error = Error>,
+    A: Extract<'a, C, Span, Out<'a> = A, Error = Error>,
+{
+    #[inline(always)]
+    fn construct(&self, ctx: &mut C, func: &mut H) -> Result<O, Error> {
+        let ret = ctx.try_mat(self)?;
+
+        func.invoke(A::extract(ctx, &ret)?)
+    }
+}
+
+impl<'a, C> Regex<C> for TakeTill
+where
+    C::Orig: AsRef<[u8]>,
+    C: Context<'a> + 'a,
+{
+    type Ret = Span;
+
+    #[inline(always)]
+    fn try_parse(&self, ctx: &mut C) -> Result<Self::Ret, crate::err::Error> {
+        let beg = ctx.offset();
+        let bytes = ctx.orig()?.as_ref();
+        let len = match bytes.iter().position(|v| *v == self.byte) {
+            Some(len) => Some(len),
+            None => self.or_eof.then_some(bytes.len()),
+        };
+        let ret = len.map(|len| Span::new(beg, len)).ok_or(Error::TakeTill);
+
+        if let Ok(span) = &ret {
+            ctx.inc(span.len);
+        }
+        trace!("take_till", beg => ctx.offset(), ret)
+    }
+}
+
+///
+/// Match the data before the first position where `P` matches, `P` is not consumed.
+///
+/// The `P` is tried at every item from current offset one by one, include the end of data.
+/// If it created by [`take_until_inclusive`](crate::re::take_until_inclusive),
+/// the data matched by `P` is included in the result.
+///
+/// # Ctor
+///
+/// Return [`Orig`](crate::ctx::Context::Orig) with the [`Span`] as the index.
+/// It will return [`Error::TakeUntil`] if the `P` never matches.
+#[derive(Debug, Clone, Default, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct TakeUntil<P> {
+    pat: P,
+    inclusive: bool,
+}
+
+def_not!(TakeUntil<P>);
+
+impl<P> TakeUntil<P> {
+    pub fn new(pat: P) -> Self {
+        Self {
+            pat,
+            inclusive: false,
+        }
+    }
+
+    pub fn pat(&self) -> &P {
+        &self.pat
+    }
+
+    pub fn inclusive(&self) -> bool {
+        self.inclusive
+    }
+
+    pub fn set_pat(&mut self, pat: P) -> &mut Self {
+        self.pat = pat;
+        self
+    }
+
+    pub fn set_inclusive(&mut self, inclusive: bool) -> &mut Self {
+        self.inclusive = inclusive;
+        self
+    }
+
+    pub fn with_inclusive(mut self, inclusive: bool) -> Self {
+        self.inclusive = inclusive;
+        self
+    }
+}
+
+impl<'a, C, P, O, H, A> Ctor<'a, C, O, O, H, A> for TakeUntil<P>
+where
+    P: Regex<C, Ret = Span>,
+    C: Context<'a> + Match<C> + 'a,
+    H: Handler<A, Out = O, Error = Error>,
+    A: Extract<'a, C, Span, Out<'a> = A, Error = Error>,
+{
+    #[inline(always)]
+    fn construct(&self, ctx: &mut C, func: &mut H) -> Result<O, Error> {
+        let ret = ctx.try_mat(self)?;
+
+        func.invoke(A::extract(ctx, &ret)?)
+    }
+}
+
+impl<'a, C, P> Regex<C> for TakeUntil<P>
+where
+    P: Regex<C, Ret = Span>,
+    C: Context<'a> + Match<C> + 'a,
+{
+    type Ret = Span;
+
+    #[inline(always)]
+    fn try_parse(&self, ctx: &mut C) -> Result<Self::Ret, crate::err::Error> {
+        let mut g = CtxGuard::new(ctx);
+        let beg = g.beg();
+        let len = g.ctx().len();
+        let mut ret = Err(Error::TakeUntil);
+
+        trace!("take_until", beg, ());
+        for offset in g.ctx().peek()?.map(|(idx, _)| beg + idx).chain(once(len)) {
+            g.ctx().set_offset(offset);
+            if let Ok(span) = g.ctx().try_mat(&self.pat) {
+                let end = if self.inclusive {
+                    span.beg + span.len
+                } else {
+                    offset
+                };
+
+                g.ctx().set_offset(end);
+                ret = Ok(Span::new(beg, end - beg));
+                break;
+            }
+        }
+        trace!("take_until", beg => g.end(), g.process_ret(ret))
+    }
+}
@@ -0,0 +1,73 @@
+use crate::ctx::Context;
+use crate::ctx::CtxGuard;
+use crate::ctx::Match;
+use crate::ctx::Span;
+use crate::ctx::TriviaSink;
+use crate::err::Error;
+use crate::re::def_not;
+use crate::re::trace;
+use crate::re::Ctor;
+use crate::re::Extract;
+use crate::re::Handler;
+use crate::re::Regex;
+
+/// Skip the trivia `T` as many times as possible, record the span of each trivia
+/// into the [`TriviaSink`] of [`Context`].
+///
+/// The recorded spans will not be removed if the outer regex fails later.
+///
+/// # Regex
+///
+/// Return the [`Span`] of all the skipped trivia, it is always succeed.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord)]
+pub struct SkipTrivia<T> {
+    val: T,
+}
+
+impl<T> SkipTrivia<T> {
+    pub fn new(val: T) -> Self {
+        Self { val }
+    }
+}
+
+def_not!(SkipTrivia<T>);
+
+impl<'a, C, O, T, H, A> Ctor<'a, C, O, O, H, A> for SkipTrivia<T>
+where
+    T: Regex<C, Ret = Span>,
+    C: Context<'a> + Match<C> + TriviaSink,
+    H: Handler<A, Out = O, Error = Error>,
+    A: Extract<'a, C, Span, Out<'a> = A, Error = Error>,
+{
+    #[inline(always)]
+    fn construct(&self, ctx: &mut C, func: &mut H) -> Result<O, Error> {
+        let ret = ctx.try_mat(self)?;
+
+        func.invoke(A::extract(ctx, &ret)?)
+    }
+}
+
+impl<'a, C, T> Regex<C> for SkipTrivia<T>
+where
+    T: Regex<C, Ret = Span>,
+    C: Context<'a> + Match<C> + TriviaSink,
+{
+    type Ret = Span;
+
+    #[inline(always)]
+    fn try_parse(&self, ctx: &mut C) -> Result<Self::Ret, crate::err::Error> {
+        let mut g = CtxGuard::new(ctx);
+        let beg = g.beg();
+
+        trace!("skip_trivia", beg, ());
+        while let Some(span) = g.non_fatal(|ctx| ctx.try_mat(&self.val))? {
+            if span.len == 0 {
+                break;
+            }
+            g.ctx().push_trivia(span);
+        }
+        let ret = Ok(Span::new(beg, g.end() - beg));
+
+        trace!("skip_trivia", beg => g.end(), g.process_ret(ret))
+    }
+}
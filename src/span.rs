@@ -8,6 +8,7 @@ use crate::iter::SpanIterator;
 use crate::re::Regex;
 
 #[derive(Debug, Clone, Default)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct SimpleStorer {
     spans: Vec<Vec<Span>>,
 }
@@ -36,6 +37,11 @@ impl SimpleStorer {
         self.len() == 0
     }
 
+    pub fn resize(&mut self, capacity: usize) -> &mut Self {
+        self.spans.resize(capacity, vec![]);
+        self
+    }
+
     pub fn reset(&mut self) -> &mut Self {
         self.spans.iter_mut().for_each(|v| v.clear());
         self
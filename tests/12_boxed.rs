@@ -0,0 +1,52 @@
+use neure::err::Error;
+use neure::prelude::*;
+use neure::re::Ctor;
+use neure::re::Pass;
+
+type DepthCtor<'a, 'b> = Box<dyn Ctor<'a, CharsCtx<'a>, &'a str, usize, Pass, &'a str> + 'b>;
+
+#[test]
+fn boxed_local() {
+    assert!(boxed_local_impl().is_ok());
+}
+
+fn boxed_local_impl() -> color_eyre::Result<()> {
+    color_eyre::install()?;
+
+    // the brackets are local data, the parser borrows them without `'static`
+    let brackets = [String::from("["), String::from("]")];
+    let (open, close) = (brackets[0].as_str(), brackets[1].as_str());
+
+    fn depth<'a: 'b, 'b>(open: &'b str, close: &'b str) -> DepthCtor<'a, 'b> {
+        // the recursion point, create the inner parser lazily
+        let nested = move |ctx: &mut CharsCtx<'a>| depth(open, close).construct(ctx, &mut Pass);
+        let nested = nested.quote(open, close);
+        let max_depth = move |ctx: &mut CharsCtx<'a>| {
+            let mut max = 0;
+            let mut offset = ctx.offset();
+
+            while let Ok(depth) = ctx.ctor(&nested) {
+                max = max.max(depth + 1);
+                offset = ctx.offset();
+            }
+            ctx.set_offset(offset);
+            Ok::<usize, Error>(max)
+        };
+
+        max_depth.boxed_local()
+    }
+
+    let parser = depth(open, close);
+
+    assert_eq!(CharsCtx::new("").ctor(&parser)?, 0);
+    assert_eq!(CharsCtx::new("[]").ctor(&parser)?, 1);
+    assert_eq!(CharsCtx::new("[[]][]").ctor(&parser)?, 2);
+    assert_eq!(CharsCtx::new("[[[]]][[]]").ctor(&parser)?, 3);
+
+    let mut ctx = CharsCtx::new("[][[]");
+
+    assert_eq!(ctx.ctor(&parser)?, 1);
+    assert_eq!(ctx.offset(), 2);
+
+    Ok(())
+}
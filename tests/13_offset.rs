@@ -0,0 +1,87 @@
+use neure::prelude::*;
+
+#[test]
+fn offset() {
+    assert!(offset_impl().is_ok());
+}
+
+fn offset_impl() -> color_eyre::Result<()> {
+    color_eyre::install()?;
+
+    let mut ctx = CharsCtx::new("αβγ");
+
+    ctx.inc(2);
+    assert_eq!(ctx.offset(), 2);
+    ctx.inc(4);
+    assert_eq!(ctx.offset(), 6);
+    ctx.dec(6);
+    assert_eq!(ctx.offset(), 0);
+    ctx.set_offset(ctx.len());
+    assert_eq!(ctx.try_mat(&re::end())?, Span::new(6, 0));
+
+    Ok(())
+}
+
+#[cfg(debug_assertions)]
+#[test]
+#[should_panic(expected = "out of bound")]
+fn offset_overshoot_inc() {
+    BytesCtx::new(b"abc").inc(4);
+}
+
+#[cfg(debug_assertions)]
+#[test]
+#[should_panic(expected = "can not decrease offset")]
+fn offset_overshoot_dec() {
+    BytesCtx::new(b"abc").inc(1).dec(2);
+}
+
+#[cfg(debug_assertions)]
+#[test]
+#[should_panic(expected = "not on a char boundary")]
+fn offset_not_char_boundary() {
+    CharsCtx::new("αβγ").set_offset(1);
+}
+
+#[test]
+fn scan_from() {
+    assert!(scan_from_impl().is_ok());
+}
+
+fn scan_from_impl() -> color_eyre::Result<()> {
+    let str = "ab中c";
+    let mut ctx = CharsCtx::new(str);
+
+    ctx.inc(2);
+    for (offset, ch) in ctx.scan_from(ctx.offset())? {
+        assert_eq!(str[offset..].chars().next(), Some(ch));
+    }
+    assert_eq!(ctx.scan_from(5)?.collect::<Vec<_>>(), [(5, 'c')]);
+    assert_eq!(ctx.scan_from(6)?.next(), None);
+
+    let bytes = b"\x01\x02\x03\x04";
+    let ctx = BytesCtx::new(bytes);
+
+    assert_eq!(
+        ctx.scan_from(2)?.collect::<Vec<_>>(),
+        [(2, 0x03), (3, 0x04)]
+    );
+
+    // scan in a custom regex
+    let digits = |ctx: &mut CharsCtx| {
+        let beg = ctx.offset();
+        let end = ctx
+            .scan_from(beg)?
+            .find(|(_, ch)| !ch.is_ascii_digit())
+            .map(|v| v.0)
+            .unwrap_or(ctx.len());
+
+        ctx.set_offset(end);
+        Ok(Span::new(beg, end - beg))
+    };
+    let mut ctx = CharsCtx::new("ab123cd");
+
+    ctx.inc(2);
+    assert_eq!(ctx.try_mat(&digits)?, Span::new(2, 3));
+    Ok(())
+}
@@ -0,0 +1,96 @@
+use neure::err::Error;
+use neure::prelude::*;
+
+#[test]
+fn progress() {
+    assert!(progress_impl().is_ok());
+}
+
+fn progress_impl() -> color_eyre::Result<()> {
+    color_eyre::install()?;
+
+    // the bounded repeat keeps matching empty data
+    let null = re::null().repeat(3..4);
+
+    assert_eq!(CharsCtx::new("ab").try_mat(&null)?, Span::new(0, 0));
+
+    let digits = neu::digit(10).repeat_zero_more();
+    let repeat = digits.repeat(2..3);
+
+    assert_eq!(CharsCtx::new("12ab").ctor(&repeat)?, ["12", ""]);
+
+    // the guard returns an error instead of looping forever
+    let guard = digits.repeat_with_progress_guard(0..);
+
+    assert!(matches!(
+        CharsCtx::new("12ab").ctor(&guard),
+        Err(Error::NoProgress { at: 2 })
+    ));
+    assert!(matches!(
+        CharsCtx::new("ab").try_mat(&guard),
+        Err(Error::NoProgress { at: 0 })
+    ));
+
+    let mut ctx = CharsCtx::new("12ab");
+
+    assert!(ctx
+        .try_mat(&re::null().repeat_with_progress_guard(..))
+        .is_err());
+    assert_eq!(ctx.offset(), 0);
+
+    let digits = neu::digit(10).repeat_one_more();
+    let guard = digits.ws().repeat_with_progress_guard(1..);
+
+    assert_eq!(CharsCtx::new("1 23 ab").ctor(&guard)?, ["1", "23"]);
+
+    // the element and the delimiter both matched nothing
+    let digits = neu::digit(10).repeat_zero_more();
+    let sep = digits.sep(",".opt()).with_guard(true);
+
+    assert!(matches!(
+        CharsCtx::new("1,2ab").ctor(&sep),
+        Err(Error::NoProgress { at: 3 })
+    ));
+    assert!(matches!(
+        CharsCtx::new("1,2ab").try_mat(&sep),
+        Err(Error::NoProgress { at: 3 })
+    ));
+    assert_eq!(
+        CharsCtx::new("1,2;").ctor(&digits.sep(",").with_guard(true))?,
+        ["1", "2"]
+    );
+
+    let sep_collect = digits
+        .sep_collect::<_, _, Vec<&str>>(",".opt())
+        .with_guard(true);
+    let mut ctx = CharsCtx::new("1,2ab");
+
+    assert!(matches!(
+        ctx.ctor(&sep_collect),
+        Err(Error::NoProgress { at: 3 })
+    ));
+    assert!(matches!(
+        ctx.try_mat(&sep_collect),
+        Err(Error::NoProgress { at: 3 })
+    ));
+    assert_eq!(ctx.offset(), 0);
+
+    let collect = digits.ws().collect::<_, Vec<&str>>().with_guard(true);
+    let mut ctx = CharsCtx::new("1 23 ab");
+
+    assert!(matches!(
+        ctx.ctor(&collect),
+        Err(Error::NoProgress { at: 5 })
+    ));
+    assert!(matches!(
+        ctx.try_mat(&collect),
+        Err(Error::NoProgress { at: 5 })
+    ));
+    assert_eq!(ctx.offset(), 0);
+
+    let digits = neu::digit(10).repeat_one_more();
+    let collect = digits.ws().collect::<_, Vec<&str>>().with_guard(true);
+
+    assert_eq!(CharsCtx::new("1 23 ab").ctor(&collect)?, ["1", "23"]);
+    Ok(())
+}
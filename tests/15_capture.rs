@@ -0,0 +1,54 @@
+use neure::ctx::SinkCtx;
+use neure::prelude::*;
+
+#[test]
+fn capture() {
+    assert!(capture_impl().is_ok());
+}
+
+fn capture_impl() -> color_eyre::Result<()> {
+    color_eyre::install()?;
+
+    let key = neu::ascii_alphabetic().repeat_one_more().capture(1);
+    let val = neu::digit(10).repeat_one_more().capture(2);
+    let pair = key.sep_once("=", val).capture(0);
+    let pairs = pair.sep(";".ws());
+    let str = "a=1; bc=23; d=456";
+    let mut ctx = CharsCtx::new(str).with_sink();
+
+    assert_eq!(ctx.ctor(&pairs)?, [("a", "1"), ("bc", "23"), ("d", "456")]);
+
+    let storer = ctx.take_captures();
+
+    assert_eq!(
+        storer.slice_iter(str, 0).unwrap().collect::<Vec<_>>(),
+        ["a=1", "bc=23", "d=456"]
+    );
+    assert_eq!(
+        storer.slice_iter(str, 1).unwrap().collect::<Vec<_>>(),
+        ["a", "bc", "d"]
+    );
+    assert_eq!(
+        storer.spans(2),
+        Some(&vec![Span::new(2, 1), Span::new(8, 2), Span::new(14, 3)])
+    );
+    assert!(ctx.captures().is_empty());
+
+    // also works with try_mat
+    let mut ctx = CharsCtx::new(str).with_sink();
+
+    assert_eq!(ctx.try_mat(&pairs)?, Span::new(0, str.len()));
+    assert_eq!(ctx.captures().spans(0).map(|v| v.len()), Some(3));
+
+    // the policy of inner context is invoked before matching
+    let key = neu::ascii_alphabetic().repeat_one_more().capture(1);
+    let val = neu::digit(10).repeat_one_more().capture(2);
+    let pairs = key.sep_once("=", val).sep(";");
+    let str = " a=1 ; bc=23;  d=456";
+    let inner = CharsCtx::new(str).ignore(neu::whitespace().repeat_full());
+    let mut ctx = SinkCtx::new(inner);
+
+    assert_eq!(ctx.ctor(&pairs)?, [("a", "1"), ("bc", "23"), ("d", "456")]);
+    assert_eq!(ctx.captures().spans(1).map(|v| v.len()), Some(3));
+    Ok(())
+}
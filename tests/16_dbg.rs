@@ -0,0 +1,62 @@
+use std::sync::Mutex;
+
+use neure::prelude::*;
+
+static MESSAGES: Mutex<Vec<String>> = Mutex::new(vec![]);
+
+fn record(msg: &str) {
+    MESSAGES.lock().unwrap().push(msg.to_owned());
+}
+
+#[test]
+fn dbg_on_err() {
+    assert!(dbg_on_err_impl().is_ok());
+}
+
+fn dbg_on_err_impl() -> color_eyre::Result<()> {
+    color_eyre::install()?;
+
+    let key = neu::ascii_alphabetic()
+        .repeat_one_more()
+        .dbg_on_err("key")
+        .with_hook(record);
+    let val = neu::digit(10)
+        .repeat_one_more()
+        .dbg_on_err("val")
+        .with_hook(record);
+    let pair = key.sep_once("=", val);
+
+    assert_eq!(CharsCtx::new("a=1").ctor(&pair)?, ("a", "1"));
+    let bytes = neu::ascii_alphabetic()
+        .repeat_one_more()
+        .dbg_on_err("key")
+        .with_hook(record)
+        .sep_once(b"=", neu::ascii_digit().repeat_one_more());
+
+    assert_eq!(BytesCtx::new(b"a=1").try_mat(&bytes)?, Span::new(0, 3));
+    assert!(MESSAGES.lock().unwrap().is_empty());
+
+    assert!(CharsCtx::new("a=xyz").ctor(&pair).is_err());
+    assert!(BytesCtx::new(b"=1").try_mat(&bytes).is_err());
+
+    let messages = std::mem::take(&mut *MESSAGES.lock().unwrap());
+
+    assert_eq!(messages.len(), 2);
+    assert!(messages[0].starts_with("r`val` failed @2: \"xyz\""));
+    assert!(messages[1].starts_with("r`key` failed @0: [61, 49]"));
+
+    let long = "1".repeat(30);
+
+    assert!(CharsCtx::new(&long).ctor(&pair).is_err());
+    assert!(
+        MESSAGES.lock().unwrap()[0].starts_with(&format!("r`key` failed @0: {:?}", &long[0..20]))
+    );
+
+    let long = format!("a={}", "x".repeat(30));
+
+    assert!(CharsCtx::new(&long).ctor(&pair).is_err());
+    assert!(
+        MESSAGES.lock().unwrap()[1].starts_with(&format!("r`val` failed @2: {:?} ", &long[2..22]))
+    );
+    Ok(())
+}
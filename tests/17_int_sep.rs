@@ -0,0 +1,39 @@
+use neure::prelude::*;
+
+#[test]
+fn int_sep() {
+    assert!(int_sep_impl().is_ok());
+}
+
+fn int_sep_impl() -> color_eyre::Result<()> {
+    color_eyre::install()?;
+
+    let int = re::int_with_separators(10).map(map::from_str_radix_sep::<i32>(10));
+
+    assert_eq!(CharsCtx::new("1_000").ctor(&int)?, 1000);
+    assert_eq!(CharsCtx::new("1_000_000;").ctor(&int)?, 1000000);
+    assert_eq!(CharsCtx::new("7").ctor(&int)?, 7);
+    assert_eq!(
+        BytesCtx::new(b"12_34").try_mat(&re::int_with_separators(10))?,
+        Span::new(0, 5)
+    );
+    assert!(CharsCtx::new("_1").ctor(&int).is_err());
+    assert!(CharsCtx::new("1__0").ctor(&int).is_err());
+    assert!(CharsCtx::new("10_").ctor(&int).is_err());
+    assert!(CharsCtx::new("").ctor(&int).is_err());
+
+    let mut ctx = CharsCtx::new("1__0");
+
+    assert!(ctx.try_mat(&re::int_with_separators(10)).is_err());
+    assert_eq!(ctx.offset(), 0);
+
+    let list = re::int_with_separators(16)
+        .map(map::from_str_radix_sep::<u32>(16))
+        .sep(",".ws());
+
+    assert_eq!(
+        CharsCtx::new("ff_ff, 1_0, a").ctor(&list)?,
+        [0xffff, 0x10, 0xa]
+    );
+    Ok(())
+}
@@ -0,0 +1,29 @@
+use neure::prelude::*;
+
+#[test]
+fn keyword() {
+    assert!(keyword_impl().is_ok());
+}
+
+fn keyword_impl() -> color_eyre::Result<()> {
+    color_eyre::install()?;
+
+    let kw = re::keyword("in");
+    let mut ctx = CharsCtx::new("integer");
+
+    assert_eq!(CharsCtx::new("in ").try_mat(&kw)?, Span::new(0, 2));
+    assert!(ctx.try_mat(&kw).is_err());
+    assert_eq!(ctx.offset(), 0);
+
+    let ident = neu::word().repeat_one_more();
+    let kw_or_ident = re::keyword("in")
+        .map(|v: &str| Ok(v.to_uppercase()))
+        .or(ident.map(|v: &str| Ok(v.to_owned())));
+    let stmt = kw_or_ident.sep(" ");
+
+    assert_eq!(
+        CharsCtx::new("x in integer in inx").ctor(&stmt)?,
+        ["x", "IN", "integer", "IN", "inx"]
+    );
+    Ok(())
+}
@@ -0,0 +1,34 @@
+use neure::prelude::*;
+
+#[test]
+fn trivia() {
+    assert!(trivia_impl().is_ok());
+}
+
+fn trivia_impl() -> color_eyre::Result<()> {
+    color_eyre::install()?;
+
+    let comment = re::not("*/").then(re::consume(1)).repeat(0..);
+    let comment = comment.quote("/*", "*/");
+    let ws = neu::whitespace().repeat_one_more();
+    let trivia = re::skip_trivia(comment.or(ws));
+    let ident = neu::ascii_alphabetic().repeat_one_more();
+    let idents = ident.padded(trivia).collect::<_, Vec<_>>();
+    let str = "a /*c*/ b";
+    let mut ctx = CharsCtx::new(str).with_sink();
+
+    assert_eq!(ctx.ctor(&idents)?, ["a", "b"]);
+
+    let trivia = ctx.take_trivia();
+    let comments: Vec<_> = trivia
+        .iter()
+        .map(|span| &str[span.beg..span.beg + span.len])
+        .filter(|v| v.starts_with("/*"))
+        .collect();
+
+    assert_eq!(trivia.len(), 3);
+    assert_eq!(trivia[1], Span::new(2, 5));
+    assert_eq!(comments, ["/*c*/"]);
+    assert!(ctx.trivia().is_empty());
+    Ok(())
+}
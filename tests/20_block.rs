@@ -0,0 +1,29 @@
+use neure::err::Error;
+use neure::prelude::*;
+
+#[test]
+fn block() {
+    assert!(block_impl().is_ok());
+}
+
+fn block_impl() -> color_eyre::Result<()> {
+    color_eyre::install()?;
+
+    let num = neu::digit(10).repeat_one_more().map(map::from_str::<i32>());
+    let block = re::block("begin".ws(), num.ws(), ",".ws(), "end");
+
+    assert_eq!(CharsCtx::new("begin 1,2,3 end").ctor(&block)?, [1, 2, 3]);
+    assert_eq!(CharsCtx::new("begin 1, 2, end").ctor(&block)?, [1, 2]);
+    assert_eq!(CharsCtx::new("begin end").ctor(&block)?, vec![]);
+
+    let mut ctx = CharsCtx::new("begin 1,2,3 ;");
+
+    assert!(matches!(ctx.ctor(&block), Err(Error::BlockEnd { at: 12 })));
+    assert_eq!(ctx.offset(), 0);
+    assert!(matches!(
+        CharsCtx::new("begin 1,2,3 ").try_mat(&block),
+        Err(Error::BlockEnd { at: 12 })
+    ));
+    assert!(CharsCtx::new("start 1,2,3 end").ctor(&block).is_err());
+    Ok(())
+}
@@ -0,0 +1,63 @@
+use neure::prelude::*;
+
+// The `Neu` units only match single item, the length of each item is taken from
+// the offset of next item, so the multibyte chars report correct span.
+#[test]
+fn multibyte() {
+    assert!(multibyte_impl().is_ok());
+}
+
+fn multibyte_impl() -> color_eyre::Result<()> {
+    color_eyre::install()?;
+
+    let str = "中a文b字";
+    let cjk = '中'.or('文').or('字');
+    let mixed = cjk.or(neu::ascii_alphabetic());
+
+    assert_eq!(
+        CharsCtx::new(str).try_mat(&cjk.repeat_one())?,
+        Span::new(0, 3)
+    );
+    assert_eq!(
+        CharsCtx::new(str).try_mat(&mixed.repeat_one_more())?,
+        Span::new(0, str.len())
+    );
+    assert_eq!(
+        CharsCtx::new(str).try_mat(&mixed.repeat_times::<3>())?,
+        Span::new(0, 7)
+    );
+    assert_eq!(
+        CharsCtx::new(str).try_mat(&mixed.repeat_range(2..4))?,
+        Span::new(0, 7)
+    );
+    assert_eq!(
+        CharsCtx::new(str).ctor(&mixed.repeat_one().collect::<_, Vec<_>>())?,
+        ["中", "a", "文", "b", "字"]
+    );
+
+    let not_ascii = neu::ascii().not();
+
+    assert_eq!(
+        CharsCtx::new("中文a").try_mat(&not_ascii.repeat_one_more())?,
+        Span::new(0, 6)
+    );
+
+    let alpha_cjk = neu::alphabetic().and(neu::ascii().not());
+
+    assert_eq!(
+        CharsCtx::new("中文a").try_mat(&alpha_cjk.repeat_one_more())?,
+        Span::new(0, 6)
+    );
+
+    let array = ['中', '文', 'a'];
+
+    assert_eq!(
+        CharsCtx::new("文中a字").try_mat(&array.repeat_one_more())?,
+        Span::new(0, 7)
+    );
+    assert_eq!(
+        CharsCtx::new("文中a字").try_mat(&re::count::<2, 2, _, _>(array))?,
+        Span::new(0, 6)
+    );
+    Ok(())
+}
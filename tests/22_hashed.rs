@@ -0,0 +1,31 @@
+#![cfg(feature = "hashing")]
+
+use neure::prelude::*;
+
+#[test]
+fn hashed() {
+    assert!(hashed_impl().is_ok());
+}
+
+fn hashed_impl() -> color_eyre::Result<()> {
+    color_eyre::install()?;
+
+    let ident = re::hashed(neu::word().repeat_one_more());
+    let idents = ident.sep(",".ws());
+    let ret = CharsCtx::new("alpha, beta, alpha, alphabet").ctor(&idents)?;
+
+    assert_eq!(ret.len(), 4);
+    assert_eq!(ret[0], ("alpha", re::ctor::fxhash(b"alpha")));
+    assert_eq!(ret[0].1, ret[2].1);
+    assert_ne!(ret[0].1, ret[1].1);
+    assert_ne!(ret[0].1, ret[3].1);
+
+    let bytes = re::hashed(re::consume(3));
+    let (_, fst) = BytesCtx::new(b"abcabc").ctor(&bytes)?;
+    let mut ctx = BytesCtx::new(b"xyzabc");
+
+    ctx.inc(3);
+    assert_eq!(ctx.ctor(&bytes)?.1, fst);
+    assert!(BytesCtx::new(b"ab").ctor(&bytes).is_err());
+    Ok(())
+}
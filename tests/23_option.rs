@@ -0,0 +1,30 @@
+use neure::map::MapSingle;
+use neure::prelude::*;
+
+#[test]
+fn option() {
+    assert!(option_impl().is_ok());
+}
+
+fn option_impl() -> color_eyre::Result<()> {
+    color_eyre::install()?;
+
+    let flatten = map::flatten_option();
+
+    assert_eq!(flatten.map_to(Some(None::<i32>))?, None);
+    assert_eq!(flatten.map_to(Some(Some(3)))?, Some(3));
+    assert_eq!(flatten.map_to(None::<Option<i32>>)?, None);
+
+    let num = neu::digit(10).repeat_one_more().map(map::from_str::<i32>());
+    let val = num.opt().quote("(", ")").opt().map(flatten);
+
+    assert_eq!(CharsCtx::new("(3)").ctor(&val)?, Some(3));
+    assert_eq!(CharsCtx::new("()").ctor(&val)?, None);
+    assert_eq!(CharsCtx::new("x").ctor(&val)?, None);
+
+    let val = val.map(map::ok_or(neure::err::Error::Option));
+
+    assert_eq!(CharsCtx::new("(42)").ctor(&val)?, 42);
+    assert!(CharsCtx::new("()").ctor(&val).is_err());
+    Ok(())
+}
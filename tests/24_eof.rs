@@ -0,0 +1,37 @@
+use neure::err::Error;
+use neure::prelude::*;
+
+#[test]
+fn eof() {
+    assert!(eof_impl().is_ok());
+}
+
+fn eof_impl() -> color_eyre::Result<()> {
+    color_eyre::install()?;
+
+    let word = neu::alphabetic().repeat_one_more();
+    let eof = re::eof();
+
+    assert_eq!(CharsCtx::new("").try_mat(&eof)?, Span::new(0, 0));
+
+    let mut ctx = CharsCtx::new("neure");
+
+    assert_eq!(ctx.try_mat(&word)?, Span::new(0, 5));
+    assert_eq!(ctx.try_mat(&eof)?, Span::new(5, 0));
+
+    let mut ctx = CharsCtx::new("neure!");
+
+    assert_eq!(ctx.try_mat(&word)?, Span::new(0, 5));
+    assert!(matches!(ctx.try_mat(&eof), Err(Error::TrailingInput)));
+    assert!(matches!(ctx.try_mat(&re::end()), Err(Error::End)));
+    assert_eq!(ctx.offset(), 5);
+
+    let parser = word.then(re::eof())._0();
+
+    assert_eq!(CharsCtx::new("neure").ctor(&parser)?, "neure");
+    assert!(matches!(
+        CharsCtx::new("neure!").ctor(&parser),
+        Err(Error::TrailingInput)
+    ));
+    Ok(())
+}
@@ -0,0 +1,38 @@
+use neure::prelude::*;
+
+fn ident(input: &str) -> color_eyre::Result<Box<str>> {
+    let ident = neu::ascii_alphabetic()
+        .repeat_one_more()
+        .map(map::to_box_str());
+
+    Ok(CharsCtx::new(input).ctor(&ident)?)
+}
+
+#[test]
+fn to_box() {
+    assert!(to_box_impl().is_ok());
+}
+
+fn to_box_impl() -> color_eyre::Result<()> {
+    color_eyre::install()?;
+
+    let mut input = String::from("let x = 1;");
+    let name = ident(&input[4..])?;
+
+    input.clear();
+    input.push_str("changed");
+    assert_eq!(&*name, "x");
+    assert_eq!(input, "changed");
+
+    let data = neu::ascii_hexdigit()
+        .repeat_one_more()
+        .map(map::to_box_bytes());
+    let data = {
+        let input = b"cafe;".to_vec();
+
+        BytesCtx::new(&input).ctor(&data)?
+    };
+
+    assert_eq!(&*data, b"cafe");
+    Ok(())
+}
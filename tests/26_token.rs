@@ -0,0 +1,47 @@
+use neure::prelude::*;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum Tok {
+    Num(i64),
+    Plus,
+}
+
+#[test]
+fn token() {
+    assert!(token_impl().is_ok());
+}
+
+fn token_impl() -> color_eyre::Result<()> {
+    color_eyre::install()?;
+
+    let num = neu::digit(10)
+        .repeat_one_more()
+        .map(map::from_str::<i64>())
+        .map(|v| Ok(Tok::Num(v)));
+    let plus = "+".map(|_| Ok(Tok::Plus));
+    let tok = num.or(plus).padded(neu::whitespace().repeat_full());
+    let toks: Vec<Tok> = CharsCtx::new("1 + 2").ctor(&tok.repeat(1..))?;
+
+    assert_eq!(toks, [Tok::Num(1), Tok::Plus, Tok::Num(2)]);
+
+    let num = (|t: &&Tok| matches!(t, Tok::Num(_)))
+        .repeat_one()
+        .map(|v: &[Tok]| match v {
+            [Tok::Num(v)] => Ok(*v),
+            _ => Err(neure::err::Error::Other),
+        });
+    let plus = (|t: &&Tok| matches!(t, Tok::Plus)).repeat_one();
+    let expr = num.sep_once(plus, num).map(|(l, r)| Ok(l + r));
+    let mut ctx = TokenCtx::new(&toks);
+
+    assert_eq!(ctx.ctor(&expr)?, 3);
+    assert_eq!(ctx.offset(), 3);
+    assert_eq!(ctx.try_mat(&re::eof())?, Span::new(3, 0));
+
+    let toks = [Tok::Num(1), Tok::Plus];
+    let mut ctx = TokenCtx::new(&toks);
+
+    assert!(ctx.ctor(&expr).is_err());
+    assert_eq!(ctx.offset(), 0);
+    Ok(())
+}
@@ -0,0 +1,50 @@
+use neure::err::Error;
+use neure::prelude::*;
+
+#[test]
+fn ascii() {
+    assert!(ascii_impl().is_ok());
+}
+
+fn ascii_impl() -> color_eyre::Result<()> {
+    color_eyre::install()?;
+
+    let ascii = re::require_ascii();
+    let word = neu::ascii_alphabetic().repeat_one_more();
+
+    assert_eq!(BytesCtx::new(b"").try_mat(&ascii)?, Span::new(0, 0));
+    assert_eq!(
+        BytesCtx::new(b"hello world").try_mat(&ascii)?,
+        Span::new(0, 0)
+    );
+
+    let mut ctx = BytesCtx::new(b"hello w\xc3\xb6rld");
+
+    assert!(matches!(
+        ctx.try_mat(&ascii),
+        Err(Error::NonAscii { at: 7 })
+    ));
+    assert_eq!(ctx.offset(), 0);
+    assert_eq!(ctx.try_mat(&word)?, Span::new(0, 5));
+    assert!(matches!(
+        ctx.try_mat(&ascii),
+        Err(Error::NonAscii { at: 7 })
+    ));
+
+    let mut ctx = BytesCtx::new(b"\xffabc");
+
+    assert_eq!(ctx.inc(1).try_mat(&ascii)?, Span::new(1, 0));
+
+    let mut ctx = CharsCtx::new("abc√");
+
+    assert!(matches!(
+        ctx.try_mat(&ascii),
+        Err(Error::NonAscii { at: 3 })
+    ));
+
+    let line = re::require_ascii().then(word)._1();
+
+    assert_eq!(BytesCtx::new(b"GET /").ctor(&line)?, b"GET");
+    assert!(BytesCtx::new(b"GET \x80").ctor(&line).is_err());
+    Ok(())
+}
@@ -0,0 +1,35 @@
+#![cfg(feature = "base64")]
+
+use neure::err::Error;
+use neure::map::MapSingle;
+use neure::prelude::*;
+
+#[test]
+fn base64() {
+    assert!(base64_impl().is_ok());
+}
+
+fn base64_impl() -> color_eyre::Result<()> {
+    color_eyre::install()?;
+
+    let standard = map::from_base64();
+    let url_safe = map::from_base64_url_safe();
+
+    assert_eq!(standard.map_to("aGVsbG8=")?, b"hello");
+    assert_eq!(standard.map_to(&b"aGVsbG8="[..])?, b"hello");
+    assert!(matches!(standard.map_to("aGVsbG8"), Err(Error::FromBase64)));
+    assert!(matches!(
+        standard.map_to("aGV*bG8="),
+        Err(Error::FromBase64)
+    ));
+    assert!(matches!(standard.map_to("-_8="), Err(Error::FromBase64)));
+    assert_eq!(url_safe.map_to("-_8=")?, [0xfb, 0xff]);
+    assert!(matches!(url_safe.map_to("+/8="), Err(Error::FromBase64)));
+
+    let data = neu::ascii_alphanumeric().or('+').or('/').or('=');
+    let data = data.repeat_one_more().map(standard);
+    let uri = data.padded("data:;base64,");
+
+    assert_eq!(CharsCtx::new("data:;base64,aGVsbG8=").ctor(&uri)?, b"hello");
+    Ok(())
+}
@@ -0,0 +1,62 @@
+use neure::err::Error;
+use neure::prelude::*;
+
+#[test]
+fn error_limit() {
+    assert!(error_limit_impl().is_ok());
+}
+
+fn error_limit_impl() -> color_eyre::Result<()> {
+    color_eyre::install()?;
+
+    let num = neu::digit(10).repeat_one_more().map(map::from_str::<i32>());
+    let stmts = num.clone().pad(";").recover_to(";").repeat(1..);
+    let input = "x;".repeat(1000);
+    let mut ctx = CharsCtx::new(&input).with_sink().with_error_limit(10);
+
+    assert!(matches!(ctx.ctor(&stmts), Err(Error::TooManyErrors)));
+    assert_eq!(ctx.errors().len(), 10);
+    assert_eq!(ctx.error_limit(), Some(10));
+
+    let input = "1;x;2;y;3;";
+    let mut ctx = CharsCtx::new(input).with_sink().with_error_limit(2);
+
+    assert_eq!(ctx.ctor(&stmts)?, [Some(1), None, Some(2), None, Some(3)]);
+    assert_eq!(ctx.take_errors().len(), 2);
+
+    ctx.reset_with(CharsCtx::new(input));
+    ctx.set_error_limit(None);
+    assert_eq!(ctx.ctor(&stmts)?.len(), 5);
+    assert_eq!(ctx.errors().len(), 2);
+
+    // the loops should not swallow the fatal error
+    let item = num.clone().recover_to(re::followed_by(","));
+    let sep = item.sep(",");
+    let input = "1,a,b,c,5";
+    let mut ctx = CharsCtx::new(input).with_sink().with_error_limit(1);
+
+    assert!(matches!(ctx.ctor(&sep), Err(Error::TooManyErrors)));
+    assert_eq!(ctx.offset(), 0);
+
+    let mut ctx = CharsCtx::new(input).with_sink().with_error_limit(3);
+
+    assert_eq!(ctx.ctor(&sep)?, [Some(1), None, None, None, Some(5)]);
+
+    let item = num.pad(",").recover_to(",");
+    let collect = item.clone().collect::<_, Vec<_>>();
+    let input = "1,a,b,c,5,";
+    let mut ctx = CharsCtx::new(input).with_sink().with_error_limit(1);
+
+    assert!(matches!(ctx.ctor(&collect), Err(Error::TooManyErrors)));
+    assert_eq!(ctx.offset(), 0);
+
+    let mut ctx = CharsCtx::new(input).with_sink().with_error_limit(3);
+
+    assert_eq!(ctx.ctor(&collect)?, [Some(1), None, None, None, Some(5)]);
+
+    let sep_collect = item.sep_collect::<_, _, Vec<_>>(re::null());
+    let mut ctx = CharsCtx::new(input).with_sink().with_error_limit(1);
+
+    assert!(matches!(ctx.ctor(&sep_collect), Err(Error::TooManyErrors)));
+    Ok(())
+}
@@ -0,0 +1,74 @@
+use neure::map::MapSingle;
+use neure::prelude::*;
+
+#[test]
+fn hex_float() {
+    assert!(hex_float_impl().is_ok());
+}
+
+fn hex_float_impl() -> color_eyre::Result<()> {
+    color_eyre::install()?;
+
+    let float = re::hex_float();
+
+    assert_eq!(BytesCtx::new(b"0x1.8p3").try_mat(&float)?, Span::new(0, 7));
+
+    for (src, len) in [
+        ("0x1.8p3", 7),
+        ("0X1P+10", 7),
+        ("0x.8p0", 6),
+        ("0x1.p-1", 7),
+        ("0xffp1f", 6),
+    ] {
+        assert_eq!(CharsCtx::new(src).try_mat(&float)?, Span::new(0, len));
+    }
+    for src in ["0x1.8", "0x.p1", "0xp1", "1.8p3", "0x1p", "0x1p+"] {
+        let mut ctx = CharsCtx::new(src);
+
+        assert!(ctx.try_mat(&float).is_err());
+        assert_eq!(ctx.offset(), 0);
+    }
+
+    let float = re::hex_float().map(map::parse_hex_float::<f64>());
+    let floats = float.sep(",".ws());
+
+    assert_eq!(
+        CharsCtx::new("0x1.8p3, 0x1p-1, 0x10p0").ctor(&floats)?,
+        [12.0, 0.5, 16.0]
+    );
+    assert_eq!(
+        CharsCtx::new("0x1.fffffffffffffp1023").ctor(&float)?,
+        f64::MAX
+    );
+
+    // the subnormal values and the mantissa wider than 53 bits are rounded once
+    for (src, val) in [
+        ("0x1.0000000000000p-1074", 5e-324),
+        ("0x1p-1075", 0.0),
+        ("0x1.8p-1075", 5e-324),
+        (
+            "0x0.fffffffffffffp-1022",
+            f64::from_bits(0xf_ffff_ffff_ffff),
+        ),
+        ("0x1.fffffffffffffp-1023", f64::MIN_POSITIVE),
+        ("0x1.00000000000008p0", 1.0),
+        ("0x1.00000000000018p0", 1.0 + 2.0 * f64::EPSILON),
+        ("0x1.000000000000080000000000000001p0", 1.0 + f64::EPSILON),
+        ("0x1.fffffffffffff8p1023", f64::INFINITY),
+    ] {
+        assert_eq!(CharsCtx::new(src).ctor(&float)?, val, "parsing {src}");
+    }
+    assert_eq!(map::parse_hex_float::<f64>().map_to("-0x1p-1074")?, -5e-324);
+
+    let float = re::hex_float().map(map::parse_hex_float::<f32>());
+
+    for (src, val) in [
+        ("0x1p-149", f32::from_bits(1)),
+        ("0x1.000001p0", 1.0),
+        ("0x1.000003p0", 1.0 + 2.0 * f32::EPSILON),
+        ("0x1.fffffep127", f32::MAX),
+    ] {
+        assert_eq!(CharsCtx::new(src).ctor(&float)?, val, "parsing {src}");
+    }
+    Ok(())
+}
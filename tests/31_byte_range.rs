@@ -0,0 +1,39 @@
+use neure::prelude::*;
+
+#[test]
+fn byte_range() {
+    assert!(byte_range_impl().is_ok());
+}
+
+fn byte_range_impl() -> color_eyre::Result<()> {
+    color_eyre::install()?;
+
+    let digit = neu::byte_range(b'0', b'9');
+    let lower = neu::byte_range(b'a', b'z');
+    let mut ctx = BytesCtx::new(b"0123456789a");
+
+    assert_eq!(ctx.try_mat(&digit.repeat_one_more())?, Span::new(0, 10));
+    assert_eq!(ctx.try_mat(&lower.repeat_one())?, Span::new(10, 1));
+
+    let ident = lower.repeat_one().then(lower.or(digit).repeat_zero_more());
+    let mut ctx = BytesCtx::new(b"x86 64");
+
+    assert_eq!(ctx.ctor(&ident)?, (&b"x"[..], &b"86"[..]));
+    assert!(BytesCtx::new(b"/").try_mat(&digit.repeat_one()).is_err());
+    assert!(BytesCtx::new(b":").try_mat(&digit.repeat_one()).is_err());
+
+    let single = neu::byte_range(b'_', b'_');
+
+    assert_eq!(
+        BytesCtx::new(b"__a").try_mat(&single.repeat_full())?,
+        Span::new(0, 2)
+    );
+    Ok(())
+}
+
+#[cfg(debug_assertions)]
+#[test]
+#[should_panic]
+fn byte_range_reversed() {
+    let _ = neu::byte_range(b'9', b'0');
+}
@@ -0,0 +1,42 @@
+use std::cell::RefCell;
+
+use neure::prelude::*;
+
+#[test]
+fn tap_spans() {
+    assert!(tap_spans_impl().is_ok());
+}
+
+fn tap_spans_impl() -> color_eyre::Result<()> {
+    color_eyre::install()?;
+
+    let spans = RefCell::new(vec![]);
+    let ident = neu::ascii_alphabetic().repeat_one_more();
+    let item = ident.tap_spans(|span: &Span| spans.borrow_mut().push(*span));
+    let list = item.sep(",".ws()).quote("(", ")");
+
+    assert_eq!(
+        CharsCtx::new("(foo, ba, r)").ctor(&list)?,
+        ["foo", "ba", "r"]
+    );
+    assert_eq!(
+        spans.take(),
+        [Span::new(1, 3), Span::new(6, 2), Span::new(10, 1)]
+    );
+
+    // failed match does not fire the callback
+    assert!(CharsCtx::new("123").ctor(&item).is_err());
+    assert!(spans.borrow().is_empty());
+
+    // works for regex too
+    let mut ctx = BytesCtx::new(b"ab cd");
+    let spans = RefCell::new(vec![]);
+    let word = neu::ascii_alphabetic().repeat_one_more();
+    let words = word
+        .tap_spans(|span: &Span| spans.borrow_mut().push(*span))
+        .sep(b" ");
+
+    assert_eq!(ctx.ctor_span(&words)?, [Span::new(0, 2), Span::new(3, 2)]);
+    assert_eq!(spans.take(), [Span::new(0, 2), Span::new(3, 2)]);
+    Ok(())
+}
@@ -0,0 +1,43 @@
+use neure::err::Error;
+use neure::prelude::*;
+
+#[test]
+fn take_till() {
+    assert!(take_till_impl().is_ok());
+}
+
+fn take_till_impl() -> color_eyre::Result<()> {
+    color_eyre::install()?;
+
+    let line = re::take_till(b'\n');
+    let mut ctx = BytesCtx::new(b"key: value\nnext\n");
+
+    assert_eq!(ctx.try_mat(&line)?, Span::new(0, 10));
+    assert_eq!(ctx.inc(1).ctor(&line)?, b"next");
+    assert_eq!(ctx.offset(), 15);
+    // empty line
+    assert_eq!(ctx.try_mat(&line)?, Span::new(15, 0));
+    assert!(matches!(
+        BytesCtx::new(b"no newline").try_mat(&line),
+        Err(Error::TakeTill)
+    ));
+
+    let line = re::take_till_or_eof(b'\n');
+    let mut ctx = BytesCtx::new(b"no newline");
+
+    assert_eq!(ctx.try_mat(&line)?, Span::new(0, 10));
+    assert_eq!(ctx.try_mat(&line)?, Span::new(10, 0));
+
+    let pair = re::take_till(b'=').sep_once(b"=", re::take_till_or_eof(b';'));
+    let pairs = pair.sep(b";");
+
+    assert_eq!(
+        BytesCtx::new(b"a=1;bc=22").ctor(&pairs)?,
+        [(&b"a"[..], &b"1"[..]), (b"bc", b"22")]
+    );
+
+    let mut ctx = CharsCtx::new("名字:值");
+
+    assert_eq!(ctx.ctor(&re::take_till(b':'))?, "名字");
+    Ok(())
+}
@@ -0,0 +1,61 @@
+use neure::err::Error;
+use neure::prelude::*;
+
+#[derive(Debug, PartialEq, Eq)]
+struct Record<'a> {
+    id: u32,
+    name: &'a str,
+}
+
+#[test]
+fn index_by() {
+    assert!(index_by_impl().is_ok());
+}
+
+fn index_by_impl() -> color_eyre::Result<()> {
+    color_eyre::install()?;
+
+    let id = neu::digit(10).repeat_one_more().map(map::from_str::<u32>());
+    let name = neu::ascii_alphabetic().repeat_one_more();
+    let record = id
+        .sep_once(",".ws(), name)
+        .map(|(id, name)| Ok(Record { id, name }))
+        .pad("\n");
+    let records = record.index_by(|r: &Record| r.id);
+    let mut ctx = CharsCtx::new("1, alice\n7, bob\n3, carol\n7, dave\n");
+    let index = ctx.ctor(&records)?;
+
+    assert_eq!(index.len(), 3);
+    assert_eq!(
+        index[&1],
+        Record {
+            id: 1,
+            name: "alice"
+        }
+    );
+    assert_eq!(
+        index[&3],
+        Record {
+            id: 3,
+            name: "carol"
+        }
+    );
+    // last wins
+    assert_eq!(
+        index[&7],
+        Record {
+            id: 7,
+            name: "dave"
+        }
+    );
+
+    let records = records.strict();
+    let mut ctx = CharsCtx::new("1, alice\n7, bob\n3, carol\n7, dave\n");
+
+    assert!(matches!(ctx.ctor(&records), Err(Error::IndexBy)));
+    assert_eq!(ctx.offset(), 0);
+    assert_eq!(CharsCtx::new("1, alice\n7, bob\n").ctor(&records)?.len(), 2);
+    assert!(CharsCtx::new("").ctor(&records).is_err());
+    assert!(CharsCtx::new("").ctor(&records.at_least(0))?.is_empty());
+    Ok(())
+}
@@ -0,0 +1,39 @@
+use neure::err::Error;
+use neure::map::MapSingle;
+use neure::prelude::*;
+
+#[test]
+fn non_empty() {
+    assert!(non_empty_impl().is_ok());
+}
+
+fn non_empty_impl() -> color_eyre::Result<()> {
+    color_eyre::install()?;
+
+    let digits = neu::digit(10).repeat_zero_more();
+    let num = digits.map(map::non_empty());
+
+    assert_eq!(CharsCtx::new("42").ctor(&num)?, "42");
+    assert!(matches!(CharsCtx::new("").ctor(&num), Err(Error::Empty)));
+    assert!(matches!(CharsCtx::new("x").ctor(&num), Err(Error::Empty)));
+
+    let item = neu::ascii_alphabetic().repeat_one();
+    let items = item.repeat(0..).map(map::non_empty());
+
+    assert_eq!(CharsCtx::new("ab").ctor(&items)?, ["a", "b"]);
+    assert!(matches!(
+        CharsCtx::new("12").ctor(&items),
+        Err(Error::Empty)
+    ));
+
+    let bytes = b'a'.repeat_zero_more().map(map::non_empty());
+
+    assert_eq!(BytesCtx::new(b"aab").ctor(&bytes)?, b"aa");
+    assert!(BytesCtx::new(b"b").ctor(&bytes).is_err());
+
+    assert_eq!(map::non_empty().map_to(String::from("s"))?, "s");
+    assert!(map::non_empty().map_to(String::new()).is_err());
+    assert_eq!(map::non_empty().map_to(vec![1])?, [1]);
+    assert!(map::non_empty().map_to(Vec::<i32>::new()).is_err());
+    Ok(())
+}
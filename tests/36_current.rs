@@ -0,0 +1,31 @@
+use neure::prelude::*;
+
+#[test]
+fn current() {
+    assert!(current_impl().is_ok());
+}
+
+fn current_impl() -> color_eyre::Result<()> {
+    color_eyre::install()?;
+
+    let mut ctx = CharsCtx::new("变量=1");
+
+    assert_eq!(ctx.current(), Some('变'));
+    assert_eq!(ctx.inc(3).current(), Some('量'));
+    assert_eq!(ctx.inc(3).current(), Some('='));
+    assert_eq!(ctx.inc(2).current(), None);
+    assert_eq!(ctx.offset(), ctx.len());
+
+    let mut ctx = BytesCtx::new(b"\xff\x01");
+
+    assert_eq!(ctx.current(), Some(0xff));
+    assert_eq!(ctx.inc(2).current(), None);
+
+    // use in the condition
+    let sign = "-".r#if(|ctx: &CharsCtx| Ok(ctx.current() == Some('-')), "+");
+
+    assert_eq!(CharsCtx::new("-1").ctor(&sign)?, "-");
+    assert_eq!(CharsCtx::new("+1").ctor(&sign)?, "+");
+    assert_eq!(CharsCtx::new("").with_sink().current(), None);
+    Ok(())
+}
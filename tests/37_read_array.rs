@@ -0,0 +1,38 @@
+use neure::err::Error;
+use neure::prelude::*;
+
+#[test]
+fn read_array() {
+    assert!(read_array_impl().is_ok());
+}
+
+fn read_array_impl() -> color_eyre::Result<()> {
+    color_eyre::install()?;
+
+    let buf = [0x01, 0x00, 0x02, 0x01, 0xff, 0xff, 0x34, 0x12];
+    let header = re::read_array_le::<u16, 4>();
+    let mut ctx = BytesCtx::new(&buf);
+    let vals: [u16; 4] = ctx.ctor(&header)?;
+
+    assert_eq!(vals, [0x0001, 0x0102, 0xffff, 0x1234]);
+    assert_eq!(ctx.offset(), 8);
+    assert_eq!(header.size(), 8);
+
+    let mut ctx = BytesCtx::new(&buf[..7]);
+
+    assert!(matches!(ctx.ctor(&header), Err(Error::ReadArray)));
+    assert_eq!(ctx.offset(), 0);
+    assert_eq!(
+        ctx.try_mat(&re::read_array_le::<u16, 3>())?,
+        Span::new(0, 6)
+    );
+
+    let signed = re::read_array_le::<i16, 2>();
+
+    assert_eq!(BytesCtx::new(&buf[4..]).ctor(&signed)?, [-1, 0x1234]);
+    assert_eq!(
+        BytesCtx::new(&buf).ctor(&re::read_array_le::<u32, 0>())?,
+        []
+    );
+    Ok(())
+}
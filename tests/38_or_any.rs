@@ -0,0 +1,41 @@
+use neure::err::Error;
+use neure::prelude::*;
+
+#[test]
+fn or_any() {
+    assert!(or_any_impl().is_ok());
+}
+
+fn or_any_impl() -> color_eyre::Result<()> {
+    color_eyre::install()?;
+
+    let alts = ["red", "green", "blue", "cyan", "magenta"];
+    let color = re::any_of(alts.map(|v| re::string(v)));
+    let mut ctx = CharsCtx::new("blue!");
+
+    assert_eq!(ctx.ctor(&color)?, "blue");
+    assert_eq!(ctx.offset(), 4);
+    assert!(matches!(
+        ctx.ctor(&color),
+        Err(Error::NoAlternative { tried: 5 })
+    ));
+    assert_eq!(ctx.offset(), 4);
+    assert_eq!(CharsCtx::new("cyan").try_mat(&color)?, Span::new(0, 4));
+
+    let color = "red".or_any(vec!["green", "blue", "cyan", "magenta"]);
+    let idx = color.map(|v: &str| Ok(alts.iter().position(|a| *a == v)));
+
+    assert_eq!(CharsCtx::new("blue").ctor(&idx)?, Some(2));
+    assert!(matches!(
+        CharsCtx::new("yellow").ctor(&idx),
+        Err(Error::NoAlternative { tried: 5 })
+    ));
+
+    let none = re::any_of(Vec::<&str>::new());
+
+    assert!(matches!(
+        CharsCtx::new("").ctor(&none),
+        Err(Error::NoAlternative { tried: 0 })
+    ));
+    Ok(())
+}
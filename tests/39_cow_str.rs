@@ -0,0 +1,34 @@
+use std::borrow::Cow;
+
+use neure::prelude::*;
+
+#[test]
+fn cow_str() {
+    assert!(cow_str_impl().is_ok());
+}
+
+fn cow_str_impl() -> color_eyre::Result<()> {
+    color_eyre::install()?;
+
+    let ident = neu::ascii_alphabetic()
+        .repeat_one()
+        .then(neu::ascii_alphanumeric().or('_').repeat_zero_more())
+        .pat()
+        .map(map::to_cow_str());
+    let input = "foo_1 = 2";
+    let mut ctx = CharsCtx::new(input);
+    let val = ctx.ctor(&ident)?;
+
+    assert!(matches!(val, Cow::Borrowed(_)));
+    assert_eq!(val, "foo_1");
+    // point to the input, no allocation
+    assert_eq!(val.as_ptr(), input.as_ptr());
+
+    let idents = ident.sep(",".ws());
+    let vals = CharsCtx::new("a, b2, c_").ctor(&idents)?;
+
+    assert!(vals.iter().all(|v| matches!(v, Cow::Borrowed(_))));
+    assert_eq!(vals, ["a", "b2", "c_"]);
+    assert!(CharsCtx::new("1a").ctor(&ident).is_err());
+    Ok(())
+}
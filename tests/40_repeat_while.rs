@@ -0,0 +1,62 @@
+use neure::prelude::*;
+
+#[test]
+fn repeat_while() {
+    assert!(repeat_while_impl().is_ok());
+}
+
+fn repeat_while_impl() -> color_eyre::Result<()> {
+    color_eyre::install()?;
+
+    // the first byte is the limit of the payload length
+    let limit = |ctx: &BytesCtx| {
+        let limit = ctx.orig_at(0)?[0] as usize;
+
+        Ok(ctx.offset() - 1 < limit)
+    };
+    let word = neu::ascii_alphabetic().repeat_one_more().pad(b" ");
+    let words = re::repeat_while(limit, word);
+    let data = b"\x06ab cd ef gh";
+    let mut ctx = BytesCtx::new(data);
+
+    ctx.inc(1);
+    // stop once the matched length exceeds 6
+    assert_eq!(ctx.ctor(&words)?, [&b"ab"[..], b"cd"]);
+    assert_eq!(ctx.offset(), 7);
+
+    let mut ctx = BytesCtx::new(b"\x07ab cd ef gh");
+
+    ctx.inc(1);
+    assert_eq!(ctx.ctor(&words)?, [&b"ab"[..], b"cd", b"ef"]);
+    assert_eq!(ctx.offset(), 10);
+
+    let mut ctx = BytesCtx::new(b"\x00ab cd");
+
+    ctx.inc(1);
+    assert!(ctx.ctor(&words)?.is_empty());
+    assert!(ctx.ctor(&words.at_least(1)).is_err());
+    assert_eq!(ctx.offset(), 1);
+
+    let mut ctx = BytesCtx::new(b"\x04ab cd ef");
+
+    ctx.inc(1);
+    assert_eq!(ctx.try_mat(&words)?, Span::new(1, 6));
+
+    // the predicate failed after one iteration, the offset is restored
+    let fail = |ctx: &BytesCtx| {
+        if ctx.offset() > 0 {
+            Err(neure::err::Error::RepeatWhile)
+        } else {
+            Ok(true)
+        }
+    };
+    let word = neu::ascii_alphabetic().repeat_one_more().pad(b" ");
+    let words = re::repeat_while(fail, word);
+    let mut ctx = BytesCtx::new(b"ab cd ef");
+
+    assert!(ctx.ctor(&words).is_err());
+    assert_eq!(ctx.offset(), 0);
+    assert!(ctx.try_mat(&words).is_err());
+    assert_eq!(ctx.offset(), 0);
+    Ok(())
+}
@@ -0,0 +1,36 @@
+#![cfg(feature = "serde")]
+
+use neure::prelude::*;
+use neure::span::SimpleStorer;
+
+#[test]
+fn serde() {
+    assert!(serde_impl().is_ok());
+}
+
+fn serde_impl() -> color_eyre::Result<()> {
+    color_eyre::install()?;
+
+    let word = neu::ascii_alphabetic().repeat_one_more();
+    let spans = CharsCtx::new("a bc def").ctor_span(&word.sep(" "))?;
+    let json = serde_json::to_string(&spans)?;
+
+    assert_eq!(
+        json,
+        r#"[{"beg":0,"len":1},{"beg":2,"len":2},{"beg":5,"len":3}]"#
+    );
+    assert_eq!(serde_json::from_str::<Vec<Span>>(&json)?, spans);
+
+    let storer = SimpleStorer::new_with(vec![spans.clone(), vec![]]);
+    let json = serde_json::to_string(&storer)?;
+    let storer: SimpleStorer = serde_json::from_str(&json)?;
+
+    assert_eq!(storer.len(), 2);
+    assert_eq!(storer.spans(0), Some(&spans));
+    assert_eq!(storer.spans(1), None);
+
+    let reason: re::EndReason = serde_json::from_str(r#""MaxReached""#)?;
+
+    assert_eq!(reason, re::EndReason::MaxReached);
+    Ok(())
+}
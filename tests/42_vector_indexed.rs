@@ -0,0 +1,43 @@
+use neure::prelude::*;
+
+#[test]
+fn vector_indexed() {
+    assert!(vector_indexed_impl().is_ok());
+}
+
+fn vector_indexed_impl() -> color_eyre::Result<()> {
+    color_eyre::install()?;
+
+    let hex = "0x".then(neu::ascii_hexdigit().repeat_one_more()).pat();
+    let bin = "0b".then(neu::range('0'..='1').repeat_one_more()).pat();
+    let dec = neu::digit(10).repeat_one_more();
+    let num = re::vector_indexed([
+        hex.into_dyn_regex(),
+        bin.into_dyn_regex(),
+        dec.into_dyn_regex(),
+    ]);
+    let num = num.map(|(idx, val): (usize, &str)| {
+        let radix = [16, 2, 10][idx];
+        let val = val.trim_start_matches("0x").trim_start_matches("0b");
+
+        Ok((
+            idx,
+            i64::from_str_radix(val, radix).map_err(|_| neure::err::Error::Other)?,
+        ))
+    });
+
+    assert_eq!(CharsCtx::new("0xff").ctor(&num)?, (0, 255));
+    assert_eq!(CharsCtx::new("0b101").ctor(&num)?, (1, 5));
+    assert_eq!(CharsCtx::new("42").ctor(&num)?, (2, 42));
+    assert!(CharsCtx::new("x").ctor(&num).is_err());
+
+    let kw = re::vector_indexed(["if", "else", "while"]);
+    let kws = kw.clone().sep(" ");
+
+    assert_eq!(
+        CharsCtx::new("while if else").ctor(&kws)?,
+        [(2, "while"), (0, "if"), (1, "else")]
+    );
+    assert_eq!(CharsCtx::new("else").try_mat(&kw)?, Span::new(0, 4));
+    Ok(())
+}
@@ -0,0 +1,37 @@
+use neure::prelude::*;
+
+#[test]
+fn sep_trivia() {
+    assert!(sep_trivia_impl().is_ok());
+}
+
+fn sep_trivia_impl() -> color_eyre::Result<()> {
+    color_eyre::install()?;
+
+    let num = neu::digit(10).repeat_one_more().map(map::from_str::<i32>());
+    let comment = "/*"
+        .then(re::not("*/").then(re::consume(1)).repeat(0..))
+        .then("*/");
+    let trivia = comment.or(neu::whitespace().repeat_one_more()).repeat(0..);
+    let nums = num.sep(",").with_trivia(trivia);
+
+    assert_eq!(CharsCtx::new("1 /*x*/, 2").ctor(&nums)?, [1, 2]);
+    assert_eq!(CharsCtx::new("1,2").ctor(&nums)?, [1, 2]);
+    assert_eq!(CharsCtx::new("1 /* a * b */, 2").ctor(&nums)?, [1, 2]);
+    assert_eq!(
+        CharsCtx::new("1/*a*//*b*/ ,/*c*/3 , 4").ctor(&nums)?,
+        [1, 3, 4]
+    );
+
+    // the trailing trivia without separator is not consumed
+    let mut ctx = CharsCtx::new("1 , 2 /*end*/");
+
+    assert_eq!(ctx.ctor(&nums)?, [1, 2]);
+    assert_eq!(ctx.offset(), 5);
+
+    let list = nums.quote("[", "]");
+
+    assert!(CharsCtx::new("[1, 2 ]").ctor(&list).is_err());
+    assert_eq!(CharsCtx::new("[1 /* one */, 2]").ctor(&list)?, [1, 2]);
+    Ok(())
+}
@@ -0,0 +1,35 @@
+use neure::err::Error;
+use neure::map::MapSingle;
+use neure::prelude::*;
+
+#[test]
+fn transpose() {
+    assert!(transpose_impl().is_ok());
+}
+
+fn transpose_impl() -> color_eyre::Result<()> {
+    color_eyre::install()?;
+
+    let transpose = map::transpose();
+
+    assert_eq!(transpose.map_to(Some(Ok(3)))?, Some(3));
+    assert!(matches!(
+        transpose.map_to(Some(Err::<i32, _>(Error::Other))),
+        Err(Error::Other)
+    ));
+    assert_eq!(transpose.map_to(None::<Result<i32, Error>>)?, None);
+
+    let num = neu::digit(10).repeat_one_more().map(map::from_str::<u8>());
+    let num = num
+        .opt()
+        .map(|v: Option<u8>| Ok(v.map(|v| v.checked_mul(2).ok_or(Error::Overflow))))
+        .map(map::transpose());
+
+    assert_eq!(CharsCtx::new("21").ctor(&num)?, Some(42));
+    assert_eq!(CharsCtx::new("").ctor(&num)?, None);
+    assert!(matches!(
+        CharsCtx::new("200").ctor(&num),
+        Err(Error::Overflow)
+    ));
+    Ok(())
+}
@@ -0,0 +1,75 @@
+use neure::prelude::*;
+use neure::re::ctor::Scratch;
+
+#[test]
+fn or_transform() {
+    assert!(or_transform_impl().is_ok());
+}
+
+fn or_transform_impl() -> color_eyre::Result<()> {
+    color_eyre::install()?;
+
+    let scratch = Scratch::new();
+    let digits = neu::digit(10).repeat_one_more();
+    let float = digits.then(".").then(digits).pat();
+    let float = float.map(map::from_str::<f64>());
+    let float = float.or_transform(&scratch, |s: &str| {
+        let len = s
+            .find(|c: char| !c.is_ascii_digit() && c != ',')
+            .unwrap_or(s.len());
+
+        Some((len, s[..len].replacen(',', ".", 1).into()))
+    });
+    let mut ctx = CharsCtx::new("3,14");
+
+    assert_eq!(ctx.ctor(&float)?, "3.14".parse::<f64>()?);
+    assert_eq!(ctx.offset(), 4);
+    assert_eq!(scratch.len(), 1);
+
+    // no retry if the pattern matched
+    assert_eq!(CharsCtx::new("2.5").ctor(&float)?, 2.5);
+    assert_eq!(scratch.len(), 1);
+    assert!(CharsCtx::new("25").ctor(&float).is_err());
+    assert_eq!(scratch.len(), 2);
+
+    // the retry must consume all the repaired data
+    let mut ctx = CharsCtx::new("1,2,3");
+
+    assert!(ctx.ctor(&float).is_err());
+    assert_eq!(ctx.offset(), 0);
+
+    // the length replaced is counted in bytes
+    let mut ctx = CharsCtx::new("1,5 €");
+
+    assert_eq!(ctx.ctor(&float)?, 1.5);
+    assert_eq!(ctx.offset(), 3);
+
+    let scratch = Scratch::<str>::default();
+
+    for i in 0..100 {
+        assert_eq!(scratch.alloc(i.to_string().into()), i.to_string());
+    }
+    assert_eq!(scratch.len(), 100);
+
+    let mut ctx = CharsCtx::new("3,14;");
+
+    assert_eq!(ctx.try_mat(&float)?, Span::new(0, 4));
+    assert_eq!(ctx.offset(), 4);
+
+    // a length out of the remaining data or not on a char boundary fails the retry
+    let one = "1".or_transform(&scratch, |s: &str| Some((s.len() + 1, "1".into())));
+    let mut ctx = CharsCtx::new("一");
+
+    assert!(ctx.ctor(&one).is_err());
+    assert_eq!(ctx.offset(), 0);
+    assert!(ctx.try_mat(&one).is_err());
+    assert_eq!(ctx.offset(), 0);
+
+    let one = "1".or_transform(&scratch, |_: &str| Some((1, "1".into())));
+
+    assert!(ctx.ctor(&one).is_err());
+    assert!(ctx.try_mat(&one).is_err());
+    assert_eq!(ctx.offset(), 0);
+    assert_eq!(CharsCtx::new("a").ctor(&one)?, "1");
+    Ok(())
+}
@@ -0,0 +1,31 @@
+use neure::prelude::*;
+
+#[test]
+fn count_all() {
+    assert!(count_all_impl().is_ok());
+}
+
+fn count_all_impl() -> color_eyre::Result<()> {
+    color_eyre::install()?;
+
+    let ab = re::count_all("ab");
+    let mut ctx = CharsCtx::new("abxabab");
+
+    assert_eq!(ctx.ctor(&ab)?, 3);
+    assert_eq!(ctx.offset(), 7);
+
+    let aa = re::count_all("aa");
+    let mut ctx = CharsCtx::new("aaaxa");
+
+    assert_eq!(ctx.ctor(&aa)?, 1);
+    assert_eq!(ctx.offset(), 5);
+
+    let mut ctx = BytesCtx::new(b"xyz");
+
+    assert_eq!(ctx.ctor(&re::count_all(b"ab"))?, 0);
+    assert_eq!(ctx.try_mat(&re::count_all(b"ab"))?, Span::new(3, 0));
+    let mut ctx = BytesCtx::new(b"ab-ab");
+
+    assert_eq!(ctx.try_mat(&re::count_all(b"ab"))?, Span::new(0, 5));
+    Ok(())
+}
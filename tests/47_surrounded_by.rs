@@ -0,0 +1,23 @@
+use neure::prelude::*;
+
+#[test]
+fn surrounded_by() {
+    assert!(surrounded_by_impl().is_ok());
+}
+
+fn surrounded_by_impl() -> color_eyre::Result<()> {
+    color_eyre::install()?;
+
+    let text = neu::ascii_alphabetic().repeat_one_more();
+    let bold = text.surrounded_by("*");
+
+    assert_eq!(CharsCtx::new("*text*").ctor(&bold)?, "text");
+    assert_eq!(CharsCtx::new("*text*").try_mat(&bold)?, Span::new(0, 6));
+
+    assert!(CharsCtx::new("*text|").ctor(&bold).is_err());
+
+    let pipe = neu::ascii_digit().repeat_one_more().surrounded_by(b"|");
+
+    assert_eq!(BytesCtx::new(b"|42|").ctor(&pipe)?, b"42");
+    Ok(())
+}
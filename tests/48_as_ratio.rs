@@ -0,0 +1,29 @@
+use neure::err::Error;
+use neure::map::MapSingle;
+use neure::prelude::*;
+
+#[test]
+fn as_ratio() {
+    assert!(as_ratio_impl().is_ok());
+}
+
+fn as_ratio_impl() -> color_eyre::Result<()> {
+    color_eyre::install()?;
+
+    assert_eq!(map::as_ratio().map_to((3, 4))?, 0.75);
+    assert_eq!(map::as_ratio().map_to((-1, 2))?, -0.5);
+    assert!(matches!(
+        map::as_ratio().map_to((1, 0)),
+        Err(Error::DivByZero)
+    ));
+
+    let int = neu::digit(10).repeat_one_more().map(map::from_str::<i64>());
+    let ratio = int.sep_once("/", int).map(map::as_ratio());
+
+    assert_eq!(CharsCtx::new("3/4").ctor(&ratio)?, 0.75);
+    assert!(matches!(
+        CharsCtx::new("1/0").ctor(&ratio),
+        Err(Error::DivByZero)
+    ));
+    Ok(())
+}
@@ -0,0 +1,62 @@
+use neure::err::Error;
+use neure::prelude::*;
+
+#[test]
+fn max_depth() {
+    assert!(max_depth_impl().is_ok());
+}
+
+fn max_depth_impl() -> color_eyre::Result<()> {
+    color_eyre::install()?;
+
+    let deep = format!("{}{}", "[".repeat(100000), "]".repeat(100000));
+    let arr = re::rec_parser(|ctor| {
+        ctor.repeat(0..)
+            .quote("[", "]")
+            .map(|v: Vec<usize>| Ok(v.into_iter().max().unwrap_or(0) + 1))
+            .max_depth(5)
+    });
+
+    let mut ctx = CharsCtx::new("[[[[]]][]]").with_sink();
+
+    assert_eq!(ctx.ctor(&arr)?, 4);
+    assert_eq!(ctx.depth(), 0);
+
+    let mut ctx = CharsCtx::new("[[[[[]]]]]").with_sink();
+
+    assert!(matches!(ctx.ctor(&arr), Err(Error::DepthExceeded)));
+    assert_eq!(ctx.depth(), 0);
+    assert_eq!(ctx.offset(), 0);
+
+    let mut ctx = CharsCtx::new(&deep).with_sink();
+
+    assert!(matches!(ctx.ctor(&arr), Err(Error::DepthExceeded)));
+    assert_eq!(ctx.depth(), 0);
+
+    let list = re::rec_parser(|ctor| {
+        ctor.or("x".map(|_| Ok(1)))
+            .sep(",")
+            .quote("[", "]")
+            .map(|v: Vec<usize>| Ok(v.into_iter().max().unwrap_or(0) + 1))
+            .max_depth(2)
+    });
+
+    // the fatal error should not be swallowed by the alternatives and loops
+    assert_eq!(CharsCtx::new("[x,x]").with_sink().ctor(&list)?, 2);
+    assert!(matches!(
+        CharsCtx::new("[x,[x,[x]]]").with_sink().ctor(&list),
+        Err(Error::DepthExceeded)
+    ));
+    assert!(matches!(
+        CharsCtx::new("[[x],[[x]]]").with_sink().ctor(&list.opt()),
+        Err(Error::DepthExceeded)
+    ));
+
+    let nested = b"(".max_depth(1).then(b")");
+
+    assert_eq!(
+        BytesCtx::new(b"()").with_sink().try_mat(&nested)?,
+        Span::new(0, 2)
+    );
+    Ok(())
+}
@@ -0,0 +1,47 @@
+use neure::err::Error;
+use neure::map::MapSingle;
+use neure::prelude::*;
+
+#[test]
+fn fixed_point() {
+    assert!(fixed_point_impl().is_ok());
+}
+
+fn fixed_point_impl() -> color_eyre::Result<()> {
+    color_eyre::install()?;
+
+    let cents = map::fixed_point(2);
+
+    assert_eq!(cents.map_to("12.34")?, 1234);
+    assert_eq!(cents.map_to("1.2")?, 120);
+    assert_eq!(cents.map_to("0")?, 0);
+    assert_eq!(cents.map_to("-3.05")?, -305);
+    assert_eq!(cents.map_to("+3.5")?, 350);
+    assert!(matches!(cents.map_to("1.234"), Err(Error::FixedPoint)));
+    assert!(matches!(cents.map_to("1."), Err(Error::FixedPoint)));
+    assert!(matches!(cents.map_to(".5"), Err(Error::FixedPoint)));
+    assert!(matches!(cents.map_to("1a.5"), Err(Error::FixedPoint)));
+    assert!(matches!(
+        cents.map_to("99999999999999999999"),
+        Err(Error::Overflow)
+    ));
+    assert!(matches!(
+        map::fixed_point(19).map_to("1"),
+        Err(Error::Overflow)
+    ));
+    assert_eq!(map::fixed_point(0).map_to("42")?, 42);
+    assert_eq!(
+        Error::Overflow.to_string(),
+        "In (`map`): value out of range of target type"
+    );
+
+    let digits = neu::digit(10).repeat_one_more();
+    let price = digits.then(".".then(digits).opt()).pat();
+    let prices = price.map(cents).sep(",");
+
+    assert_eq!(
+        CharsCtx::new("12.34,1.2,5").ctor(&prices)?,
+        [1234, 120, 500]
+    );
+    Ok(())
+}
@@ -0,0 +1,35 @@
+use neure::prelude::*;
+
+#[test]
+fn repeat_string() {
+    assert!(repeat_string_impl().is_ok());
+}
+
+fn repeat_string_impl() -> color_eyre::Result<()> {
+    color_eyre::install()?;
+
+    let upper = neu::ascii_alphabetic()
+        .repeat_one()
+        .map(|v: &str| Ok(v.chars().next().unwrap().to_ascii_uppercase()));
+    let word = upper.repeat_string(2..=4);
+
+    assert_eq!(CharsCtx::new("ab").ctor(&word)?, "AB");
+    assert_eq!(CharsCtx::new("abcd").ctor(&word)?, "ABCD");
+
+    let mut ctx = CharsCtx::new("abcdef");
+
+    assert_eq!(ctx.ctor(&word)?, "ABCD");
+    assert_eq!(ctx.offset(), 4);
+
+    let mut ctx = CharsCtx::new("a1");
+
+    assert!(ctx.ctor(&word).is_err());
+    assert_eq!(ctx.offset(), 0);
+
+    let word = upper.repeat_string(..3);
+
+    assert_eq!(CharsCtx::new("1").ctor(&word)?, "");
+    assert_eq!(CharsCtx::new("abc").ctor(&word)?, "AB");
+    assert_eq!(CharsCtx::new("abc").try_mat(&word)?, Span::new(0, 2));
+    Ok(())
+}
@@ -0,0 +1,33 @@
+use neure::err::Error;
+use neure::prelude::*;
+
+#[test]
+fn lex_step() {
+    assert!(lex_step_impl().is_ok());
+}
+
+fn lex_step_impl() -> color_eyre::Result<()> {
+    color_eyre::install()?;
+
+    let rules = ["<", "<=", "=", "<<"];
+    let step = re::lex_step(rules);
+    let mut ctx = CharsCtx::new("<=<<<");
+
+    assert_eq!(ctx.ctor_span(&step)?, (1, Span::new(0, 2)));
+    assert_eq!(ctx.ctor_span(&step)?, (3, Span::new(2, 2)));
+    assert_eq!(ctx.ctor(&step)?, (0, "<"));
+    assert!(matches!(ctx.ctor(&step), Err(Error::LexStep)));
+    assert_eq!(ctx.offset(), 5);
+
+    let mut ctx = CharsCtx::new("x<=");
+
+    assert!(ctx.ctor(&step).is_err());
+    assert_eq!(ctx.offset(), 0);
+
+    let mut ctx = BytesCtx::new(b"<==");
+    let step = re::lex_step([b"=".as_slice(), b"<".as_slice(), b"<=".as_slice()]);
+
+    assert_eq!(ctx.try_mat(&step)?, Span::new(0, 2));
+    assert_eq!(ctx.try_mat(&step)?, Span::new(2, 1));
+    Ok(())
+}
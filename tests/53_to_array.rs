@@ -0,0 +1,38 @@
+use neure::err::Error;
+use neure::map::MapSingle;
+use neure::prelude::*;
+
+#[test]
+fn to_array() {
+    assert!(to_array_impl().is_ok());
+}
+
+fn to_array_impl() -> color_eyre::Result<()> {
+    color_eyre::install()?;
+
+    let arr = map::to_array::<i32, 3>();
+
+    assert_eq!(arr.map_to(vec![1, 2, 3])?, [1, 2, 3]);
+    assert!(matches!(
+        arr.map_to(vec![1, 2]),
+        Err(Error::LenConstraint {
+            expect: 3,
+            found: 2
+        })
+    ));
+    assert!(matches!(
+        arr.map_to(vec![1, 2, 3, 4]),
+        Err(Error::LenConstraint {
+            expect: 3,
+            found: 4
+        })
+    ));
+    assert_eq!(map::to_array::<u8, 0>().map_to(vec![])?, []);
+
+    let word = neu::ascii_alphabetic().repeat_one_more();
+    let triple = word.sep(",").map(map::to_array::<_, 3>());
+
+    assert_eq!(CharsCtx::new("a,bc,d").ctor(&triple)?, ["a", "bc", "d"]);
+    assert!(CharsCtx::new("a,bc,d,e").ctor(&triple).is_err());
+    Ok(())
+}
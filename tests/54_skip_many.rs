@@ -0,0 +1,64 @@
+use std::alloc::GlobalAlloc;
+use std::alloc::Layout;
+use std::alloc::System;
+use std::cell::Cell;
+
+use neure::prelude::*;
+
+thread_local! {
+    static ALLOCS: Cell<usize> = const { Cell::new(0) };
+}
+
+struct CountAlloc;
+
+unsafe impl GlobalAlloc for CountAlloc {
+    unsafe fn alloc(&self, layout: Layout) -> *mut u8 {
+        ALLOCS.with(|v| v.set(v.get() + 1));
+        System.alloc(layout)
+    }
+
+    unsafe fn dealloc(&self, ptr: *mut u8, layout: Layout) {
+        System.dealloc(ptr, layout)
+    }
+}
+
+#[global_allocator]
+static GLOBAL: CountAlloc = CountAlloc;
+
+fn allocs() -> usize {
+    ALLOCS.with(|v| v.get())
+}
+
+#[test]
+fn skip_many() {
+    assert!(skip_many_impl().is_ok());
+}
+
+fn skip_many_impl() -> color_eyre::Result<()> {
+    color_eyre::install()?;
+
+    let input = " \t\n".repeat(10000) + "end";
+    let sp = neu::whitespace().repeat_one();
+    let skip = sp.skip_many();
+    let mut ctx = CharsCtx::new(&input);
+    let before = allocs();
+
+    ctx.ctor(&skip)?;
+    assert_eq!(allocs(), before);
+    assert_eq!(ctx.offset(), 30000);
+    assert_eq!(ctx.ctor(&skip)?, ());
+    assert_eq!(ctx.offset(), 30000);
+
+    let skip1 = sp.skip_many1();
+    let mut ctx = CharsCtx::new(&input);
+
+    assert_eq!(ctx.try_mat(&skip1)?, Span::new(0, 30000));
+    assert!(ctx.ctor(&skip1).is_err());
+    assert_eq!(ctx.offset(), 30000);
+
+    let skip2 = sp.skip_many().at_least(2);
+
+    assert!(CharsCtx::new(" x").ctor(&skip2).is_err());
+    assert!(CharsCtx::new("  x").ctor(&skip2).is_ok());
+    Ok(())
+}
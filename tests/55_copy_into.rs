@@ -0,0 +1,43 @@
+use neure::err::Error;
+use neure::prelude::*;
+
+#[test]
+fn copy_into() {
+    assert!(copy_into_impl().is_ok());
+}
+
+fn copy_into_impl() -> color_eyre::Result<()> {
+    color_eyre::install()?;
+
+    let digits = neu::ascii_digit().repeat_one_more();
+
+    let mut buf = [0; 4];
+    let copy = re::copy_into(&mut buf, digits);
+    let mut ctx = BytesCtx::new(b"123456");
+
+    assert!(matches!(
+        ctx.ctor(&copy),
+        Err(Error::CopyInto { need: 6, remain: 4 })
+    ));
+    assert_eq!(ctx.offset(), 0);
+    assert!(copy.is_empty());
+
+    let mut buf = [0; 8];
+    let copy = re::copy_into(&mut buf, digits);
+    let mut ctx = BytesCtx::new(b"123456");
+
+    assert_eq!(ctx.ctor(&copy)?, 6);
+    assert_eq!(copy.len(), 6);
+    assert_eq!(copy.capacity(), 8);
+    assert_eq!(copy.into_written(), b"123456");
+    assert_eq!(&buf[6..], [0, 0]);
+
+    let mut buf = [0; 5];
+    let digits = neu::digit(10).repeat_one_more();
+    let copy = re::copy_into(&mut buf, digits);
+    let mut ctx = CharsCtx::new("12 34 56");
+
+    assert_eq!(ctx.try_mat(&copy.sep(" "))?, Span::new(0, 6));
+    assert_eq!(&buf, b"1234\0");
+    Ok(())
+}
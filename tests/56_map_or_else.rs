@@ -0,0 +1,35 @@
+use neure::prelude::*;
+
+#[test]
+fn map_or_else() {
+    assert!(map_or_else_impl().is_ok());
+}
+
+fn map_or_else_impl() -> color_eyre::Result<()> {
+    color_eyre::install()?;
+
+    let num = neu::digit(10).repeat_one_more().map(map::from_str::<u8>());
+    let num = num.map_or_else(Some, |_| None);
+
+    assert_eq!(CharsCtx::new("42").ctor(&num)?, Some(42));
+    assert_eq!(CharsCtx::new("x").ctor(&num)?, None);
+
+    let len = neu::ascii_alphabetic().repeat_one_more();
+    let len = len.map_or_else(|v: &str| v.len(), |e| e.to_string().len());
+    let mut ctx = CharsCtx::new("abc");
+
+    assert_eq!(ctx.ctor(&len)?, 3);
+    assert_eq!(ctx.offset(), 3);
+    assert!(ctx.ctor(&len)? > 0);
+    assert_eq!(ctx.offset(), 3);
+
+    let num = neu::digit(10).repeat_one_more().map(map::from_str::<u8>());
+    let num = num.map_or_else(|v| v as u32, |_| 1000);
+    let mut ctx = CharsCtx::new("256");
+
+    assert_eq!(ctx.ctor(&num)?, 1000);
+    assert_eq!(ctx.offset(), 0);
+    assert_eq!(ctx.try_mat(&num)?, Span::new(0, 3));
+    assert_eq!(CharsCtx::new("x").try_mat(&num)?, Span::new(0, 0));
+    Ok(())
+}
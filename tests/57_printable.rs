@@ -0,0 +1,41 @@
+use neure::neu::Neu;
+use neure::prelude::*;
+
+#[test]
+fn printable() {
+    assert!(printable_impl().is_ok());
+}
+
+fn printable_impl() -> color_eyre::Result<()> {
+    color_eyre::install()?;
+
+    let printable = neu::printable();
+    let visible = neu::visible();
+
+    assert!(printable.is_match(&' '));
+    assert!(!visible.is_match(&' '));
+    assert!(printable.is_match(&'a'));
+    assert!(visible.is_match(&'a'));
+    assert!(!printable.is_match(&'\n'));
+    assert!(!visible.is_match(&'\n'));
+    assert!(printable.is_match(&'中'));
+    assert!(visible.is_match(&'中'));
+    assert!(!printable.is_match(&'\u{3000}'));
+
+    assert!(printable.is_match(&b' '));
+    assert!(!visible.is_match(&b' '));
+    assert!(printable.is_match(&b'a'));
+    assert!(visible.is_match(&b'a'));
+    assert!(!printable.is_match(&b'\n'));
+    assert!(!visible.is_match(&b'\n'));
+    assert!(!printable.is_match(&0x7f));
+
+    let line = neu::printable().repeat_one_more();
+
+    assert_eq!(CharsCtx::new("hello world\r\n").ctor(&line)?, "hello world");
+
+    let line = neu::printable().repeat_one_more();
+
+    assert_eq!(BytesCtx::new(b"ab cd\x00").ctor(&line)?, b"ab cd");
+    Ok(())
+}
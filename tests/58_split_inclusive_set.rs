@@ -0,0 +1,32 @@
+use neure::map::MapSingle;
+use neure::prelude::*;
+
+#[test]
+fn split_inclusive_set() {
+    assert!(split_inclusive_set_impl().is_ok());
+}
+
+fn split_inclusive_set_impl() -> color_eyre::Result<()> {
+    color_eyre::install()?;
+
+    let split = map::split_inclusive_set(&['+', '-']);
+
+    assert_eq!(split.map_to("a+b-c")?, ["a", "+", "b", "-", "c"]);
+    assert_eq!(split.map_to("+a-")?, ["+", "a", "-"]);
+    assert_eq!(split.map_to("a+-b")?, ["a", "+", "-", "b"]);
+    assert_eq!(split.map_to("abc")?, ["abc"]);
+    assert!(split.map_to("")?.is_empty());
+    assert_eq!(split.delims(), ['+', '-']);
+
+    let expr = neu::ascii_alphanumeric()
+        .or('+')
+        .or('-')
+        .repeat_one_more()
+        .map(split);
+
+    assert_eq!(
+        CharsCtx::new("x1+y-z 1").ctor(&expr)?,
+        ["x1", "+", "y", "-", "z"]
+    );
+    Ok(())
+}
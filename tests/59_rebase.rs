@@ -0,0 +1,38 @@
+use neure::prelude::*;
+
+#[test]
+fn rebase() {
+    assert!(rebase_impl().is_ok());
+}
+
+fn rebase_impl() -> color_eyre::Result<()> {
+    color_eyre::install()?;
+
+    let str = "let arr = [foo, bar, baz];";
+    let block = neu::not(']').repeat_zero_more().quote("[", "]");
+    let inner = CharsCtx::new(str).ctor_span(&"let arr = ".then(block))?.1;
+
+    assert_eq!(inner, Span::new(11, 13));
+
+    let item = neu::ascii_alphabetic().repeat_one_more();
+    let items = item.rebase(inner.beg).sep(",".ws());
+    let mut ctx = CharsCtx::new(&str[inner.beg..inner.beg + inner.len]);
+    let spans = ctx.ctor_span(&items)?;
+
+    assert_eq!(
+        spans,
+        [Span::new(11, 3), Span::new(16, 3), Span::new(21, 3)]
+    );
+    assert_eq!(
+        spans
+            .iter()
+            .map(|v| &str[v.beg..v.beg + v.len])
+            .collect::<Vec<_>>(),
+        ["foo", "bar", "baz"]
+    );
+
+    let mut ctx = CharsCtx::new("foo");
+
+    assert_eq!(ctx.try_mat(&item.rebase(100))?, Span::new(0, 3));
+    Ok(())
+}
@@ -0,0 +1,28 @@
+use neure::map::MapSingle;
+use neure::prelude::*;
+
+#[test]
+fn to_csv_row() {
+    assert!(to_csv_row_impl().is_ok());
+}
+
+fn to_csv_row_impl() -> color_eyre::Result<()> {
+    color_eyre::install()?;
+
+    let row = map::to_csv_row();
+
+    assert_eq!(row.map_to(vec!["a", "b,c"])?, r#"a,"b,c""#);
+    assert_eq!(row.map_to(vec![r#"a "b" c"#, "d"])?, r#""a ""b"" c",d"#);
+    assert_eq!(row.map_to(vec!["line\nbreak", ""])?, "\"line\nbreak\",");
+    assert_eq!(row.map_to(vec!["x"])?, "x");
+    assert_eq!(row.map_to(vec![])?, "");
+
+    let field = neu::not(';').repeat_one_more();
+    let fields = field.sep(";").map(row);
+
+    assert_eq!(
+        CharsCtx::new("1;two, three;4").ctor(&fields)?,
+        r#"1,"two, three",4"#
+    );
+    Ok(())
+}
@@ -0,0 +1,35 @@
+use std::collections::HashSet;
+
+use neure::prelude::*;
+
+#[test]
+fn collect_counted() {
+    assert!(collect_counted_impl().is_ok());
+}
+
+fn collect_counted_impl() -> color_eyre::Result<()> {
+    color_eyre::install()?;
+
+    let word = neu::ascii_alphabetic().repeat_one_more();
+    let words = word.pad(";").collect_counted::<_, Vec<_>>();
+    let mut ctx = CharsCtx::new("a;bc;d;e");
+
+    assert_eq!(ctx.ctor(&words)?, (3, vec!["a", "bc", "d"]));
+    assert_eq!(ctx.offset(), 7);
+
+    // duplicated items are dropped by the set, but still counted
+    let words = word.pad(";").collect_counted::<_, HashSet<_>>();
+    let (cnt, set) = CharsCtx::new("x;y;x;x;").ctor(&words)?;
+
+    assert_eq!(cnt, 4);
+    assert_eq!(set.len(), 2);
+    assert!(set.contains("x") && set.contains("y"));
+
+    let words = word.pad(";").collect_counted::<_, Vec<_>>().at_least(2);
+    let mut ctx = CharsCtx::new("a;1");
+
+    assert!(ctx.ctor(&words).is_err());
+    assert_eq!(ctx.offset(), 0);
+    assert_eq!(CharsCtx::new("a;b;1").try_mat(&words)?, Span::new(0, 4));
+    Ok(())
+}
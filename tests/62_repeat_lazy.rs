@@ -0,0 +1,37 @@
+use neure::prelude::*;
+
+#[test]
+fn repeat_lazy() {
+    assert!(repeat_lazy_impl().is_ok());
+}
+
+fn repeat_lazy_impl() -> color_eyre::Result<()> {
+    color_eyre::install()?;
+
+    let any = neu::any().repeat_one();
+    let body = any.repeat_lazy(1.., ">");
+    let tag = "<".then(body);
+    let mut ctx = CharsCtx::new("<a><b>");
+
+    assert_eq!(ctx.ctor(&tag)?, ("<", vec!["a"]));
+    assert_eq!(ctx.offset(), 3);
+    assert_eq!(ctx.try_mat(&tag)?, Span::new(3, 3));
+
+    // greedy repeat consumes the whole input
+    let greedy = "<".then(any.repeat(1..)).then(">");
+
+    assert!(CharsCtx::new("<a><b>").try_mat(&greedy).is_err());
+
+    // the terminator is not checked before the minimum count
+    assert_eq!(CharsCtx::new("<>>").try_mat(&tag)?, Span::new(0, 3));
+
+    // the count exceeded the range
+    let tag = "<".then(any.repeat_lazy(1..3, ">"));
+
+    assert!(CharsCtx::new("<abc>").try_mat(&tag).is_err());
+    assert_eq!(CharsCtx::new("<ab>").try_mat(&tag)?, Span::new(0, 4));
+
+    // no terminator
+    assert!(CharsCtx::new("<abc").ctor(&tag).is_err());
+    Ok(())
+}
@@ -0,0 +1,40 @@
+use neure::err::Error;
+use neure::map::MapSingle;
+use neure::prelude::*;
+
+#[test]
+fn utf8_checked() {
+    assert!(utf8_checked_impl().is_ok());
+}
+
+fn utf8_checked_impl() -> color_eyre::Result<()> {
+    color_eyre::install()?;
+
+    let full = "中文abc".as_bytes();
+    let checked = map::utf8_checked(full);
+
+    assert_eq!(checked.map_to(&full[0..3])?, "中");
+    assert_eq!(checked.map_to(&full[3..7])?, "文a");
+    assert_eq!(checked.map_to(&full[6..])?, "abc");
+    assert_eq!(checked.map_to(&full[9..])?, "");
+    assert!(matches!(
+        checked.map_to(&full[0..4]),
+        Err(Error::NotCharBoundary { at: 4 })
+    ));
+    assert!(matches!(
+        checked.map_to(&full[1..6]),
+        Err(Error::NotCharBoundary { at: 1 })
+    ));
+    assert!(matches!(
+        checked.map_to("abc".as_bytes()),
+        Err(Error::OriginOutOfBound)
+    ));
+
+    let four = re::consume(4).map(checked);
+    let mut ctx = BytesCtx::new(full);
+
+    assert!(ctx.ctor(&four).is_err());
+    ctx.set_offset(3);
+    assert_eq!(ctx.ctor(&four)?, "文a");
+    Ok(())
+}
@@ -0,0 +1,31 @@
+use std::cell::Cell;
+
+use neure::prelude::*;
+
+#[test]
+fn on_success() {
+    assert!(on_success_impl().is_ok());
+}
+
+fn on_success_impl() -> color_eyre::Result<()> {
+    color_eyre::install()?;
+
+    let cnt = Cell::new(0usize);
+    let int = neu::digit(10).repeat_one_more().map(map::from_str::<i64>());
+    let int = int.on_success(|_: &i64| cnt.set(cnt.get() + 1));
+    let list = int.sep(",".ws()).quote("[", "]");
+
+    assert_eq!(CharsCtx::new("[1, 22, 333]").ctor(&list)?, [1, 22, 333]);
+    assert_eq!(cnt.get(), 3);
+
+    // not called on failure
+    cnt.set(0);
+    assert!(CharsCtx::new("[a]").ctor(&list).is_err());
+    assert_eq!(cnt.get(), 0);
+
+    // not called in regex
+    cnt.set(0);
+    assert_eq!(CharsCtx::new("[1, 22]").try_mat(&list)?, Span::new(0, 7));
+    assert_eq!(cnt.get(), 0);
+    Ok(())
+}
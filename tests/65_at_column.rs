@@ -0,0 +1,58 @@
+use neure::err::Error;
+use neure::prelude::*;
+
+#[test]
+fn at_column() {
+    assert!(at_column_impl().is_ok());
+}
+
+fn at_column_impl() -> color_eyre::Result<()> {
+    color_eyre::install()?;
+
+    let col = re::at_column(5);
+    let str = "header\n    value\n";
+    let mut ctx = CharsCtx::new(str);
+
+    ctx.set_offset(11);
+    assert_eq!(ctx.try_mat(&col)?, Span::new(11, 0));
+    assert_eq!(ctx.offset(), 11);
+    ctx.set_offset(12);
+    assert!(matches!(
+        ctx.try_mat(&col),
+        Err(Error::AtColumn {
+            expect: 5,
+            found: 6
+        })
+    ));
+
+    // first line has no newline before it
+    ctx.set_offset(4);
+    assert_eq!(ctx.try_mat(&col)?, Span::new(4, 0));
+
+    let value = col.then(neu::ascii_alphabetic().repeat_one_more())._1();
+    let mut ctx = BytesCtx::new(str.as_bytes());
+
+    ctx.set_offset(11);
+    assert_eq!(ctx.ctor(&value)?, b"value");
+
+    let mut ctx = BytesCtx::new(b"\n     value");
+
+    ctx.set_offset(6);
+    assert!(ctx.ctor(&value).is_err());
+
+    // the column is counted in chars, same as line_col
+    let mut ctx = CharsCtx::new("ab\n变量cd");
+
+    for (offset, col) in [(0, 1), (3, 1), (6, 2), (9, 3), (10, 4), (11, 5)] {
+        ctx.set_offset(offset);
+        assert_eq!(ctx.try_mat(&re::at_column(col))?, Span::new(offset, 0));
+        assert_eq!(ctx.line_col(offset).1, col);
+    }
+
+    // count in bytes for BytesCtx
+    let mut ctx = BytesCtx::new("ab\n变量cd".as_bytes());
+
+    ctx.set_offset(9);
+    assert_eq!(ctx.try_mat(&re::at_column(7))?, Span::new(9, 0));
+    Ok(())
+}
@@ -0,0 +1,54 @@
+use neure::prelude::*;
+
+#[test]
+fn word_boundary() {
+    assert!(word_boundary_impl().is_ok());
+}
+
+fn word_boundary_impl() -> color_eyre::Result<()> {
+    color_eyre::install()?;
+
+    let b = re::word_boundary();
+    let nb = re::non_word_boundary();
+    let str = "变量 x_1";
+    let mut ctx = CharsCtx::new(str);
+    let mut check = |offset: usize, is_boundary: bool| {
+        ctx.set_offset(offset);
+        assert_eq!(ctx.try_mat(&b).is_ok(), is_boundary, "offset {offset}");
+        assert_eq!(ctx.try_mat(&nb).is_ok(), !is_boundary, "offset {offset}");
+        assert_eq!(ctx.offset(), offset);
+    };
+
+    // the begin and end of data
+    check(0, true);
+    check(str.len(), true);
+    // previous char is multi-byte
+    check(3, false);
+    check(6, true);
+    check(7, true);
+    check(8, false);
+
+    let mut ctx = CharsCtx::new("");
+
+    assert!(ctx.try_mat(&b).is_err());
+    assert_eq!(ctx.try_mat(&nb)?, Span::new(0, 0));
+
+    let str = b"ab cd";
+    let mut ctx = BytesCtx::new(str);
+
+    assert_eq!(ctx.try_mat(&b)?, Span::new(0, 0));
+    ctx.set_offset(2);
+    assert_eq!(ctx.try_mat(&b)?, Span::new(2, 0));
+    ctx.set_offset(4);
+    assert_eq!(ctx.try_mat(&nb)?, Span::new(4, 0));
+    ctx.set_offset(5);
+    assert_eq!(ctx.try_mat(&b)?, Span::new(5, 0));
+
+    let word = b.then(neu::word().repeat_one_more())._1();
+    let mut ctx = CharsCtx::new("foo_bar baz");
+
+    assert_eq!(ctx.ctor(&word)?, "foo_bar");
+    ctx.set_offset(4);
+    assert!(ctx.ctor(&word).is_err());
+    Ok(())
+}
@@ -0,0 +1,42 @@
+use neure::err::Error;
+use neure::map::MapSingle;
+use neure::prelude::*;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum Op {
+    Add,
+    Sub,
+    Mul,
+    Div,
+}
+
+#[test]
+fn one_of_enum() {
+    assert!(one_of_enum_impl().is_ok());
+}
+
+fn one_of_enum_impl() -> color_eyre::Result<()> {
+    color_eyre::install()?;
+
+    let table = map::one_of_enum([
+        ("sub", Op::Sub),
+        ("add", Op::Add),
+        ("div", Op::Div),
+        ("mul", Op::Mul),
+    ]);
+
+    assert_eq!(table.map_to("add")?, Op::Add);
+    assert_eq!(table.map_to("div")?, Op::Div);
+    assert!(matches!(table.map_to("mod"), Err(Error::Lookup)));
+    assert!(matches!(table.map_to(""), Err(Error::Lookup)));
+
+    let op = neu::ascii_alphabetic().repeat_one_more().map(table);
+    let ops = op.clone().sep(",".ws());
+
+    assert_eq!(
+        CharsCtx::new("add, sub, mul, div").ctor(&ops)?,
+        [Op::Add, Op::Sub, Op::Mul, Op::Div]
+    );
+    assert!(CharsCtx::new("ADD").ctor(&op).is_err());
+    Ok(())
+}
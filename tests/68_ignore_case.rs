@@ -0,0 +1,42 @@
+use neure::prelude::*;
+
+#[test]
+fn ignore_case() {
+    assert!(ignore_case_impl().is_ok());
+}
+
+fn ignore_case_impl() -> color_eyre::Result<()> {
+    color_eyre::install()?;
+
+    let get = re::string_ignore_case("GET");
+
+    for str in ["get", "GET", "GeT"] {
+        let mut ctx = CharsCtx::new(str);
+
+        assert_eq!(ctx.try_mat(&get)?, Span::new(0, 3));
+        assert_eq!(ctx.offset(), 3);
+    }
+    assert!(CharsCtx::new("GE").try_mat(&get).is_err());
+    assert!(CharsCtx::new("GEX").try_mat(&get).is_err());
+    // multi-byte char never match the ASCII char
+    assert!(CharsCtx::new("GÉT").try_mat(&get).is_err());
+    assert!(CharsCtx::new("G变T").try_mat(&get).is_err());
+
+    let cafe = re::string_ignore_case("café");
+
+    assert_eq!(CharsCtx::new("CAFé!").try_mat(&cafe)?, Span::new(0, 5));
+    assert!(CharsCtx::new("CAFÉ!").try_mat(&cafe).is_err());
+
+    let method = get.map(|str: &str| Ok(str.to_ascii_uppercase()));
+
+    assert_eq!(CharsCtx::new("gEt /").ctor(&method)?, "GET");
+
+    let get = re::lit_slice_ignore_case(b"GET");
+
+    for bytes in [b"get", b"GET", b"GeT"] {
+        assert_eq!(BytesCtx::new(bytes).try_mat(&get)?, Span::new(0, 3));
+    }
+    assert!(BytesCtx::new(b"GEX").try_mat(&get).is_err());
+    assert!(BytesCtx::new(&[b'G', 0xC3, 0x89]).try_mat(&get).is_err());
+    Ok(())
+}
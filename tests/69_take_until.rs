@@ -0,0 +1,47 @@
+use neure::err::Error;
+use neure::prelude::*;
+
+#[test]
+fn take_until() {
+    assert!(take_until_impl().is_ok());
+}
+
+fn take_until_impl() -> color_eyre::Result<()> {
+    color_eyre::install()?;
+
+    let level = re::take_until(": ");
+    let mut ctx = CharsCtx::new("WARN: disk full");
+
+    assert_eq!(ctx.ctor(&level)?, "WARN");
+    assert_eq!(ctx.offset(), 4);
+    // the pattern matches at the start
+    assert_eq!(ctx.try_mat(&level)?, Span::new(4, 0));
+
+    // offset restored on failure
+    let mut ctx = CharsCtx::new("no delimiter");
+
+    ctx.set_offset(3);
+    assert!(matches!(ctx.try_mat(&level), Err(Error::TakeUntil)));
+    assert_eq!(ctx.offset(), 3);
+
+    // pattern can match at the end of data
+    let line = re::take_until("\n".or(re::end()));
+    let mut ctx = CharsCtx::new("第一\n第二");
+
+    assert_eq!(ctx.ctor(&line)?, "第一");
+    assert_eq!(ctx.inc(1).ctor(&line)?, "第二");
+
+    let stmt = re::take_until_inclusive(";");
+    let stmts = stmt.ws().repeat(1..);
+    let mut ctx = CharsCtx::new("a = 1; b = 2;");
+
+    assert_eq!(ctx.ctor(&stmts)?, ["a = 1;", "b = 2;"]);
+
+    let header = re::take_until_inclusive(b"\r\n");
+    let mut ctx = BytesCtx::new(b"Host: x\r\nbody");
+
+    assert_eq!(ctx.ctor(&header)?, b"Host: x\r\n");
+    assert!(ctx.try_mat(&header).is_err());
+    assert_eq!(ctx.offset(), 9);
+    Ok(())
+}
@@ -0,0 +1,50 @@
+use neure::prelude::*;
+
+#[test]
+fn line_nav() {
+    assert!(line_nav_impl().is_ok());
+}
+
+fn line_nav_impl() -> color_eyre::Result<()> {
+    color_eyre::install()?;
+
+    let str = "首行\nsecond line\nlast";
+    let mut ctx = CharsCtx::new(str);
+
+    // first line
+    ctx.set_offset(3);
+    assert_eq!(ctx.to_line_start().offset(), 0);
+    assert_eq!(ctx.to_line_end().offset(), 6);
+    // stay at the `\n`
+    assert_eq!(ctx.to_line_end().offset(), 6);
+    // `\n` belongs to the line before it
+    assert_eq!(ctx.to_line_start().offset(), 0);
+
+    // middle line
+    ctx.set_offset(10);
+    assert_eq!(ctx.to_line_start().offset(), 7);
+    assert_eq!(ctx.to_line_start().offset(), 7);
+    assert_eq!(ctx.to_line_end().offset(), 18);
+
+    // last line
+    ctx.set_offset(21);
+    assert_eq!(ctx.to_line_start().offset(), 19);
+    assert_eq!(ctx.to_line_end().offset(), str.len());
+    assert_eq!(ctx.to_line_start().offset(), 19);
+
+    // resynchronize at the next line
+    let stmt = neu::ascii_alphabetic().repeat_one_more();
+    let mut ctx = CharsCtx::new("ok\n1bad\nfine");
+
+    assert_eq!(ctx.ctor(&stmt)?, "ok");
+    ctx.inc(1);
+    assert!(ctx.ctor(&stmt).is_err());
+    ctx.to_line_end().inc(1);
+    assert_eq!(ctx.ctor(&stmt)?, "fine");
+
+    let mut ctx = CharsCtx::new("");
+
+    assert_eq!(ctx.to_line_start().offset(), 0);
+    assert_eq!(ctx.to_line_end().offset(), 0);
+    Ok(())
+}
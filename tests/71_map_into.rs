@@ -0,0 +1,43 @@
+use neure::prelude::*;
+
+#[derive(Debug, PartialEq, Eq)]
+struct Ident(String);
+
+impl From<&str> for Ident {
+    fn from(value: &str) -> Self {
+        Self(value.to_owned())
+    }
+}
+
+#[test]
+fn map_into() {
+    assert!(map_into_impl().is_ok());
+}
+
+fn map_into_impl() -> color_eyre::Result<()> {
+    color_eyre::install()?;
+
+    let ident = neu::ascii_alphabetic().repeat_one_more().map_into();
+    let ident: Ident = CharsCtx::new("foo bar").ctor(&ident)?;
+
+    assert_eq!(ident, Ident("foo".to_owned()));
+
+    let ident = neu::ascii_alphabetic()
+        .repeat_one_more()
+        .map_into::<_, Ident>();
+    let idents = ident.sep(" ");
+
+    assert_eq!(
+        CharsCtx::new("foo bar").ctor(&idents)?,
+        [Ident("foo".to_owned()), Ident("bar".to_owned())]
+    );
+
+    let num = neu::digit(10)
+        .repeat_times::<2>()
+        .map(map::from_str::<u8>());
+    let num = num.map_into::<_, u32>();
+
+    assert_eq!(CharsCtx::new("42").ctor(&num)?, 42u32);
+    assert!(CharsCtx::new("x").ctor(&num).is_err());
+    Ok(())
+}
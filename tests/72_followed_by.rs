@@ -0,0 +1,46 @@
+use neure::err::Error;
+use neure::prelude::*;
+
+#[test]
+fn followed_by() {
+    assert!(followed_by_impl().is_ok());
+}
+
+fn followed_by_impl() -> color_eyre::Result<()> {
+    color_eyre::install()?;
+
+    let a = re::one('a').then(re::followed_by(re::one('b')))._0();
+    let mut ctx = CharsCtx::new("ab");
+
+    assert_eq!(ctx.ctor(&a)?, "a");
+    assert_eq!(ctx.offset(), 1);
+
+    let mut ctx = CharsCtx::new("ac");
+
+    assert!(ctx.ctor(&a).is_err());
+    assert_eq!(ctx.offset(), 0);
+
+    let la = re::followed_by("ab");
+    let mut ctx = CharsCtx::new("xab");
+
+    assert!(matches!(ctx.try_mat(&la), Err(Error::FollowedBy)));
+    assert_eq!(ctx.offset(), 0);
+    ctx.set_offset(1);
+    assert_eq!(ctx.try_mat(&la)?, Span::new(1, 0));
+    assert_eq!(ctx.offset(), 1);
+
+    let nla = re::not_followed_by("ab");
+
+    assert!(matches!(ctx.try_mat(&nla), Err(Error::NotFollowedBy)));
+    assert_eq!(ctx.offset(), 1);
+    ctx.set_offset(0);
+    assert_eq!(ctx.try_mat(&nla)?, Span::new(0, 0));
+    assert_eq!(ctx.offset(), 0);
+
+    let a = re::one('a').then(re::not_followed_by(re::one('b')))._0();
+
+    assert!(CharsCtx::new("ab").ctor(&a).is_err());
+    assert_eq!(CharsCtx::new("ac").ctor(&a)?, "a");
+    assert_eq!(CharsCtx::new("a").ctor(&a)?, "a");
+    Ok(())
+}
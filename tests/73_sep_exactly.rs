@@ -0,0 +1,49 @@
+use neure::err::Error;
+use neure::prelude::*;
+
+#[test]
+fn sep_exactly() {
+    assert!(sep_exactly_impl().is_ok());
+}
+
+fn sep_exactly_impl() -> color_eyre::Result<()> {
+    color_eyre::install()?;
+
+    let num = neu::digit(10).repeat_one_more().map(map::from_str::<u8>());
+    let rgba = num.sep_exactly::<4, _>(",");
+    let mut ctx = CharsCtx::new("1,2,3,4");
+
+    assert_eq!(ctx.ctor(&rgba)?, [1, 2, 3, 4]);
+    assert_eq!(ctx.offset(), 7);
+
+    for str in ["1,2,3", "1,2,3,4,5", "1,2,3,4,", "1,2,,3,4", ""] {
+        let mut ctx = CharsCtx::new(str);
+
+        assert!(matches!(ctx.ctor(&rgba), Err(Error::SepExactly)), "{str}");
+        assert_eq!(ctx.offset(), 0);
+    }
+
+    // the data after the elements is not a delimiter
+    let mut ctx = CharsCtx::new("1,2,3,4;");
+
+    assert_eq!(ctx.ctor(&rgba)?, [1, 2, 3, 4]);
+    assert_eq!(ctx.offset(), 7);
+
+    let rgba = rgba.quote("rgba(", ")");
+    let mut ctx = CharsCtx::new("rgba(255,0,128,1)");
+
+    assert_eq!(ctx.ctor(&rgba)?, [255, 0, 128, 1]);
+    assert_eq!(
+        CharsCtx::new("rgba(255,0,128,1)").try_mat(&rgba)?,
+        Span::new(0, 17)
+    );
+    assert!(CharsCtx::new("rgba(255,0,128)").try_mat(&rgba).is_err());
+
+    let pair = neu::ascii_alphabetic()
+        .repeat_one_more()
+        .sep_exactly(" ".ws());
+    let [key, value] = CharsCtx::new("key  value").ctor(&pair)?;
+
+    assert_eq!((key, value), ("key", "value"));
+    Ok(())
+}
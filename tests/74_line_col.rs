@@ -0,0 +1,34 @@
+use neure::prelude::*;
+
+#[test]
+fn line_col() {
+    assert!(line_col_impl().is_ok());
+}
+
+fn line_col_impl() -> color_eyre::Result<()> {
+    color_eyre::install()?;
+
+    let str = "fn 主() {\n    x\n}";
+    let ctx = CharsCtx::new(str);
+
+    assert_eq!(ctx.line_col(0), (1, 1));
+    assert_eq!(ctx.line_col(3), (1, 4));
+    // after the multi-byte char
+    assert_eq!(ctx.line_col(6), (1, 5));
+    // on the `\n`
+    assert_eq!(ctx.line_col(10), (1, 9));
+    assert_eq!(ctx.line_col(11), (2, 1));
+    assert_eq!(ctx.line_col(15), (2, 5));
+    assert_eq!(ctx.line_col(str.len()), (3, 2));
+
+    let ident = neu::ascii_alphabetic().repeat_one_more();
+    let mut ctx = CharsCtx::new(str);
+
+    ctx.set_offset(15);
+    let span = ctx.try_mat(&ident)?;
+
+    assert_eq!(ctx.span_line_col(&span), ((2, 5), (2, 6)));
+    assert_eq!(ctx.span_line_col(&Span::new(3, 12)), ((1, 4), (2, 5)));
+    assert_eq!(CharsCtx::new("").line_col(0), (1, 1));
+    Ok(())
+}
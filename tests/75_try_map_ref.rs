@@ -0,0 +1,53 @@
+use neure::err::Error;
+use neure::map::MapRef;
+use neure::map::MapSingle;
+use neure::prelude::*;
+
+#[derive(Debug, PartialEq, Eq)]
+enum Value {
+    Int(i64),
+    Raw(String),
+}
+
+#[test]
+fn try_map_ref() {
+    assert!(try_map_ref_impl().is_ok());
+}
+
+fn try_map_ref_impl() -> color_eyre::Result<()> {
+    color_eyre::install()?;
+
+    let validate = |v: &&str| {
+        str::parse::<i64>(v)
+            .map(Value::Int)
+            .map_err(|_| Error::FromStr)
+    };
+
+    assert_eq!(validate.map_ref(&"42")?, Value::Int(42));
+
+    let value = map::try_map_ref(validate, |v: &str, e| match e {
+        Error::FromStr => Ok(Value::Raw(v.to_owned())),
+        e => Err(e),
+    });
+
+    assert_eq!(value.map_to("-7")?, Value::Int(-7));
+    assert_eq!(value.map_to("0x1f")?, Value::Raw("0x1f".to_owned()));
+
+    let field = neu::word().repeat_one_more().map(value);
+    let fields = field.sep(",");
+
+    assert_eq!(
+        CharsCtx::new("1,a_b,99999999999999999999").ctor(&fields)?,
+        [
+            Value::Int(1),
+            Value::Raw("a_b".to_owned()),
+            Value::Raw("99999999999999999999".to_owned())
+        ]
+    );
+
+    // the error of fallback is returned
+    let strict = map::try_map_ref(validate, |_: &str, _| Err(Error::Other));
+
+    assert!(matches!(strict.map_to("x"), Err(Error::Other)));
+    Ok(())
+}
@@ -0,0 +1,45 @@
+use neure::err::Error;
+use neure::prelude::*;
+
+#[test]
+fn error_offset() {
+    assert!(error_offset_impl().is_ok());
+}
+
+fn error_offset_impl() -> color_eyre::Result<()> {
+    color_eyre::install()?;
+
+    let ident = neu::ascii_alphabetic().repeat_one_more();
+    let decl = re::string("let").ws().then(ident);
+    let mut ctx = CharsCtx::new("let 123");
+
+    let err = ctx.ctor(&decl).unwrap_err();
+
+    assert!(matches!(err, Error::NeuOneMore { at: 4 }));
+    assert_eq!(err.offset(), Some(4));
+    assert_eq!(ctx.offset(), 0);
+
+    let err = CharsCtx::new("var x").ctor(&decl).unwrap_err();
+
+    assert!(matches!(err, Error::String { at: 0 }));
+    assert_eq!(err.offset(), Some(0));
+
+    let kw = re::keyword("in");
+    let mut ctx = CharsCtx::new("x int");
+
+    ctx.set_offset(2);
+    assert_eq!(ctx.try_mat(&kw).unwrap_err().offset(), Some(2));
+
+    let head = re::lit_slice(b"\x7fELF");
+    let digit = neu::ascii_digit().repeat_times::<2>();
+    let mut ctx = BytesCtx::new(b"\x7fELX1");
+
+    assert_eq!(ctx.try_mat(&head).unwrap_err().offset(), Some(0));
+    ctx.set_offset(4);
+    assert!(matches!(
+        ctx.try_mat(&digit),
+        Err(Error::NeuRepeat { at: 4 })
+    ));
+    assert_eq!(Error::Option.offset(), None);
+    Ok(())
+}
@@ -0,0 +1,36 @@
+use neure::prelude::*;
+
+#[test]
+fn ws_list() {
+    assert!(ws_list_impl().is_ok());
+}
+
+fn ws_list_impl() -> color_eyre::Result<()> {
+    color_eyre::install()?;
+
+    let num = neu::digit(10).repeat_one_more().map(map::from_str::<i32>());
+    let array = num.ws_list(",", "[", "]");
+
+    assert_eq!(CharsCtx::new("[1,2,3]").ctor(&array)?, [1, 2, 3]);
+    assert_eq!(CharsCtx::new("  [ 1 , 2 , 3 ]  ").ctor(&array)?, [1, 2, 3]);
+    assert_eq!(
+        CharsCtx::new("[\n\t1,\n\t2,\n\t3,\n]").ctor(&array)?,
+        [1, 2, 3]
+    );
+    assert_eq!(CharsCtx::new("[ ]").ctor(&array)?, []);
+
+    assert!(CharsCtx::new("[ 1 , 2 ; 3 ]").ctor(&array).is_err());
+    assert!(CharsCtx::new("[ 1 , , 3 ]").ctor(&array).is_err());
+
+    let mut ctx = CharsCtx::new("[ 1 , 2 ] tail");
+
+    assert_eq!(ctx.try_mat(&array)?, Span::new(0, 10));
+
+    let matrix = num.ws_list(",", "[", "]").ws_list(",", "[", "]");
+
+    assert_eq!(
+        CharsCtx::new("[ [1, 2], [ 3 ,4 ] ]").ctor(&matrix)?,
+        [[1, 2], [3, 4]]
+    );
+    Ok(())
+}
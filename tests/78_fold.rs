@@ -0,0 +1,41 @@
+use neure::err::Error;
+use neure::prelude::*;
+
+#[test]
+fn fold() {
+    assert!(fold_impl().is_ok());
+}
+
+fn fold_impl() -> color_eyre::Result<()> {
+    color_eyre::install()?;
+
+    let digit = neu::digit(10).repeat_one().map(map::from_str::<u32>());
+    let sum = digit.fold("+", || 0, |acc, v| acc + v);
+    let mut ctx = CharsCtx::new("1+2+3");
+
+    assert_eq!(ctx.ctor(&sum)?, 6);
+    assert_eq!(ctx.offset(), 5);
+    assert_eq!(CharsCtx::new("7").ctor(&sum)?, 7);
+    assert_eq!(CharsCtx::new("1+2+3").try_mat(&sum)?, Span::new(0, 5));
+
+    let mut ctx = CharsCtx::new("+1");
+
+    assert!(matches!(ctx.ctor(&sum), Err(Error::Fold)));
+    assert_eq!(ctx.offset(), 0);
+
+    // the last element without delimiter is dropped if skip is false
+    let strict = digit.fold(";", || 0, |acc, v| acc + v).with_skip(false);
+
+    assert_eq!(CharsCtx::new("1;2;3").ctor(&strict)?, 3);
+    assert_eq!(CharsCtx::new("1;2;3;").ctor(&strict)?, 6);
+
+    let empty = digit.fold(",", Vec::new, |mut acc: Vec<u32>, v| {
+        acc.push(v);
+        acc
+    });
+    let empty = empty.at_least(0);
+
+    assert_eq!(CharsCtx::new("x").ctor(&empty)?, Vec::<u32>::new());
+    assert_eq!(CharsCtx::new("9,8").ctor(&empty)?, [9, 8]);
+    Ok(())
+}
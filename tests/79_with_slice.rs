@@ -0,0 +1,30 @@
+use neure::prelude::*;
+
+#[test]
+fn with_slice() {
+    assert!(with_slice_impl().is_ok());
+}
+
+fn with_slice_impl() -> color_eyre::Result<()> {
+    color_eyre::install()?;
+
+    let int = neu::digit(10).repeat_one_more().map(map::from_str::<i64>());
+    let pair = int.sep_once(",".ws(), int).with_slice();
+    let mut ctx = CharsCtx::new("12, 345;");
+
+    assert_eq!(ctx.ctor(&pair)?, ((12, 345), "12, 345"));
+    assert_eq!(ctx.offset(), 7);
+
+    let mut ctx = CharsCtx::new("12;");
+
+    assert!(ctx.ctor(&pair).is_err());
+    assert_eq!(ctx.offset(), 0);
+    assert_eq!(CharsCtx::new("1,2").try_mat(&pair)?, Span::new(0, 3));
+
+    let magic = re::lit_slice(b"\x89PNG").map(|v: &[u8]| Ok(v.len()));
+    let magic = magic.with_slice();
+    let mut ctx = BytesCtx::new(b"\x89PNG\r\n");
+
+    assert_eq!(ctx.ctor(&magic)?, (4, &b"\x89PNG"[..]));
+    Ok(())
+}
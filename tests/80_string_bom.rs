@@ -0,0 +1,41 @@
+use neure::prelude::*;
+
+#[test]
+fn string_bom() {
+    assert!(string_bom_impl().is_ok());
+}
+
+fn string_bom_impl() -> color_eyre::Result<()> {
+    color_eyre::install()?;
+
+    let hello = re::string_bom("hello");
+    let mut ctx = CharsCtx::new("\u{FEFF}hello world");
+
+    assert_eq!(ctx.try_mat(&hello)?, Span::new(3, 5));
+    assert_eq!(ctx.offset(), 8);
+
+    let mut ctx = CharsCtx::new("hello world");
+
+    assert_eq!(ctx.try_mat(&hello)?, Span::new(0, 5));
+    assert_eq!(ctx.offset(), 5);
+
+    // only skipped at the start of data
+    let mut ctx = CharsCtx::new(" \u{FEFF}hello");
+
+    ctx.set_offset(1);
+    assert!(ctx.try_mat(&hello).is_err());
+    assert_eq!(ctx.offset(), 1);
+
+    // the plain string never skip the BOM
+    assert!(CharsCtx::new("\u{FEFF}hello")
+        .try_mat(&re::string("hello"))
+        .is_err());
+
+    let greet = hello.ws().then(neu::ascii_alphabetic().repeat_one_more());
+
+    assert_eq!(
+        CharsCtx::new("\u{FEFF}hello world").ctor(&greet)?,
+        ("hello", "world")
+    );
+    Ok(())
+}
@@ -0,0 +1,41 @@
+use neure::prelude::*;
+
+#[test]
+fn round_trip() {
+    assert!(round_trip_impl().is_ok());
+}
+
+fn round_trip_impl() -> color_eyre::Result<()> {
+    color_eyre::install()?;
+
+    let num = neu::digit(10).repeat_one_more().map(map::from_str::<u32>());
+    let list = num.sep(",").quote("[", "]");
+    let list = list
+        .with_slice()
+        .map(map::assert_round_trip(|v: &Vec<u32>| {
+            let items: Vec<_> = v.iter().map(u32::to_string).collect();
+
+            format!("[{}]", items.join(","))
+        }));
+
+    assert_eq!(CharsCtx::new("[1,22,333]").ctor(&list)?, [1, 22, 333]);
+
+    let word = re::lit_slice(b"ping").with_slice();
+    let word = word.map(map::assert_round_trip(|v: &&[u8]| v.to_vec()));
+
+    assert_eq!(BytesCtx::new(b"ping").ctor(&word)?, b"ping");
+    Ok(())
+}
+
+#[cfg(debug_assertions)]
+#[test]
+#[should_panic(expected = "the rendered value is not equal to the matched data")]
+fn round_trip_buggy_render() {
+    let num = neu::digit(10).repeat_one_more().map(map::from_str::<u32>());
+    // the leading zeros are lost after rendering
+    let num = num
+        .with_slice()
+        .map(map::assert_round_trip(|v: &u32| v.to_string()));
+
+    let _ = CharsCtx::new("007").ctor(&num);
+}
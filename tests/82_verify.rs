@@ -0,0 +1,27 @@
+use neure::err::Error;
+use neure::prelude::*;
+
+#[test]
+fn verify() {
+    assert!(verify_impl().is_ok());
+}
+
+fn verify_impl() -> color_eyre::Result<()> {
+    color_eyre::install()?;
+
+    let port = neu::digit(10).repeat_one_more().map(map::from_str::<u32>());
+    let port = port.verify(|v: &u32| *v <= 65535);
+
+    assert_eq!(CharsCtx::new("8080").ctor(&port)?, 8080);
+
+    let mut ctx = CharsCtx::new("99999");
+
+    assert!(matches!(ctx.ctor(&port), Err(Error::Verify)));
+    assert_eq!(ctx.offset(), 0);
+
+    let addr = "localhost".then(":")._0().then(port)._1();
+
+    assert_eq!(CharsCtx::new("localhost:443").ctor(&addr)?, 443);
+    assert!(CharsCtx::new("localhost:65536").ctor(&addr).is_err());
+    Ok(())
+}
@@ -0,0 +1,32 @@
+use neure::prelude::*;
+
+#[test]
+fn sep_spanned() {
+    assert!(sep_spanned_impl().is_ok());
+}
+
+fn sep_spanned_impl() -> color_eyre::Result<()> {
+    color_eyre::install()?;
+
+    let str = "10,20,30";
+    let num = neu::digit(10).repeat_one_more().map(map::from_str::<u32>());
+    let nums = num.sep_spanned(",");
+    let mut ctx = CharsCtx::new(str);
+    let vals = ctx.ctor(&nums)?;
+
+    assert_eq!(
+        vals,
+        [
+            (Span::new(0, 2), 10),
+            (Span::new(3, 2), 20),
+            (Span::new(6, 2), 30)
+        ]
+    );
+    for (span, val) in vals {
+        assert_eq!(ctx.orig_sub(span.beg, span.len)?, val.to_string());
+    }
+    assert_eq!(ctx.offset(), str.len());
+    assert!(CharsCtx::new("").ctor(&nums).is_err());
+    assert_eq!(CharsCtx::new("10,20,30").try_mat(&nums)?, Span::new(0, 8));
+    Ok(())
+}
@@ -0,0 +1,70 @@
+use std::io::Read;
+
+use neure::ctx::ReadCtx;
+use neure::err::Error;
+use neure::prelude::*;
+
+/// A reader return at most 3 bytes every time.
+struct Slow<'a>(&'a [u8]);
+
+impl Read for Slow<'_> {
+    fn read(&mut self, buf: &mut [u8]) -> std::io::Result<usize> {
+        let len = buf.len().min(self.0.len()).min(3);
+
+        buf[..len].copy_from_slice(&self.0[..len]);
+        self.0 = &self.0[len..];
+        Ok(len)
+    }
+}
+
+fn line<R: Read>(ctx: &mut ReadCtx<R>) -> Result<String, Error> {
+    ctx.with_ctx(|ctx| {
+        let line = neu::not(b'\n').repeat_zero_more().then(b"\n")._0();
+        let line = line.map(map::from_utf8::<String>());
+
+        ctx.ctor(&line)
+    })
+}
+
+#[test]
+fn read_ctx() {
+    assert!(read_ctx_impl().is_ok());
+}
+
+fn read_ctx_impl() -> color_eyre::Result<()> {
+    color_eyre::install()?;
+
+    let mut ctx = ReadCtx::new(Slow(b"first line\nsecond\n\nlast one\n")).with_lookahead(16);
+
+    assert_eq!(line(&mut ctx)?, "first line");
+    assert_eq!(ctx.offset(), 11);
+    assert_eq!(ctx.try_mat(&b"second\n")?, Span::new(11, 7));
+    assert_eq!(line(&mut ctx)?, "");
+    assert!(ctx.try_mat(&b"first").is_err());
+    assert_eq!(ctx.offset(), 19);
+    assert_eq!(line(&mut ctx)?, "last one");
+    assert!(ctx.is_eof());
+    assert!(matches!(line(&mut ctx), Err(Error::NeedMore)));
+
+    let data = (0..1000)
+        .map(|v| v.to_string())
+        .collect::<Vec<_>>()
+        .join(",");
+    let mut ctx = ReadCtx::new(Slow(data.as_bytes())).with_lookahead(2);
+    let mut count = 0;
+    let nums = ctx.with_ctx(|ctx| {
+        count += 1;
+        let num = neu::ascii_digit().repeat_one_more();
+        let num = num
+            .map(map::from_utf8::<String>())
+            .map(map::from_str::<u64>());
+
+        ctx.ctor(&num.sep(b","))
+    })?;
+
+    assert_eq!(nums, (0..1000).collect::<Vec<_>>());
+    // the window is grown geometrically, the pattern is not matched once per lookahead
+    assert!(count < 20);
+    assert_eq!(ctx.offset(), data.len());
+    Ok(())
+}
@@ -0,0 +1,31 @@
+use neure::prelude::*;
+
+#[test]
+fn balanced() {
+    assert!(balanced_impl().is_ok());
+}
+
+fn balanced_impl() -> color_eyre::Result<()> {
+    color_eyre::install()?;
+
+    let inside = re::inside_balanced("(", ")");
+    let mut ctx = CharsCtx::new("(a(b)c)d");
+
+    assert_eq!(ctx.try_mat(&inside)?, Span::new(1, 5));
+    assert_eq!(ctx.offset(), 7);
+    assert_eq!(ctx.reset().ctor(&inside)?, "a(b)c");
+    assert_eq!(CharsCtx::new("()").ctor(&inside)?, "");
+
+    let mut ctx = CharsCtx::new("(a(b)c");
+
+    assert!(ctx.try_mat(&inside).is_err());
+    assert_eq!(ctx.offset(), 0);
+    assert!(CharsCtx::new("a(b)").try_mat(&inside).is_err());
+
+    let block = re::balanced("begin", "end");
+    let mut ctx = CharsCtx::new("begin 中 begin end end;");
+
+    assert_eq!(ctx.ctor(&block)?, "begin 中 begin end end");
+    assert_eq!(ctx.orig()?, ";");
+    Ok(())
+}
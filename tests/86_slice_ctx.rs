@@ -0,0 +1,58 @@
+use neure::ctx::SliceCtx;
+use neure::prelude::*;
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+enum Token {
+    Ident(&'static str),
+    Int(i64),
+    Colon,
+    Comma,
+}
+
+impl Token {
+    fn is_ident(&self) -> bool {
+        matches!(self, Token::Ident(_))
+    }
+}
+
+#[test]
+fn slice_ctx() {
+    assert!(slice_ctx_impl().is_ok());
+}
+
+fn slice_ctx_impl() -> color_eyre::Result<()> {
+    color_eyre::install()?;
+
+    let toks = [
+        Token::Ident("a"),
+        Token::Colon,
+        Token::Int(1),
+        Token::Comma,
+        Token::Ident("b"),
+        Token::Colon,
+        Token::Int(2),
+    ];
+    let ident = re::one(|t: &&Token| t.is_ident()).map(|v: &[Token]| match v {
+        [Token::Ident(name)] => Ok(*name),
+        _ => Err(neure::err::Error::Other),
+    });
+    let int = re::one(|t: &&Token| matches!(t, Token::Int(_))).map(|v: &[Token]| match v {
+        [Token::Int(v)] => Ok(*v),
+        _ => Err(neure::err::Error::Other),
+    });
+    let colon = re::one(|t: &&Token| t == &&Token::Colon);
+    let comma = re::one(|t: &&Token| t == &&Token::Comma);
+    let field = ident.then(colon)._0().then(int);
+    let mut ctx = SliceCtx::new(&toks);
+
+    assert_eq!(ctx.ctor(&field.sep(comma))?, [("a", 1), ("b", 2)]);
+    assert_eq!(ctx.offset(), 7);
+    assert_eq!(ctx.reset().inc(4).ctor(&field)?, ("b", 2));
+    assert_eq!(ctx.orig_sub(0, 2)?, &toks[..2]);
+
+    let mut ctx = SliceCtx::new(&toks[..2]);
+
+    assert!(ctx.ctor(&field).is_err());
+    assert_eq!(ctx.offset(), 0);
+    Ok(())
+}
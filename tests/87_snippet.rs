@@ -0,0 +1,50 @@
+use neure::err::Error;
+use neure::prelude::*;
+
+#[test]
+fn snippet() {
+    assert!(snippet_impl().is_ok());
+}
+
+fn snippet_impl() -> color_eyre::Result<()> {
+    color_eyre::install()?;
+
+    let key = neu::ascii_alphabetic().repeat_one_more();
+    let val = neu::ascii_digit().repeat_one_more();
+    let pair = key.sep_once("=", val);
+    let mut ctx = CharsCtx::new("name=value with a long tail");
+    let err = ctx.ctor(&pair).unwrap_err().with_snippet(&ctx);
+
+    assert_eq!(err.text, "value with a lon");
+    assert_eq!(err.at, 5);
+    assert!(matches!(err.inner, Error::NeuOneMore { .. }));
+    assert_eq!(ctx.offset(), 0);
+    assert_eq!(CharsCtx::new("age=42").ctor(&pair)?, ("age", "42"));
+
+    let int = "let ".then(neu::ascii_digit().repeat_one_more())._1();
+    let mut ctx = CharsCtx::new("let x = 1;");
+    let err = ctx.ctor(&int).unwrap_err().with_snippet(&ctx);
+
+    assert_eq!(err.at, 4);
+    assert_eq!(
+        err.to_string(),
+        "In (`NeuOneMore`): need more data at offset 4 (near `x = 1;` at offset 4)"
+    );
+
+    // the error does not carry an offset, use the offset of context
+    let ver = neu::ascii_digit().repeat_one_more().sep(".");
+    let mut ctx = CharsCtx::new("v1.2.3");
+    let err = ctx.ctor(&ver).unwrap_err().with_snippet(&ctx);
+
+    assert_eq!(
+        err.to_string(),
+        "In (`Separate`): need more data (near `v1.2.3` at offset 0)"
+    );
+
+    let kw = re::lit_slice(b"fn");
+    let mut ctx = BytesCtx::new(b"pub fn");
+    let err = ctx.try_mat(&kw).unwrap_err().with_snippet(&ctx);
+
+    assert_eq!((err.text.as_str(), err.at), ("pub fn", 0));
+    Ok(())
+}
@@ -0,0 +1,35 @@
+use neure::err::Error;
+use neure::prelude::*;
+use std::cell::Cell;
+
+#[test]
+fn map_each() {
+    assert!(map_each_impl().is_ok());
+}
+
+fn map_each_impl() -> color_eyre::Result<()> {
+    color_eyre::install()?;
+
+    let cnt = Cell::new(0);
+    let num = neu::digit(10).repeat_one_more();
+    let nums = num.sep(",").map_each(|s: &str| {
+        cnt.set(cnt.get() + 1);
+        s.parse::<u8>().map_err(|_| Error::FromStr)
+    });
+
+    assert_eq!(CharsCtx::new("1,22,255").ctor(&nums)?, [1, 22, 255]);
+    assert_eq!(cnt.get(), 3);
+
+    // the first failed element aborts the whole parse
+    cnt.set(0);
+    assert!(matches!(
+        CharsCtx::new("1,256,3,4").ctor(&nums),
+        Err(Error::FromStr)
+    ));
+    assert_eq!(cnt.get(), 2);
+
+    // the mapper is not used by regex
+    assert_eq!(CharsCtx::new("1,256").try_mat(&nums)?, Span::new(0, 5));
+    assert_eq!(cnt.get(), 2);
+    Ok(())
+}